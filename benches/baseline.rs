@@ -20,6 +20,16 @@ impl ProofPolicy<str> for BorrowedProof {
     }
 }
 
+struct ToolNamePolicy;
+
+impl Policy<&'static str> for ToolNamePolicy {
+    type Rules = hlist_ty![NonEmpty, MaxBytes<16>, AsciiIdentifier];
+
+    fn rules() -> Self::Rules {
+        hlist![NonEmpty, MaxBytes::<16>, AsciiIdentifier]
+    }
+}
+
 fn measure<T>(name: &str, iterations: u64, mut operation: impl FnMut() -> T) {
     for _ in 0..iterations.min(10_000) {
         black_box(operation());
@@ -90,6 +100,29 @@ fn hlist_mapping(iterations: u64) {
     });
 }
 
+fn accumulated_validation(iterations: u64) {
+    let rejected = "not/an identifier at all";
+
+    measure("validate_all inline errors", iterations, || {
+        validate_all::<ToolNamePolicy, _>(black_box(rejected)).expect_err("invalid name")
+    });
+    measure("validate_all Vec errors", iterations, || {
+        let value = black_box(rejected);
+        let mut errors = Vec::with_capacity(3);
+        ToolNamePolicy::rules().check_all(&value, &mut errors);
+
+        if errors.is_empty() {
+            Ok(value)
+        } else {
+            Err(errors)
+        }
+    });
+    measure("validate_all_buffered", iterations, || {
+        validate_all_buffered::<ToolNamePolicy, _, 3>(black_box(rejected))
+            .expect_err("invalid name")
+    });
+}
+
 fn main() {
     let iterations = env::var("STRUSTEGY_BENCH_ITERS")
         .ok()
@@ -114,6 +147,7 @@ fn main() {
         prove::<BorrowedProof, _>(black_box(borrowed)).expect("valid borrowed proof")
     });
 
+    accumulated_validation(iterations);
     hlist_mapping(iterations);
     composition_depth(iterations);
 }
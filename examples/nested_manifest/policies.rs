@@ -4,7 +4,7 @@ use core::fmt;
 use std::error::Error;
 
 use strustegy::{
-    ByteLen, InclusiveU64, MaxBytes, NonEmpty, ProjectEvidence, ProofPolicy, Prove, Rule,
+    ByteLen, HexLower, InclusiveU64, MaxBytes, NonEmpty, ProjectEvidence, ProofPolicy, Prove, Rule,
     TrimmedAsciiIdentifier, ValidationError, hlist, hlist_pat, hlist_ty, validation_policy,
 };
 
@@ -129,31 +129,10 @@ validation_policy! {
     ArtifactNamePolicy: String => [NonEmpty, MaxBytes<96>, ArtifactNameSyntax]
 }
 
-#[derive(Debug, Clone, Copy, Default)]
-pub struct LowercaseSha256Syntax;
-
-impl Rule<String> for LowercaseSha256Syntax {
-    fn check(&self, value: &String) -> Result<(), ValidationError> {
-        let valid = value.len() == 64
-            && value
-                .bytes()
-                .all(|byte| byte.is_ascii_digit() || matches!(byte, b'a'..=b'f'));
-
-        if valid {
-            Ok(())
-        } else {
-            Err(ValidationError::new(
-                "lowercase_sha256_syntax",
-                "invalid_syntax",
-            ))
-        }
-    }
-}
-
 pub enum ChecksumSyntaxPolicy {}
 
 validation_policy! {
-    ChecksumSyntaxPolicy: String => [LowercaseSha256Syntax]
+    ChecksumSyntaxPolicy: String => [HexLower<32>]
 }
 
 pub enum TimeoutPolicy {}
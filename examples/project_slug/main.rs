@@ -2,13 +2,12 @@
 
 mod pipeline;
 mod registry;
-mod runtime;
 mod types;
 
 use registry::SlugRegistry;
-use runtime::block_on;
 use strustegy::{
-    AsyncStrategy, AsyncStrategyExt, Validated, async_strategy_fn, into_async, strategy_fn,
+    AsyncStrategy, AsyncStrategyExt, Validated, async_strategy_fn, block_on, into_async,
+    strategy_fn,
 };
 use types::{AvailableProjectSlug, ProjectSlugPolicy, RegistrationError};
 
@@ -3,6 +3,9 @@
 use core::future::{Future, ready};
 use core::ops::AsyncFn;
 
+#[cfg(feature = "alloc")]
+use crate::dyn_strategy::BoxAsyncStrategy;
+use crate::resilience::{Retry, Timeout};
 use crate::strategy::Strategy;
 
 /// A shared, statically dispatched asynchronous strategy.
@@ -119,6 +122,27 @@ pub trait AsyncStrategyExt: Sized {
     fn and_then_async<G>(self, next: G) -> AsyncAndThen<Self, G> {
         AsyncAndThen::new(self, next)
     }
+
+    /// Retry failed attempts according to a
+    /// [`RetrySchedule`](crate::resilience::RetrySchedule) such as
+    /// [`RetryPolicy`](crate::resilience::RetryPolicy).
+    fn retry<P>(self, policy: P) -> Retry<Self, P> {
+        Retry::new(self, policy)
+    }
+
+    /// Resolve to an error when `duration` passes on `clock` before completion.
+    fn timeout<C>(self, duration: core::time::Duration, clock: C) -> Timeout<Self, C> {
+        Timeout::new(self, duration, clock)
+    }
+
+    /// Erase this strategy's concrete type behind a [`BoxAsyncStrategy`].
+    #[cfg(feature = "alloc")]
+    fn boxed_async<Input>(self) -> BoxAsyncStrategy<Input, <Self as AsyncStrategy<Input>>::Output>
+    where
+        Self: AsyncStrategy<Input> + 'static,
+    {
+        BoxAsyncStrategy::new(self)
+    }
 }
 
 impl<S> AsyncStrategyExt for S {}
@@ -0,0 +1,89 @@
+//! A minimal blocking executor and an async-to-sync strategy adapter.
+//!
+//! This module is available with the `blocking` feature. It is intended for
+//! synchronous call sites and tests that reuse asynchronous policies, not as a
+//! replacement for an application runtime.
+
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::Arc;
+use std::task::Wake;
+use std::thread::{self, Thread};
+
+use crate::async_strategy::AsyncStrategy;
+use crate::strategy::Strategy;
+
+/// Wakes a parked [`block_on`] caller.
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Drive `future` to completion on the current thread.
+///
+/// The thread parks while the future is pending and is unparked by the
+/// future's waker, so waiting costs no CPU. Futures woken by another thread,
+/// such as a channel or a timer thread, complete normally. Futures that rely on
+/// a specific runtime's reactor or timers are never woken here.
+pub fn block_on<F>(future: F) -> F::Output
+where
+    F: Future,
+{
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Run an existing [`AsyncStrategy`] as a synchronous [`Strategy`].
+///
+/// Each application is driven to completion with [`block_on`], so the same
+/// executor limitations apply.
+#[derive(Debug, Clone, Copy)]
+pub struct BlockOn<S> {
+    strategy: S,
+}
+
+impl<S> BlockOn<S> {
+    pub const fn new(strategy: S) -> Self {
+        Self { strategy }
+    }
+
+    pub const fn get_ref(&self) -> &S {
+        &self.strategy
+    }
+
+    pub fn into_inner(self) -> S {
+        self.strategy
+    }
+}
+
+impl<Input, S> Strategy<Input> for BlockOn<S>
+where
+    S: AsyncStrategy<Input>,
+{
+    type Output = <S as AsyncStrategy<Input>>::Output;
+
+    fn apply(&self, input: Input) -> Self::Output {
+        block_on(self.strategy.apply_async(input))
+    }
+}
+
+/// Lower an asynchronous strategy into the synchronous strategy world.
+pub const fn into_blocking<S>(strategy: S) -> BlockOn<S> {
+    BlockOn::new(strategy)
+}
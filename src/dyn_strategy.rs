@@ -0,0 +1,219 @@
+//! Object-safe strategies for pipelines selected at runtime.
+//!
+//! Static composition remains the default. These wrappers trade one virtual
+//! call and one allocation per strategy for a nameable type that erases deeply
+//! nested `Compose<AndThen<...>>` types.
+
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+
+use crate::async_strategy::AsyncStrategy;
+use crate::strategy::Strategy;
+
+/// An object-safe view of [`Strategy`] with the output fixed as a parameter.
+///
+/// Every [`Strategy`] implements this trait automatically.
+pub trait DynStrategy<Input, Output> {
+    fn apply_dyn(&self, input: Input) -> Output;
+}
+
+impl<Input, S> DynStrategy<Input, S::Output> for S
+where
+    S: Strategy<Input>,
+{
+    fn apply_dyn(&self, input: Input) -> S::Output {
+        self.apply(input)
+    }
+}
+
+macro_rules! dyn_strategy_wrapper {
+    (
+        $(#[$meta:meta])*
+        $name:ident, $pointer:ident, $($bounds:tt)*
+    ) => {
+        $(#[$meta])*
+        pub struct $name<Input, Output> {
+            strategy: $pointer<dyn DynStrategy<Input, Output> $($bounds)*>,
+        }
+
+        impl<Input, Output> $name<Input, Output> {
+            /// Erase the concrete type of `strategy`.
+            pub fn new<S>(strategy: S) -> Self
+            where
+                S: Strategy<Input, Output = Output> $($bounds)* + 'static,
+            {
+                Self {
+                    strategy: $pointer::new(strategy),
+                }
+            }
+        }
+
+        impl<Input, Output> Strategy<Input> for $name<Input, Output> {
+            type Output = Output;
+
+            fn apply(&self, input: Input) -> Self::Output {
+                self.strategy.apply_dyn(input)
+            }
+        }
+
+        impl<Input, Output> fmt::Debug for $name<Input, Output> {
+            fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str(stringify!($name))
+            }
+        }
+    };
+}
+
+dyn_strategy_wrapper! {
+    /// A uniquely owned, dynamically dispatched strategy.
+    BoxStrategy, Box,
+}
+
+dyn_strategy_wrapper! {
+    /// A uniquely owned, dynamically dispatched strategy that is `Send + Sync`.
+    SendBoxStrategy, Box, + Send + Sync
+}
+
+dyn_strategy_wrapper! {
+    /// A shared, reference-counted, dynamically dispatched strategy.
+    ArcStrategy, Arc,
+}
+
+dyn_strategy_wrapper! {
+    /// A shared, reference-counted, dynamically dispatched strategy that is
+    /// `Send + Sync`.
+    SendArcStrategy, Arc, + Send + Sync
+}
+
+impl<Input, Output> Clone for ArcStrategy<Input, Output> {
+    fn clone(&self) -> Self {
+        Self {
+            strategy: Arc::clone(&self.strategy),
+        }
+    }
+}
+
+impl<Input, Output> Clone for SendArcStrategy<Input, Output> {
+    fn clone(&self) -> Self {
+        Self {
+            strategy: Arc::clone(&self.strategy),
+        }
+    }
+}
+
+/// A heap-allocated future borrowing from the strategy that produced it.
+pub type BoxFuture<'a, Output> = Pin<Box<dyn Future<Output = Output> + 'a>>;
+
+/// A heap-allocated future that can move between threads.
+pub type SendBoxFuture<'a, Output> = Pin<Box<dyn Future<Output = Output> + Send + 'a>>;
+
+/// An object-safe view of [`AsyncStrategy`] returning boxed futures.
+///
+/// Every [`AsyncStrategy`] implements this trait automatically. The futures are
+/// not `Send`: stable Rust cannot yet name the `Send`-ness of an
+/// [`AsyncStrategy`]'s return-position future in a blanket implementation.
+/// Use [`SendBoxAsyncStrategy`] when the erased strategy must cross threads.
+pub trait DynAsyncStrategy<Input, Output> {
+    fn apply_boxed<'a>(&'a self, input: Input) -> BoxFuture<'a, Output>
+    where
+        Input: 'a;
+}
+
+impl<Input, S> DynAsyncStrategy<Input, S::Output> for S
+where
+    S: AsyncStrategy<Input>,
+{
+    fn apply_boxed<'a>(&'a self, input: Input) -> BoxFuture<'a, S::Output>
+    where
+        Input: 'a,
+    {
+        Box::pin(self.apply_async(input))
+    }
+}
+
+/// A uniquely owned, dynamically dispatched asynchronous strategy.
+pub struct BoxAsyncStrategy<Input, Output> {
+    strategy: Box<dyn DynAsyncStrategy<Input, Output>>,
+}
+
+impl<Input, Output> BoxAsyncStrategy<Input, Output> {
+    /// Erase the concrete type of `strategy`.
+    pub fn new<S>(strategy: S) -> Self
+    where
+        S: AsyncStrategy<Input, Output = Output> + 'static,
+    {
+        Self {
+            strategy: Box::new(strategy),
+        }
+    }
+}
+
+impl<Input, Output> AsyncStrategy<Input> for BoxAsyncStrategy<Input, Output> {
+    type Output = Output;
+
+    fn apply_async(&self, input: Input) -> impl Future<Output = Self::Output> {
+        self.strategy.apply_boxed(input)
+    }
+}
+
+impl<Input, Output> fmt::Debug for BoxAsyncStrategy<Input, Output> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("BoxAsyncStrategy")
+    }
+}
+
+type SendAsyncFn<Input, Output> = dyn Fn(Input) -> SendBoxFuture<'static, Output> + Send + Sync;
+
+/// A uniquely owned, dynamically dispatched asynchronous strategy that is
+/// `Send + Sync` and returns `Send` futures.
+///
+/// It erases a function returning a `Send` future rather than an
+/// [`AsyncStrategy`], because the `Send`-ness of a generic strategy's future
+/// cannot be named on stable Rust. Share an existing strategy through an
+/// [`Arc`] to erase it, so the future's `Send` bound is checked where its
+/// concrete type is known:
+///
+/// ```
+/// use std::sync::Arc;
+/// use strustegy::prelude::*;
+///
+/// let double = Arc::new(async_strategy_fn(async |value: u32| value * 2));
+/// let erased = SendBoxAsyncStrategy::new(move |value: u32| {
+///     let double = Arc::clone(&double);
+///     async move { double.apply_async(value).await }
+/// });
+/// # let _: SendBoxAsyncStrategy<u32, u32> = erased;
+/// ```
+pub struct SendBoxAsyncStrategy<Input, Output> {
+    function: Box<SendAsyncFn<Input, Output>>,
+}
+
+impl<Input, Output> SendBoxAsyncStrategy<Input, Output> {
+    /// Erase `function`, which starts one application per call.
+    pub fn new<F, Fut>(function: F) -> Self
+    where
+        F: Fn(Input) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Output> + Send + 'static,
+    {
+        Self {
+            function: Box::new(move |input| Box::pin(function(input))),
+        }
+    }
+}
+
+impl<Input, Output> AsyncStrategy<Input> for SendBoxAsyncStrategy<Input, Output> {
+    type Output = Output;
+
+    fn apply_async(&self, input: Input) -> impl Future<Output = Self::Output> {
+        (self.function)(input)
+    }
+}
+
+impl<Input, Output> fmt::Debug for SendBoxAsyncStrategy<Input, Output> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("SendBoxAsyncStrategy")
+    }
+}
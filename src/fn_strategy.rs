@@ -5,8 +5,8 @@ use crate::strategy::Strategy;
 /// Adapt a shared closure or function into a [`Strategy`].
 ///
 /// The wrapped callable must implement [`Fn`], matching `Strategy::apply`'s
-/// shared receiver. Closures that require mutable access to captured state are
-/// intentionally excluded from this adapter.
+/// shared receiver. Closures that require mutable access to captured state
+/// belong in [`FnMutStrategy`](crate::strategy_mut::FnMutStrategy).
 #[derive(Debug, Clone, Copy)]
 pub struct FnStrategy<F> {
     function: F,
@@ -1,54 +1,167 @@
+#![no_std]
 #![forbid(unsafe_code)]
 #![warn(rust_2018_idioms)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 pub mod async_strategy;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(feature = "alloc")]
+pub mod dyn_strategy;
 pub mod fn_strategy;
 pub mod hlist;
+pub mod observe;
 pub mod pipeline;
 pub mod proof;
 pub mod refine;
+#[cfg(feature = "alloc")]
+pub mod registry;
+pub mod resilience;
 pub mod strategy;
+pub mod strategy_mut;
 pub mod validate;
+#[cfg(feature = "serde")]
+pub mod wire;
 
 pub use async_strategy::{
     AsyncAndThen, AsyncCompose, AsyncFnStrategy, AsyncStrategy, AsyncStrategyExt, IntoAsync,
     async_strategy_fn, into_async,
 };
+#[cfg(feature = "blocking")]
+pub use blocking::{BlockOn, block_on, into_blocking};
+#[cfg(feature = "alloc")]
+pub use dyn_strategy::{
+    ArcStrategy, BoxAsyncStrategy, BoxFuture, BoxStrategy, DynAsyncStrategy, DynStrategy,
+    SendArcStrategy, SendBoxAsyncStrategy, SendBoxFuture, SendBoxStrategy,
+};
 pub use fn_strategy::{FnStrategy, strategy_fn};
 pub use hlist::{Get, GetExt, HCons, HList, HNil, Here, NonEmptyHList, There};
-pub use pipeline::{HMap, HMapRefExt};
-pub use proof::{Validated, Witnessed};
-pub use refine::refiners::{ByteLen, Trimmed, TrimmedAsciiIdentifier, TrimmedNonEmpty, Utf8};
-pub use refine::{ProjectEvidence, ProofPolicy, Prove, Refine, prove, prove_projected};
+#[cfg(feature = "alloc")]
+pub use observe::EventRecorder;
+#[cfg(feature = "tracing")]
+pub use observe::TracingObserver;
+pub use observe::{Event, Instrumented, NoopObserver, Observer};
+pub use pipeline::{HMap, HMapMut, HMapRefExt};
+pub use proof::{CombinedPolicy, ImpliesValidation, Proven, Validated, Witnessed};
+pub use refine::bytes::{
+    LengthField, LengthPrefixed, Magic, Prefix, U8, U16Be, U16Le, U32Be, U32Le, U64Be, U64Le,
+};
+pub use refine::format::{Base64, Base64Url, Hex, HexLower, HexUpper, SemVer, SemVerParts, Uuid};
+pub use refine::net::{Cidr, CidrBlock, Hostname, IpAddrRefiner, Port, SocketAddrRefiner};
+pub use refine::owned::{Canonicalize, OwnedProofPolicy, ProveOwned, RefineOwned, prove_owned};
+pub use refine::parse::{Ensure, ParseBool, ParseFloat, ParseFromStr, ParseInt};
+#[cfg(feature = "alloc")]
+pub use refine::refiners::{
+    AsciiLowercase, CollapseWhitespace, CowExt, NormalizeLineEndings, StripPrefix,
+};
+pub use refine::refiners::{
+    ByteLen, Checked, FileExtensionIn, MaxPathDepth, PathComponents, RelativePath,
+    SafeRelativePath, Trimmed, TrimmedAsciiIdentifier, TrimmedNonEmpty, Utf8,
+};
+pub use refine::time::{
+    FixedClock, HumanDuration, MaxDuration, NotAfter, NotBefore, Rfc3339, SystemClock, Timestamp,
+    WallClock,
+};
+pub use refine::token::{KeyValue, KeyValuePair, Segments, SegmentsView, SplitExact, SplitOnce};
+#[cfg(feature = "unicode")]
+pub use refine::unicode::{
+    GraphemeCount, Graphemes, MaxGraphemes, Nfc, NoBidiControls, SingleScript, graphemes,
+};
+pub use refine::uri::{MaxQueryPairs, NoUserInfo, PathNoDotSegments, SchemeIn, Uri, UriParts};
+pub use refine::{
+    ProjectEvidence, ProofPolicy, Prove, Refine, RefineChain, RefineExt, RefineSequence,
+    RefineView, ThenRefine, prove, prove_projected,
+};
+#[cfg(feature = "alloc")]
+pub use refine::{prove_all, prove_all_observed, prove_projected_all};
+#[cfg(feature = "alloc")]
+pub use registry::{PipelineBuilder, RegistryPipeline, StrategyRegistry, UnknownStrategy};
+#[cfg(feature = "alloc")]
+pub use resilience::MockClock;
+pub use resilience::{
+    Backoff, Clock, ConstantBackoff, Elapsed, ExponentialBackoff, Retry, RetryPolicy,
+    RetrySchedule, Timeout,
+};
 pub use strategy::{AndThen, Compose, Identity, Strategy, StrategyExt};
+pub use strategy_mut::{
+    AndThenMut, ByMut, ComposeMut, FnMutStrategy, IntoStrategyMut, StrategyMut, StrategyMutExt,
+    strategy_fn_mut,
+};
 pub use validate::rules::{AsciiIdentifier, InclusiveU64, MaxBytes, MaxUnicodeScalars, NonEmpty};
 pub use validate::{
-    Policy, Rule, ValidateRules, ValidateWith, ValidationError, ValidationErrors, validate_all,
-    validate_first,
+    ConstLiteral, ConstRule, ErrorBuffer, ErrorSink, Policy, Rule, ValidateRules, ValidationError,
+    validate_all_buffered, validate_const, validate_first,
 };
+#[cfg(feature = "alloc")]
+pub use validate::{ValidateWith, ValidationErrors, validate_all, validate_all_observed};
+#[cfg(feature = "serde")]
+pub use wire::Projected;
 
-/// Commonly used Strustegy traits, types, rules, and macros.
+/// Commonly used Strustegy traits, core combinators, rules, and macros.
+///
+/// Domain-specific refiners such as [`refine::bytes`] and [`refine::uri`], the
+/// [`resilience`] wrappers, and the observer [`Event`] stay in
+/// their modules, so a glob import does not claim common names like `Uri`,
+/// `Retry`, `Clock`, or `Event`.
 pub mod prelude {
     pub use crate::async_strategy::{
         AsyncAndThen, AsyncCompose, AsyncFnStrategy, AsyncStrategy, AsyncStrategyExt, IntoAsync,
         async_strategy_fn, into_async,
     };
+    #[cfg(feature = "blocking")]
+    pub use crate::blocking::{BlockOn, block_on, into_blocking};
+    #[cfg(feature = "alloc")]
+    pub use crate::dyn_strategy::{
+        ArcStrategy, BoxAsyncStrategy, BoxStrategy, DynAsyncStrategy, DynStrategy, SendArcStrategy,
+        SendBoxAsyncStrategy, SendBoxStrategy,
+    };
     pub use crate::fn_strategy::{FnStrategy, strategy_fn};
     pub use crate::hlist::{Get, GetExt, HCons, HList, HNil, Here, NonEmptyHList, There};
-    pub use crate::pipeline::{HMap, HMapRefExt};
-    pub use crate::proof::{Validated, Witnessed};
+    #[cfg(feature = "tracing")]
+    pub use crate::observe::TracingObserver;
+    pub use crate::observe::{Instrumented, NoopObserver, Observer};
+    pub use crate::pipeline::{HMap, HMapMut, HMapRefExt};
+    pub use crate::proof::{CombinedPolicy, ImpliesValidation, Proven, Validated, Witnessed};
+    pub use crate::refine::owned::{
+        Canonicalize, OwnedProofPolicy, ProveOwned, RefineOwned, prove_owned,
+    };
+    #[cfg(feature = "alloc")]
+    pub use crate::refine::refiners::CowExt;
     pub use crate::refine::refiners::{
         ByteLen, Trimmed, TrimmedAsciiIdentifier, TrimmedNonEmpty, Utf8,
     };
-    pub use crate::refine::{ProjectEvidence, ProofPolicy, Prove, Refine, prove, prove_projected};
+    pub use crate::refine::{
+        ProjectEvidence, ProofPolicy, Prove, Refine, RefineChain, RefineExt, RefineSequence,
+        RefineView, ThenRefine, prove, prove_projected,
+    };
+    #[cfg(feature = "alloc")]
+    pub use crate::refine::{prove_all, prove_all_observed, prove_projected_all};
+    #[cfg(feature = "alloc")]
+    pub use crate::registry::{
+        PipelineBuilder, RegistryPipeline, StrategyRegistry, UnknownStrategy,
+    };
     pub use crate::strategy::{AndThen, Compose, Identity, Strategy, StrategyExt};
+    pub use crate::strategy_mut::{
+        AndThenMut, ByMut, ComposeMut, FnMutStrategy, IntoStrategyMut, StrategyMut, StrategyMutExt,
+        strategy_fn_mut,
+    };
     pub use crate::validate::rules::{
         AsciiIdentifier, InclusiveU64, MaxBytes, MaxUnicodeScalars, NonEmpty,
     };
     pub use crate::validate::{
-        Policy, Rule, ValidateRules, ValidateWith, ValidationError, ValidationErrors, validate_all,
-        validate_first,
+        ConstLiteral, ConstRule, ErrorBuffer, ErrorSink, Policy, Rule, ValidateRules,
+        ValidationError, validate_all_buffered, validate_const, validate_first,
+    };
+    #[cfg(feature = "alloc")]
+    pub use crate::validate::{
+        ValidateWith, ValidationErrors, validate_all, validate_all_observed,
     };
-    pub use crate::{hlist, hlist_pat, hlist_ty, validation_policy};
+    #[cfg(feature = "serde")]
+    pub use crate::wire::Projected;
+    pub use crate::{const_validated, hlist, hlist_pat, hlist_ty, validation_policy};
 }
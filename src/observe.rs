@@ -0,0 +1,299 @@
+//! Execution hooks for strategies, validation rules, and refiners.
+//!
+//! An [`Observer`] receives the name of each stage, rule, or refiner and the
+//! redaction-safe outcome, never the value being processed. Every method has a
+//! no-op default, so an observer implements only the events it needs.
+//! Observers that need timings read their own clock between
+//! [`Observer::stage_entered`] and [`Observer::stage_exited`].
+//!
+//! Rules are named by [`Rule::name`] and refiners by [`Refine::name`], so a
+//! rule or refiner reports the same name whether it passes or fails.
+//!
+//! [`Rule::name`]: crate::Rule::name
+//! [`Refine::name`]: crate::Refine::name
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cell::RefCell;
+
+use crate::async_strategy::AsyncStrategy;
+use crate::strategy::Strategy;
+use crate::validate::ValidationError;
+
+/// A receiver for execution events.
+pub trait Observer {
+    /// A named stage is about to run.
+    fn stage_entered(&self, stage: &'static str) {
+        let _ = stage;
+    }
+
+    /// A named stage returned.
+    fn stage_exited(&self, stage: &'static str) {
+        let _ = stage;
+    }
+
+    /// The span a named stage runs inside.
+    ///
+    /// [`Instrumented`] enters it for the duration of a synchronous call and
+    /// on every poll of an asynchronous one. Defaults to a disabled span.
+    /// Requires the `tracing` feature.
+    #[cfg(feature = "tracing")]
+    fn stage_span(&self, stage: &'static str) -> tracing::Span {
+        let _ = stage;
+        tracing::Span::none()
+    }
+
+    /// A validation rule accepted the value.
+    ///
+    /// `rule` is [`Rule::name`], the same name [`rule_failed`] reports when
+    /// the rule rejects a value. A custom rule that keeps the default name
+    /// reports its Rust type name here rather than the rule in its
+    /// [`ValidationError`].
+    ///
+    /// [`Rule::name`]: crate::Rule::name
+    /// [`rule_failed`]: Observer::rule_failed
+    fn rule_passed(&self, rule: &'static str) {
+        let _ = rule;
+    }
+
+    /// A validation rule rejected the value with `code`.
+    ///
+    /// `rule` is [`Rule::name`](crate::Rule::name), not the rule carried by
+    /// the error, so passes and failures of one rule share a name.
+    fn rule_failed(&self, rule: &'static str, code: &'static str) {
+        let _ = (rule, code);
+    }
+
+    /// A refiner finished, successfully or not.
+    ///
+    /// `refiner` is [`Refine::name`](crate::Refine::name).
+    fn refiner_ran(&self, refiner: &'static str, outcome: Result<(), ValidationError>) {
+        let _ = (refiner, outcome);
+    }
+}
+
+impl<O: Observer + ?Sized> Observer for &O {
+    fn stage_entered(&self, stage: &'static str) {
+        (**self).stage_entered(stage);
+    }
+
+    fn stage_exited(&self, stage: &'static str) {
+        (**self).stage_exited(stage);
+    }
+
+    #[cfg(feature = "tracing")]
+    fn stage_span(&self, stage: &'static str) -> tracing::Span {
+        (**self).stage_span(stage)
+    }
+
+    fn rule_passed(&self, rule: &'static str) {
+        (**self).rule_passed(rule);
+    }
+
+    fn rule_failed(&self, rule: &'static str, code: &'static str) {
+        (**self).rule_failed(rule, code);
+    }
+
+    fn refiner_ran(&self, refiner: &'static str, outcome: Result<(), ValidationError>) {
+        (**self).refiner_ran(refiner, outcome);
+    }
+}
+
+/// An observer that ignores every event.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopObserver;
+
+impl Observer for NoopObserver {}
+
+/// Report a strategy's execution to an [`Observer`] as a named stage.
+///
+/// The observer sees the stage name on entry and exit, but neither the input
+/// nor the output. Asynchronous strategies exit when their future completes.
+/// With the `tracing` feature the stage also runs inside
+/// [`Observer::stage_span`], so interleaved futures each re-enter their own
+/// span when polled.
+#[derive(Debug, Clone, Copy)]
+pub struct Instrumented<S, O> {
+    strategy: S,
+    stage: &'static str,
+    observer: O,
+}
+
+impl<S, O> Instrumented<S, O> {
+    pub const fn new(strategy: S, stage: &'static str, observer: O) -> Self {
+        Self {
+            strategy,
+            stage,
+            observer,
+        }
+    }
+
+    pub const fn get_ref(&self) -> &S {
+        &self.strategy
+    }
+
+    pub const fn stage(&self) -> &'static str {
+        self.stage
+    }
+
+    pub fn into_inner(self) -> S {
+        self.strategy
+    }
+}
+
+impl<Input, S, O> Strategy<Input> for Instrumented<S, O>
+where
+    S: Strategy<Input>,
+    O: Observer,
+{
+    type Output = S::Output;
+
+    fn apply(&self, input: Input) -> Self::Output {
+        #[cfg(feature = "tracing")]
+        let span = self.observer.stage_span(self.stage);
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        self.observer.stage_entered(self.stage);
+        let output = self.strategy.apply(input);
+        self.observer.stage_exited(self.stage);
+        output
+    }
+}
+
+impl<Input, S, O> AsyncStrategy<Input> for Instrumented<S, O>
+where
+    S: AsyncStrategy<Input>,
+    O: Observer,
+{
+    type Output = S::Output;
+
+    async fn apply_async(&self, input: Input) -> Self::Output {
+        let stage = async {
+            self.observer.stage_entered(self.stage);
+            let output = self.strategy.apply_async(input).await;
+            self.observer.stage_exited(self.stage);
+            output
+        };
+
+        #[cfg(feature = "tracing")]
+        let stage = tracing::Instrument::instrument(stage, self.observer.stage_span(self.stage));
+
+        stage.await
+    }
+}
+
+/// One event captured by an [`EventRecorder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    StageEntered(&'static str),
+    StageExited(&'static str),
+    RulePassed(&'static str),
+    RuleFailed {
+        rule: &'static str,
+        code: &'static str,
+    },
+    RefinerRan {
+        refiner: &'static str,
+        outcome: Result<(), ValidationError>,
+    },
+}
+
+/// An in-memory observer for tests that keeps every event in order.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default)]
+pub struct EventRecorder {
+    events: RefCell<Vec<Event>>,
+}
+
+#[cfg(feature = "alloc")]
+impl EventRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return every recorded event in arrival order.
+    pub fn events(&self) -> Vec<Event> {
+        self.events.borrow().clone()
+    }
+
+    /// Forget every recorded event.
+    pub fn clear(&self) {
+        self.events.borrow_mut().clear();
+    }
+
+    fn record(&self, event: Event) {
+        self.events.borrow_mut().push(event);
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Observer for EventRecorder {
+    fn stage_entered(&self, stage: &'static str) {
+        self.record(Event::StageEntered(stage));
+    }
+
+    fn stage_exited(&self, stage: &'static str) {
+        self.record(Event::StageExited(stage));
+    }
+
+    fn rule_passed(&self, rule: &'static str) {
+        self.record(Event::RulePassed(rule));
+    }
+
+    fn rule_failed(&self, rule: &'static str, code: &'static str) {
+        self.record(Event::RuleFailed { rule, code });
+    }
+
+    fn refiner_ran(&self, refiner: &'static str, outcome: Result<(), ValidationError>) {
+        self.record(Event::RefinerRan { refiner, outcome });
+    }
+}
+
+/// Forward events to the [`tracing`] crate.
+///
+/// Each stage is an `INFO` span named `strustegy.stage` with a `stage` field,
+/// so subscribers see its duration. [`Instrumented`] scopes the span to its
+/// stage, so the observer keeps no state and can be shared across threads and
+/// interleaved futures. Rule and refiner events are `TRACE` when they pass and
+/// `DEBUG` when they fail. Requires the `tracing` feature.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TracingObserver;
+
+#[cfg(feature = "tracing")]
+impl TracingObserver {
+    pub const fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Observer for TracingObserver {
+    fn stage_span(&self, stage: &'static str) -> tracing::Span {
+        tracing::info_span!("strustegy.stage", stage)
+    }
+
+    fn rule_passed(&self, rule: &'static str) {
+        tracing::trace!(rule, "validation rule passed");
+    }
+
+    fn rule_failed(&self, rule: &'static str, code: &'static str) {
+        tracing::debug!(rule, code, "validation rule failed");
+    }
+
+    fn refiner_ran(&self, refiner: &'static str, outcome: Result<(), ValidationError>) {
+        match outcome {
+            Ok(()) => tracing::trace!(refiner, "refiner passed"),
+            Err(error) => tracing::debug!(
+                refiner,
+                rule = error.rule(),
+                code = error.code(),
+                "refiner failed"
+            ),
+        }
+    }
+}
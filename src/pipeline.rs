@@ -2,6 +2,7 @@
 
 use crate::hlist::{HCons, HList, HNil};
 use crate::strategy::Strategy;
+use crate::strategy_mut::StrategyMut;
 
 /// Map one heterogeneous strategy across an owned HList.
 pub trait HMap<S>: HList {
@@ -34,6 +35,41 @@ where
     }
 }
 
+/// Map one stateful strategy across an owned HList, head first.
+///
+/// The same `&mut S` is threaded through every element, so state updated by
+/// one element is visible to the next.
+pub trait HMapMut<S>: HList {
+    type Output: HList;
+
+    #[must_use]
+    fn hmap_stateful(self, strategy: &mut S) -> Self::Output;
+}
+
+impl<S> HMapMut<S> for HNil {
+    type Output = HNil;
+
+    fn hmap_stateful(self, _strategy: &mut S) -> Self::Output {
+        HNil
+    }
+}
+
+impl<H, T, S> HMapMut<S> for HCons<H, T>
+where
+    T: HList + HMapMut<S>,
+    S: StrategyMut<H>,
+{
+    type Output = HCons<<S as StrategyMut<H>>::Output, <T as HMapMut<S>>::Output>;
+
+    fn hmap_stateful(self, strategy: &mut S) -> Self::Output {
+        let head = strategy.apply_mut(self.head);
+        HCons {
+            head,
+            tail: self.tail.hmap_stateful(strategy),
+        }
+    }
+}
+
 /// Borrowed and mutable HList mapping derived from the HList GAT views.
 pub trait HMapRefExt: HList {
     #[must_use]
@@ -3,6 +3,10 @@
 use core::fmt;
 use core::marker::PhantomData;
 
+use crate::hlist::{HCons, HNil};
+use crate::refine::{ProjectEvidence, ProofPolicy, Prove};
+use crate::validate::{self, ValidateRules, ValidationError};
+
 /// A receipt that the wrapped value passed `Policy` when this wrapper was created.
 ///
 /// This is not necessarily a perpetual invariant for arbitrary `T`. Interior
@@ -56,7 +60,7 @@ pub struct Validated<T, Policy> {
 }
 
 impl<T, Policy> Validated<T, Policy> {
-    pub(crate) fn new(value: T) -> Self {
+    pub(crate) const fn new(value: T) -> Self {
         Self {
             value,
             _policy: PhantomData,
@@ -162,6 +166,79 @@ impl<'input, Input: ?Sized, Policy, Evidence> Witnessed<'input, Input, Policy, E
     }
 }
 
+impl<'input, Input: ?Sized, Left, LeftEvidence> Witnessed<'input, Input, Left, LeftEvidence> {
+    /// Combine this witness with another witness of the same input into a
+    /// witness of `Combined`.
+    ///
+    /// The evidence is the pair `hlist![left, right]`. Witnesses are combined
+    /// only when both borrow the very same input, compared by address and
+    /// length; otherwise this returns a `witnessed` / `different_input` error.
+    #[allow(clippy::type_complexity)]
+    pub fn combine<Combined, RightEvidence>(
+        self,
+        other: Witnessed<'input, Input, Combined::Right, RightEvidence>,
+    ) -> Result<
+        Witnessed<'input, Input, Combined, HCons<LeftEvidence, HCons<RightEvidence, HNil>>>,
+        ValidationError,
+    >
+    where
+        Combined: CombinedPolicy<Input, Left = Left>,
+    {
+        if !core::ptr::eq(self.input, other.input) {
+            return Err(ValidationError::new("witnessed", "different_input"));
+        }
+
+        Ok(Witnessed::new(
+            self.input,
+            HCons {
+                head: self.evidence,
+                tail: HCons {
+                    head: other.evidence,
+                    tail: HNil,
+                },
+            },
+        ))
+    }
+}
+
+impl<'input, Input, Policy>
+    Witnessed<'input, Input, Policy, <Policy::Refiners as Prove<Input>>::Evidence<'input>>
+where
+    Input: ?Sized + 'input,
+    Policy: ProofPolicy<Input>,
+{
+    /// Replace the raw evidence with the policy's own named projection while
+    /// keeping the witness.
+    ///
+    /// Only the policy's [`ProjectEvidence`] implementation can choose the new
+    /// evidence, so callers cannot attach arbitrary data to the witness.
+    pub fn map_evidence(self) -> Witnessed<'input, Input, Policy, Policy::Output<'input>>
+    where
+        Policy: ProjectEvidence<Input>,
+    {
+        Witnessed::new(self.input, Policy::project(self.input, self.evidence))
+    }
+
+    /// Produce the validation receipt that the policy declares its witness
+    /// implies.
+    ///
+    /// The validation rules always run on the owned value, so a policy whose
+    /// declaration is wrong returns the first failing rule's error instead of
+    /// a receipt.
+    pub fn to_validated(
+        &self,
+    ) -> Result<Validated<Policy::Owned, Policy::Validation>, ValidationError>
+    where
+        Policy: ImpliesValidation<Input>,
+    {
+        let value = Policy::owned(self.input, &self.evidence);
+
+        <Policy::Validation as validate::Policy<Policy::Owned>>::rules().check_first(&value)?;
+
+        Ok(Validated::new(value))
+    }
+}
+
 impl<Input: ?Sized, Policy, Evidence> AsRef<Input> for Witnessed<'_, Input, Policy, Evidence> {
     fn as_ref(&self) -> &Input {
         self.input
@@ -173,3 +250,149 @@ impl<Input: ?Sized, Policy, Evidence> fmt::Debug for Witnessed<'_, Input, Policy
         formatter.write_str("Witnessed(<redacted>)")
     }
 }
+
+/// A policy whose witness is the pair of a `Left` and a `Right` witness over
+/// the same input.
+///
+/// The owner of the combined marker declares which two proof policies it
+/// joins; [`Witnessed::combine`] is the only way to produce its witness, and
+/// it accepts only witnesses of exactly those policies.
+///
+/// ```compile_fail
+/// use strustegy::prelude::*;
+///
+/// enum NameProof {}
+/// enum OtherProof {}
+/// enum BothProof {}
+///
+/// impl ProofPolicy<str> for NameProof {
+///     type Refiners = hlist_ty![TrimmedNonEmpty];
+///
+///     fn refiners() -> Self::Refiners {
+///         hlist![TrimmedNonEmpty]
+///     }
+/// }
+///
+/// impl ProofPolicy<str> for OtherProof {
+///     type Refiners = hlist_ty![ByteLen];
+///
+///     fn refiners() -> Self::Refiners {
+///         hlist![ByteLen]
+///     }
+/// }
+///
+/// impl CombinedPolicy<str> for BothProof {
+///     type Left = NameProof;
+///     type Right = NameProof;
+/// }
+///
+/// let name = prove::<NameProof, _>("name").unwrap();
+/// let other = prove::<OtherProof, _>(name.get()).unwrap();
+/// let _both = name.combine::<BothProof, _>(other);
+/// ```
+pub trait CombinedPolicy<Input: ?Sized> {
+    type Left;
+    type Right;
+}
+
+/// A proof policy whose owner asserts that its evidence implies a validation
+/// policy over an owned value.
+///
+/// [`Witnessed::to_validated`] builds the owned value with [`owned`], runs the
+/// `Validation` rules on it, and wraps it as
+/// `Validated<Self::Owned, Self::Validation>`. The declaration cannot forge a
+/// receipt: an owned value that fails `Validation` is rejected.
+///
+/// [`owned`]: ImpliesValidation::owned
+pub trait ImpliesValidation<Input: ?Sized>: ProofPolicy<Input> {
+    type Owned;
+    type Validation: validate::Policy<Self::Owned>;
+
+    fn owned<'input>(
+        input: &'input Input,
+        evidence: &<Self::Refiners as Prove<Input>>::Evidence<'input>,
+    ) -> Self::Owned;
+}
+
+/// An owned value accompanied by evidence from one specific owned proof policy.
+///
+/// `Proven` is the owned counterpart of [`Witnessed`]. It holds the value
+/// produced by the policy's final [`RefineOwned`](crate::RefineOwned) stage,
+/// which is typically a canonicalized form of the original input, together with
+/// every stage's owned evidence. The same proof-model limitations apply.
+///
+/// Its fields are private, so downstream safe code cannot pair an arbitrary
+/// value with arbitrary evidence.
+///
+/// ```compile_fail
+/// use strustegy::{HNil, Proven};
+///
+/// enum ExamplePolicy {}
+/// let _forged = Proven::<String, ExamplePolicy, HNil> {
+///     value: String::from("unchecked"),
+///     evidence: HNil,
+///     _policy: core::marker::PhantomData,
+/// };
+/// ```
+#[must_use = "proven values should be consumed by trusted code"]
+pub struct Proven<T, Policy, Evidence> {
+    value: T,
+    evidence: Evidence,
+    _policy: PhantomData<fn() -> Policy>,
+}
+
+impl<T, Policy, Evidence> Proven<T, Policy, Evidence> {
+    pub(crate) fn new(value: T, evidence: Evidence) -> Self {
+        Self {
+            value,
+            evidence,
+            _policy: PhantomData,
+        }
+    }
+
+    /// Borrow the proven value.
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Borrow the heterogeneous evidence produced by the policy.
+    pub fn evidence(&self) -> &Evidence {
+        &self.evidence
+    }
+
+    /// Consume the wrapper and return the proven value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Consume the wrapper and return the value and evidence.
+    pub fn into_parts(self) -> (T, Evidence) {
+        (self.value, self.evidence)
+    }
+}
+
+impl<T: Clone, Policy, Evidence: Clone> Clone for Proven<T, Policy, Evidence> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone(), self.evidence.clone())
+    }
+}
+
+impl<T: PartialEq, Policy, Evidence: PartialEq> PartialEq for Proven<T, Policy, Evidence> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.evidence == other.evidence
+    }
+}
+
+impl<T: Eq, Policy, Evidence: Eq> Eq for Proven<T, Policy, Evidence> {}
+
+impl<T, Policy, Evidence> AsRef<T> for Proven<T, Policy, Evidence> {
+    fn as_ref(&self) -> &T {
+        self.get()
+    }
+}
+
+impl<T, Policy, Evidence> fmt::Debug for Proven<T, Policy, Evidence> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("Proven(<redacted>)")
+    }
+}
@@ -0,0 +1,209 @@
+//! Zero-copy refiners for binary frames.
+//!
+//! Fixed-offset readers take their byte offset as a const parameter that
+//! defaults to zero, so a frame header is described entirely by its proof
+//! policy's refiner types. Short input is rejected with `too_short`; other
+//! codes are `mismatch` for [`Magic`] and `truncated` for [`LengthPrefixed`].
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::refine::Refine;
+use crate::validate::ValidationError;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Borrow the first `N` bytes as a fixed-size array.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Prefix<const N: usize>;
+
+impl<const N: usize> Refine<[u8]> for Prefix<N> {
+    type Output<'input>
+        = &'input [u8; N]
+    where
+        [u8]: 'input;
+
+    fn name(&self) -> &'static str {
+        "prefix"
+    }
+
+    fn refine<'input>(&self, input: &'input [u8]) -> Result<Self::Output<'input>, ValidationError> {
+        input
+            .first_chunk::<N>()
+            .ok_or_else(|| ValidationError::new("prefix", "too_short"))
+    }
+}
+
+/// Require the input to start with a fixed byte signature.
+///
+/// The matched bytes are returned as a borrowed view.
+#[derive(Debug, Clone, Copy)]
+pub struct Magic {
+    bytes: &'static [u8],
+}
+
+impl Magic {
+    pub const fn new(bytes: &'static [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub const fn bytes(&self) -> &'static [u8] {
+        self.bytes
+    }
+}
+
+impl Refine<[u8]> for Magic {
+    type Output<'input>
+        = &'input [u8]
+    where
+        [u8]: 'input;
+
+    fn name(&self) -> &'static str {
+        "magic"
+    }
+
+    fn refine<'input>(&self, input: &'input [u8]) -> Result<Self::Output<'input>, ValidationError> {
+        match input.get(..self.bytes.len()) {
+            Some(signature) if signature == self.bytes => Ok(signature),
+            Some(_) => Err(ValidationError::new("magic", "mismatch")),
+            None => Err(ValidationError::new("magic", "too_short")),
+        }
+    }
+}
+
+/// Fixed-offset integer readers usable as the length field of
+/// [`LengthPrefixed`].
+///
+/// This trait is sealed.
+pub trait LengthField: sealed::Sealed {
+    /// The offset of the first byte after the field.
+    const END: usize;
+
+    /// Read the field, or return `None` when the input is too short.
+    fn read_length(input: &[u8]) -> Option<u64>;
+}
+
+macro_rules! fixed_int {
+    ($($(#[$meta:meta])* $name:ident, $integer:ty, $from_bytes:ident, $rule:literal;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy, Default)]
+            pub struct $name<const OFFSET: usize = 0>;
+
+            impl<const OFFSET: usize> $name<OFFSET> {
+                fn read(input: &[u8]) -> Option<$integer> {
+                    input
+                        .get(OFFSET..)?
+                        .first_chunk()
+                        .map(|bytes| <$integer>::$from_bytes(*bytes))
+                }
+            }
+
+            impl<const OFFSET: usize> Refine<[u8]> for $name<OFFSET> {
+                type Output<'input>
+                    = $integer
+                where
+                    [u8]: 'input;
+
+                fn name(&self) -> &'static str {
+                    $rule
+                }
+
+                fn refine<'input>(
+                    &self,
+                    input: &'input [u8],
+                ) -> Result<Self::Output<'input>, ValidationError> {
+                    Self::read(input).ok_or_else(|| ValidationError::new($rule, "too_short"))
+                }
+            }
+
+            impl<const OFFSET: usize> sealed::Sealed for $name<OFFSET> {}
+
+            impl<const OFFSET: usize> LengthField for $name<OFFSET> {
+                const END: usize = OFFSET + size_of::<$integer>();
+
+                fn read_length(input: &[u8]) -> Option<u64> {
+                    Self::read(input).map(u64::from)
+                }
+            }
+        )*
+    };
+}
+
+fixed_int! {
+    /// Read a `u8` at byte `OFFSET`.
+    U8, u8, from_be_bytes, "u8";
+    /// Read a big-endian `u16` at byte `OFFSET`.
+    U16Be, u16, from_be_bytes, "u16_be";
+    /// Read a little-endian `u16` at byte `OFFSET`.
+    U16Le, u16, from_le_bytes, "u16_le";
+    /// Read a big-endian `u32` at byte `OFFSET`.
+    U32Be, u32, from_be_bytes, "u32_be";
+    /// Read a little-endian `u32` at byte `OFFSET`.
+    U32Le, u32, from_le_bytes, "u32_le";
+    /// Read a big-endian `u64` at byte `OFFSET`.
+    U64Be, u64, from_be_bytes, "u64_be";
+    /// Read a little-endian `u64` at byte `OFFSET`.
+    U64Le, u64, from_le_bytes, "u64_le";
+}
+
+/// Borrow a payload whose length is given by the length field `L`.
+///
+/// The payload starts immediately after the field. Bytes after the payload are
+/// ignored, so several length-prefixed payloads can be proven from one frame.
+/// A missing field is rejected with `too_short` and a short payload with
+/// `truncated`.
+pub struct LengthPrefixed<L> {
+    _field: PhantomData<fn() -> L>,
+}
+
+impl<L> LengthPrefixed<L> {
+    pub const fn new() -> Self {
+        Self {
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<L> Copy for LengthPrefixed<L> {}
+
+impl<L> Clone for LengthPrefixed<L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<L> Default for LengthPrefixed<L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<L> fmt::Debug for LengthPrefixed<L> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("LengthPrefixed")
+    }
+}
+
+impl<L: LengthField> Refine<[u8]> for LengthPrefixed<L> {
+    type Output<'input>
+        = &'input [u8]
+    where
+        [u8]: 'input;
+
+    fn name(&self) -> &'static str {
+        "length_prefixed"
+    }
+
+    fn refine<'input>(&self, input: &'input [u8]) -> Result<Self::Output<'input>, ValidationError> {
+        let length = L::read_length(input)
+            .ok_or_else(|| ValidationError::new("length_prefixed", "too_short"))?;
+
+        usize::try_from(length)
+            .ok()
+            .and_then(|length| input.get(L::END..)?.get(..length))
+            .ok_or_else(|| ValidationError::new("length_prefixed", "truncated"))
+    }
+}
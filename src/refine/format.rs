@@ -0,0 +1,350 @@
+//! Dependency-free refiners for common identifier formats.
+//!
+//! Each refiner is also a [`Rule`] for string-like values, so it can check
+//! syntax in a validation policy when the decoded evidence is not needed.
+
+use crate::refine::Refine;
+use crate::validate::{Rule, ValidationError};
+
+macro_rules! refiner_rule {
+    ($($name:ident $(<const $param:ident: usize>)? => $rule:literal),* $(,)?) => {
+        $(
+            impl<T $(, const $param: usize)?> Rule<T> for $name $(<$param>)?
+            where
+                T: AsRef<str>,
+            {
+                fn name(&self) -> &'static str {
+                    $rule
+                }
+
+                fn check(&self, value: &T) -> Result<(), ValidationError> {
+                    self.refine(value.as_ref()).map(drop)
+                }
+            }
+        )*
+    };
+}
+
+fn hex_digit(byte: u8, case: Case) -> Option<u8> {
+    match byte {
+        b'0'..=b'9' => Some(byte - b'0'),
+        b'a'..=b'f' if case != Case::Upper => Some(byte - b'a' + 10),
+        b'A'..=b'F' if case != Case::Lower => Some(byte - b'A' + 10),
+        _ => None,
+    }
+}
+
+fn decode_hex(hex: &[u8], output: &mut [u8], case: Case) -> bool {
+    hex.chunks_exact(2).zip(output).all(|(pair, byte)| {
+        match (hex_digit(pair[0], case), hex_digit(pair[1], case)) {
+            (Some(high), Some(low)) => {
+                *byte = (high << 4) | low;
+                true
+            }
+            _ => false,
+        }
+    })
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Lower,
+    Upper,
+    Either,
+}
+
+/// Decode a canonical hyphenated UUID such as
+/// `67e55044-10b1-426f-9247-bb680e5fe0c8` into its 16 bytes.
+///
+/// Hex digits may use either case. Braced, URN, and unhyphenated forms are
+/// rejected.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Uuid;
+
+impl Refine<str> for Uuid {
+    type Output<'input>
+        = [u8; 16]
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "uuid"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        let input = input.as_bytes();
+
+        if input.len() != 36 {
+            return Err(ValidationError::new("uuid", "invalid_length"));
+        }
+
+        let mut uuid = [0; 16];
+        let mut decoded = 0;
+        let mut valid = true;
+
+        for (index, group) in input.split(|byte| *byte == b'-').enumerate() {
+            let expected = [8, 4, 4, 4, 12].get(index).copied();
+            let output = uuid.get_mut(decoded..decoded + group.len() / 2);
+
+            valid &= match (expected, output) {
+                (Some(expected), Some(output)) if group.len() == expected => {
+                    decode_hex(group, output, Case::Either)
+                }
+                _ => false,
+            };
+            decoded += group.len() / 2;
+        }
+
+        if valid && decoded == 16 {
+            Ok(uuid)
+        } else {
+            Err(ValidationError::new("uuid", "invalid_syntax"))
+        }
+    }
+}
+
+macro_rules! fixed_hex {
+    ($($(#[$meta:meta])* $name:ident, $case:expr, $rule:literal;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy, Default)]
+            pub struct $name<const N: usize>;
+
+            impl<const N: usize> Refine<str> for $name<N> {
+                type Output<'input>
+                    = [u8; N]
+                where
+                    str: 'input;
+
+                fn name(&self) -> &'static str {
+                    $rule
+                }
+
+                fn refine<'input>(
+                    &self,
+                    input: &'input str,
+                ) -> Result<Self::Output<'input>, ValidationError> {
+                    if input.len() != N * 2 {
+                        return Err(ValidationError::new($rule, "invalid_length"));
+                    }
+
+                    let mut bytes = [0; N];
+
+                    if decode_hex(input.as_bytes(), &mut bytes, $case) {
+                        Ok(bytes)
+                    } else {
+                        Err(ValidationError::new($rule, "invalid_digit"))
+                    }
+                }
+            }
+        )*
+    };
+}
+
+fixed_hex! {
+    /// Decode exactly `N` bytes written as `2 * N` lowercase hex digits.
+    HexLower, Case::Lower, "hex_lower";
+    /// Decode exactly `N` bytes written as `2 * N` uppercase hex digits.
+    HexUpper, Case::Upper, "hex_upper";
+    /// Decode exactly `N` bytes written as `2 * N` hex digits of either case.
+    Hex, Case::Either, "hex";
+}
+
+fn base64_value(byte: u8, url_safe: bool) -> Option<u8> {
+    match byte {
+        b'A'..=b'Z' => Some(byte - b'A'),
+        b'a'..=b'z' => Some(byte - b'a' + 26),
+        b'0'..=b'9' => Some(byte - b'0' + 52),
+        b'+' if !url_safe => Some(62),
+        b'/' if !url_safe => Some(63),
+        b'-' if url_safe => Some(62),
+        b'_' if url_safe => Some(63),
+        _ => None,
+    }
+}
+
+/// Validate base64 and return the decoded length in bytes.
+///
+/// Unused bits in the final symbol must be zero, so every accepted string is
+/// the canonical encoding of its bytes.
+fn decoded_base64_len(
+    input: &str,
+    url_safe: bool,
+    padding_required: bool,
+    rule: &'static str,
+) -> Result<usize, ValidationError> {
+    let input = input.as_bytes();
+    let symbols = input.len() - input.iter().rev().take_while(|byte| **byte == b'=').count();
+    let (data, padding) = input.split_at(symbols);
+    let remainder = symbols % 4;
+
+    let padding_valid = match (padding.len(), remainder) {
+        (0, 0) => true,
+        (0, 2 | 3) => !padding_required,
+        (padding, remainder @ (2 | 3)) => padding + remainder == 4,
+        _ => false,
+    };
+
+    if remainder == 1 {
+        return Err(ValidationError::new(rule, "invalid_length"));
+    }
+
+    let mut last = 0;
+
+    for byte in data {
+        last = base64_value(*byte, url_safe)
+            .ok_or_else(|| ValidationError::new(rule, "invalid_character"))?;
+    }
+
+    let unused_bits = match remainder {
+        2 => 0b1111,
+        3 => 0b11,
+        _ => 0,
+    };
+
+    if padding_valid && last & unused_bits == 0 {
+        Ok(symbols / 4 * 3 + remainder.saturating_sub(1))
+    } else {
+        Err(ValidationError::new(rule, "invalid_padding"))
+    }
+}
+
+/// Validate standard, padded base64 and return the decoded length.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Base64;
+
+impl Refine<str> for Base64 {
+    type Output<'input>
+        = usize
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "base64"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        decoded_base64_len(input, false, true, "base64")
+    }
+}
+
+/// Validate URL-safe base64 and return the decoded length.
+///
+/// Padding is optional, but when present it must be complete.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Base64Url;
+
+impl Refine<str> for Base64Url {
+    type Output<'input>
+        = usize
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "base64_url"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        decoded_base64_len(input, true, false, "base64_url")
+    }
+}
+
+/// The borrowed components of a SemVer 2.0 version string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SemVerParts<'input> {
+    pub major: &'input str,
+    pub minor: &'input str,
+    pub patch: &'input str,
+    /// The pre-release identifiers after `-`, without the separator.
+    pub pre: Option<&'input str>,
+    /// The build metadata after `+`, without the separator.
+    pub build: Option<&'input str>,
+}
+
+fn numeric_identifier(identifier: &str) -> bool {
+    !identifier.is_empty()
+        && identifier.bytes().all(|byte| byte.is_ascii_digit())
+        && (identifier == "0" || !identifier.starts_with('0'))
+}
+
+fn dot_separated(identifiers: &str, numeric_without_leading_zero: bool) -> bool {
+    identifiers.split('.').all(|identifier| {
+        let alphanumeric = !identifier.is_empty()
+            && identifier
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-');
+        let numeric = identifier.bytes().all(|byte| byte.is_ascii_digit());
+
+        alphanumeric
+            && !(numeric_without_leading_zero && numeric && !numeric_identifier(identifier))
+    })
+}
+
+/// Split a SemVer 2.0 string into borrowed components.
+///
+/// A leading `v` is rejected. Codes are `invalid_core`, `invalid_pre_release`,
+/// and `invalid_build`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SemVer;
+
+impl Refine<str> for SemVer {
+    type Output<'input>
+        = SemVerParts<'input>
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "semver"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        let (version, build) = match input.split_once('+') {
+            Some((version, build)) => (version, Some(build)),
+            None => (input, None),
+        };
+        let (core, pre) = match version.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (version, None),
+        };
+
+        let mut numbers = core.split('.');
+        let (Some(major), Some(minor), Some(patch), None) = (
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+        ) else {
+            return Err(ValidationError::new("semver", "invalid_core"));
+        };
+
+        if ![major, minor, patch].into_iter().all(numeric_identifier) {
+            return Err(ValidationError::new("semver", "invalid_core"));
+        }
+
+        if pre.is_some_and(|pre| !dot_separated(pre, true)) {
+            return Err(ValidationError::new("semver", "invalid_pre_release"));
+        }
+
+        if build.is_some_and(|build| !dot_separated(build, false)) {
+            return Err(ValidationError::new("semver", "invalid_build"));
+        }
+
+        Ok(SemVerParts {
+            major,
+            minor,
+            patch,
+            pre,
+            build,
+        })
+    }
+}
+
+refiner_rule!(
+    Uuid => "uuid",
+    HexLower<const N: usize> => "hex_lower",
+    HexUpper<const N: usize> => "hex_upper",
+    Hex<const N: usize> => "hex",
+    Base64 => "base64",
+    Base64Url => "base64_url",
+    SemVer => "semver",
+);
@@ -2,13 +2,28 @@
 //!
 //! A [`Refine`] implementation may return evidence whose type is indexed by the
 //! lifetime of the borrowed input. [`Prove`] recursively executes an HList of
-//! refiners and computes a matching HList of evidence.
+//! refiners and computes a matching HList of evidence. [`RefineChain`] and
+//! [`ThenRefine`] instead thread each stage's borrowed output into the next
+//! stage.
 
 use crate::hlist::{HCons, HList, HNil};
+use crate::observe::Observer;
 use crate::proof::Witnessed;
-use crate::validate::ValidationError;
+#[cfg(feature = "alloc")]
+use crate::validate::ValidationErrors;
+use crate::validate::{ErrorSink, ValidationError};
 
+pub mod bytes;
+pub mod format;
+pub mod net;
+pub mod owned;
+pub mod parse;
 pub mod refiners;
+pub mod time;
+pub mod token;
+#[cfg(feature = "unicode")]
+pub mod unicode;
+pub mod uri;
 
 /// Refine borrowed input into lifetime-indexed evidence.
 ///
@@ -20,6 +35,16 @@ pub trait Refine<Input: ?Sized> {
     where
         Input: 'input;
 
+    /// The name observers receive for this refiner, whether it succeeds or
+    /// fails.
+    ///
+    /// Defaults to [`core::any::type_name`], which is not stable across
+    /// compiler versions and spells out generic parameters. Built-in refiners
+    /// return the rule name carried by their errors.
+    fn name(&self) -> &'static str {
+        core::any::type_name::<Self>()
+    }
+
     fn refine<'input>(&self, input: &'input Input)
     -> Result<Self::Output<'input>, ValidationError>;
 }
@@ -34,6 +59,59 @@ pub trait Prove<Input: ?Sized>: HList {
         &self,
         input: &'input Input,
     ) -> Result<Self::Evidence<'input>, ValidationError>;
+
+    /// Run every refiner, pushing each failure onto `errors`.
+    ///
+    /// Evidence is returned only when every refiner succeeded. The default
+    /// runs [`prove`](Self::prove) and pushes its single failure. The HList
+    /// implementations run every refiner.
+    fn prove_each<'input, E: ErrorSink + ?Sized>(
+        &self,
+        input: &'input Input,
+        errors: &mut E,
+    ) -> Option<Self::Evidence<'input>> {
+        match self.prove(input) {
+            Ok(evidence) => Some(evidence),
+            Err(error) => {
+                errors.push(error);
+                None
+            }
+        }
+    }
+
+    /// Run [`prove_each`](Self::prove_each), reporting each refiner's outcome
+    /// to `observer`.
+    ///
+    /// The default runs `prove_each` without reporting. The HList
+    /// implementations report every refiner.
+    fn prove_each_observed<'input, E, O>(
+        &self,
+        input: &'input Input,
+        errors: &mut E,
+        observer: &O,
+    ) -> Option<Self::Evidence<'input>>
+    where
+        E: ErrorSink + ?Sized,
+        O: Observer + ?Sized,
+    {
+        let _ = observer;
+        self.prove_each(input, errors)
+    }
+
+    /// Run every refiner and collect all failures instead of stopping at the
+    /// first one.
+    #[cfg(feature = "alloc")]
+    fn prove_all<'input>(
+        &self,
+        input: &'input Input,
+    ) -> Result<Self::Evidence<'input>, ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+
+        match self.prove_each(input, &mut errors) {
+            Some(evidence) if errors.is_empty() => Ok(evidence),
+            _ => Err(errors),
+        }
+    }
 }
 
 impl<Input: ?Sized> Prove<Input> for HNil {
@@ -70,8 +148,296 @@ where
             tail: self.tail.prove(input)?,
         })
     }
+
+    fn prove_each<'input, E: ErrorSink + ?Sized>(
+        &self,
+        input: &'input Input,
+        errors: &mut E,
+    ) -> Option<Self::Evidence<'input>> {
+        let head = match self.head.refine(input) {
+            Ok(evidence) => Some(evidence),
+            Err(error) => {
+                errors.push(error);
+                None
+            }
+        };
+        let tail = self.tail.prove_each(input, errors);
+
+        Some(HCons {
+            head: head?,
+            tail: tail?,
+        })
+    }
+
+    fn prove_each_observed<'input, E, O>(
+        &self,
+        input: &'input Input,
+        errors: &mut E,
+        observer: &O,
+    ) -> Option<Self::Evidence<'input>>
+    where
+        E: ErrorSink + ?Sized,
+        O: Observer + ?Sized,
+    {
+        let refiner = self.head.name();
+        let head = match self.head.refine(input) {
+            Ok(evidence) => {
+                observer.refiner_ran(refiner, Ok(()));
+                Some(evidence)
+            }
+            Err(error) => {
+                observer.refiner_ran(refiner, Err(error));
+                errors.push(error);
+                None
+            }
+        };
+        let tail = self.tail.prove_each_observed(input, errors, observer);
+
+        Some(HCons {
+            head: head?,
+            tail: tail?,
+        })
+    }
+}
+
+/// A refiner whose evidence is a borrowed view into the same input.
+///
+/// Every [`Refine`] implementation whose output is `&'input Target` implements
+/// this trait automatically. Such refiners can feed the next stage of a
+/// [`RefineChain`]. The target type may not borrow from anything else.
+pub trait RefineView<Input: ?Sized>: Refine<Input> {
+    type Target: ?Sized + 'static;
+
+    fn refine_view<'input>(
+        &self,
+        input: &'input Input,
+    ) -> Result<&'input Self::Target, ValidationError>
+    where
+        Input: 'input;
+}
+
+impl<Input, Target, R> RefineView<Input> for R
+where
+    Input: ?Sized + 'static,
+    Target: ?Sized + 'static,
+    R: for<'input> Refine<Input, Output<'input> = &'input Target>,
+{
+    type Target = Target;
+
+    fn refine_view<'input>(&self, input: &'input Input) -> Result<&'input Target, ValidationError>
+    where
+        Input: 'input,
+    {
+        self.refine(input)
+    }
+}
+
+/// Recursive execution of an HList of refiners where each stage refines the
+/// borrowed output of the previous one.
+///
+/// Every stage except the last must implement [`RefineView`]. The last stage
+/// may produce any evidence.
+pub trait RefineSequence<Input: ?Sized>: HList {
+    type Evidence<'input>: HList
+    where
+        Input: 'input;
+
+    fn refine_sequence<'input>(
+        &self,
+        input: &'input Input,
+    ) -> Result<Self::Evidence<'input>, ValidationError>;
+}
+
+impl<Input: ?Sized> RefineSequence<Input> for HNil {
+    type Evidence<'input>
+        = HNil
+    where
+        Input: 'input;
+
+    fn refine_sequence<'input>(
+        &self,
+        _input: &'input Input,
+    ) -> Result<Self::Evidence<'input>, ValidationError> {
+        Ok(HNil)
+    }
+}
+
+impl<Input, Last> RefineSequence<Input> for HCons<Last, HNil>
+where
+    Input: ?Sized,
+    Last: Refine<Input>,
+{
+    type Evidence<'input>
+        = HCons<Last::Output<'input>, HNil>
+    where
+        Input: 'input;
+
+    fn refine_sequence<'input>(
+        &self,
+        input: &'input Input,
+    ) -> Result<Self::Evidence<'input>, ValidationError> {
+        Ok(HCons {
+            head: self.head.refine(input)?,
+            tail: HNil,
+        })
+    }
+}
+
+impl<Input, Head, Next, Rest> RefineSequence<Input> for HCons<Head, HCons<Next, Rest>>
+where
+    Input: ?Sized + 'static,
+    Head: RefineView<Input>,
+    Rest: HList,
+    HCons<Next, Rest>: RefineSequence<Head::Target>,
+{
+    type Evidence<'input>
+        = HCons<
+        &'input Head::Target,
+        <HCons<Next, Rest> as RefineSequence<Head::Target>>::Evidence<'input>,
+    >
+    where
+        Input: 'input;
+
+    fn refine_sequence<'input>(
+        &self,
+        input: &'input Input,
+    ) -> Result<Self::Evidence<'input>, ValidationError> {
+        let view = self.head.refine_view(input)?;
+
+        Ok(HCons {
+            head: view,
+            tail: self.tail.refine_sequence(view)?,
+        })
+    }
 }
 
+/// Thread borrowed input through an HList of refiners in order.
+///
+/// Unlike the refiners of a [`ProofPolicy`], which each see the original input,
+/// each stage of a chain refines the previous stage's borrowed output. The
+/// evidence is an HList holding every stage's output, so the whole chain is
+/// retained in the witness and every view still borrows from the original
+/// input.
+///
+/// ```
+/// use strustegy::prelude::*;
+/// use strustegy::refine::refiners::Checked;
+///
+/// let chain = RefineChain::new(hlist![Utf8, Trimmed, Checked::new(AsciiIdentifier)]);
+/// let bytes = b"  sync_status  ".to_vec();
+/// let hlist_pat![text, trimmed, identifier] = chain.refine(bytes.as_slice()).unwrap();
+///
+/// assert_eq!(text, "  sync_status  ");
+/// assert_eq!(trimmed, "sync_status");
+/// assert_eq!(identifier.as_ptr(), bytes.as_ptr().wrapping_add(2));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RefineChain<Refiners> {
+    refiners: Refiners,
+}
+
+impl<Refiners: HList> RefineChain<Refiners> {
+    pub const fn new(refiners: Refiners) -> Self {
+        Self { refiners }
+    }
+
+    /// Borrow the chained refiners.
+    pub const fn get_ref(&self) -> &Refiners {
+        &self.refiners
+    }
+
+    /// Consume the chain and return its refiners.
+    pub fn into_inner(self) -> Refiners {
+        self.refiners
+    }
+}
+
+impl<Input, Refiners> Refine<Input> for RefineChain<Refiners>
+where
+    Input: ?Sized,
+    Refiners: RefineSequence<Input>,
+{
+    type Output<'input>
+        = Refiners::Evidence<'input>
+    where
+        Input: 'input;
+
+    fn name(&self) -> &'static str {
+        "refine_chain"
+    }
+
+    fn refine<'input>(
+        &self,
+        input: &'input Input,
+    ) -> Result<Self::Output<'input>, ValidationError> {
+        self.refiners.refine_sequence(input)
+    }
+}
+
+/// Refine the borrowed output of `first` with `second`.
+///
+/// This is the two-stage counterpart of [`RefineChain`] and keeps only the
+/// last stage's evidence. Because a chain ending in a view is itself a
+/// [`RefineView`], chains extend with further [`then_refine`] calls.
+///
+/// [`then_refine`]: RefineExt::then_refine
+///
+/// ```
+/// use strustegy::prelude::*;
+/// use strustegy::refine::refiners::Checked;
+///
+/// let chain = Utf8.then_refine(Trimmed).then_refine(Checked::new(AsciiIdentifier));
+/// let bytes = b"  sync_status  ".to_vec();
+/// let identifier = chain.refine(bytes.as_slice()).unwrap();
+///
+/// assert_eq!(identifier, "sync_status");
+/// assert_eq!(identifier.as_ptr(), bytes.as_ptr().wrapping_add(2));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ThenRefine<F, G> {
+    pub first: F,
+    pub second: G,
+}
+
+impl<F, G> ThenRefine<F, G> {
+    pub const fn new(first: F, second: G) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<Input, F, G> Refine<Input> for ThenRefine<F, G>
+where
+    Input: ?Sized + 'static,
+    F: RefineView<Input>,
+    G: Refine<F::Target>,
+{
+    type Output<'input>
+        = G::Output<'input>
+    where
+        Input: 'input;
+
+    fn name(&self) -> &'static str {
+        "then_refine"
+    }
+
+    fn refine<'input>(
+        &self,
+        input: &'input Input,
+    ) -> Result<Self::Output<'input>, ValidationError> {
+        self.second.refine(self.first.refine_view(input)?)
+    }
+}
+
+/// Fluent sequential refinement.
+pub trait RefineExt: Sized {
+    /// Refine this refiner's borrowed output with `next`.
+    fn then_refine<G>(self, next: G) -> ThenRefine<Self, G> {
+        ThenRefine::new(self, next)
+    }
+}
+
+impl<R> RefineExt for R {}
+
 /// A proof policy fixes the exact refiner HList used to produce evidence.
 pub trait ProofPolicy<Input: ?Sized> {
     type Refiners: Prove<Input>;
@@ -98,6 +464,53 @@ where
     Ok(Witnessed::new(input, evidence))
 }
 
+/// Execute every refiner of a policy, collecting all failures.
+///
+/// This is the accumulating counterpart of [`prove`], in the same way that
+/// [`validate_all`](crate::validate_all) accompanies
+/// [`validate_first`](crate::validate_first). Errors retain refiner order.
+#[cfg(feature = "alloc")]
+#[allow(clippy::type_complexity)]
+pub fn prove_all<'input, Policy, Input>(
+    input: &'input Input,
+) -> Result<
+    Witnessed<'input, Input, Policy, <Policy::Refiners as Prove<Input>>::Evidence<'input>>,
+    ValidationErrors,
+>
+where
+    Input: ?Sized + 'input,
+    Policy: ProofPolicy<Input>,
+{
+    let evidence = Policy::refiners().prove_all(input)?;
+    Ok(Witnessed::new(input, evidence))
+}
+
+/// Execute every refiner of a policy like [`prove_all`], reporting each
+/// refiner's outcome to `observer`.
+///
+/// The observer receives refiner names and failure codes, never `input`.
+#[cfg(feature = "alloc")]
+#[allow(clippy::type_complexity)]
+pub fn prove_all_observed<'input, Policy, Input, O>(
+    input: &'input Input,
+    observer: &O,
+) -> Result<
+    Witnessed<'input, Input, Policy, <Policy::Refiners as Prove<Input>>::Evidence<'input>>,
+    ValidationErrors,
+>
+where
+    Input: ?Sized + 'input,
+    Policy: ProofPolicy<Input>,
+    O: Observer + ?Sized,
+{
+    let mut errors = ValidationErrors::new();
+
+    match Policy::refiners().prove_each_observed(input, &mut errors, observer) {
+        Some(evidence) if errors.is_empty() => Ok(Witnessed::new(input, evidence)),
+        _ => Err(errors),
+    }
+}
+
 /// Project a policy's raw heterogeneous evidence into a named domain shape.
 ///
 /// The policy remains responsible for the exact refiner HList, while this trait
@@ -162,3 +575,18 @@ where
     let (input, evidence) = witnessed.into_parts();
     Ok(Policy::project(input, evidence))
 }
+
+/// Execute every refiner of a projecting policy, collecting all failures, and
+/// project the evidence on success.
+#[cfg(feature = "alloc")]
+pub fn prove_projected_all<'input, Policy, Input>(
+    input: &'input Input,
+) -> Result<Policy::Output<'input>, ValidationErrors>
+where
+    Input: ?Sized + 'input,
+    Policy: ProjectEvidence<Input>,
+{
+    let witnessed = prove_all::<Policy, Input>(input)?;
+    let (input, evidence) = witnessed.into_parts();
+    Ok(Policy::project(input, evidence))
+}
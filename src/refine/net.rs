@@ -0,0 +1,195 @@
+//! Refiners for network addresses, ports, and hostnames.
+//!
+//! Addresses parse into [`core::net`] types. Failures use stable codes that
+//! never include the rejected text.
+
+use core::net::{IpAddr, SocketAddr};
+
+use crate::refine::Refine;
+use crate::refine::token::SegmentsView;
+use crate::validate::ValidationError;
+
+/// Parse an IPv4 or IPv6 address.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IpAddrRefiner;
+
+impl Refine<str> for IpAddrRefiner {
+    type Output<'input>
+        = IpAddr
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "ip_addr"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        input
+            .parse()
+            .map_err(|_| ValidationError::new("ip_addr", "invalid_address"))
+    }
+}
+
+/// Parse an IPv4 `address:port` or bracketed IPv6 `[address]:port` pair.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SocketAddrRefiner;
+
+impl Refine<str> for SocketAddrRefiner {
+    type Output<'input>
+        = SocketAddr
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "socket_addr"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        input
+            .parse()
+            .map_err(|_| ValidationError::new("socket_addr", "invalid_address"))
+    }
+}
+
+/// Parse a decimal port number within `MIN..=MAX`.
+///
+/// The default range `1..=65535` excludes port zero. Signs and whitespace are
+/// rejected with `not_a_number`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Port<const MIN: u16 = 1, const MAX: u16 = { u16::MAX }>;
+
+impl<const MIN: u16, const MAX: u16> Refine<str> for Port<MIN, MAX> {
+    type Output<'input>
+        = u16
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "port"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        if input.is_empty() || !input.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(ValidationError::new("port", "not_a_number"));
+        }
+
+        match input.parse::<u16>() {
+            Ok(port) if MIN <= port && port <= MAX => Ok(port),
+            _ => Err(ValidationError::new("port", "out_of_range")),
+        }
+    }
+}
+
+/// Require an RFC 1123 hostname and borrow its dot-separated labels.
+///
+/// Labels are 1 to 63 ASCII letters, digits, or hyphens and may not begin or
+/// end with a hyphen. The whole name is at most 253 bytes, and a trailing dot
+/// is rejected. Codes are `empty`, `too_long`, and `invalid_label`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Hostname;
+
+impl Refine<str> for Hostname {
+    type Output<'input>
+        = SegmentsView<'input, '.'>
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "hostname"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        if input.is_empty() {
+            return Err(ValidationError::new("hostname", "empty"));
+        }
+
+        if input.len() > 253 {
+            return Err(ValidationError::new("hostname", "too_long"));
+        }
+
+        let labels = SegmentsView::new(input);
+        let valid = labels.iter().all(|label| {
+            let bytes = label.as_bytes();
+
+            (1..=63).contains(&bytes.len())
+                && bytes
+                    .iter()
+                    .all(|byte| byte.is_ascii_alphanumeric() || *byte == b'-')
+                && bytes.first() != Some(&b'-')
+                && bytes.last() != Some(&b'-')
+        });
+
+        if valid {
+            Ok(labels)
+        } else {
+            Err(ValidationError::new("hostname", "invalid_label"))
+        }
+    }
+}
+
+/// An IP address and prefix length produced by [`Cidr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CidrBlock {
+    pub address: IpAddr,
+    pub prefix_len: u8,
+}
+
+impl CidrBlock {
+    /// Return whether `address` is in the same family and shares the first
+    /// `prefix_len` bits.
+    pub fn contains(&self, address: IpAddr) -> bool {
+        match (self.address, address) {
+            (IpAddr::V4(network), IpAddr::V4(address)) => {
+                let mask = u32::MAX.checked_shl(32_u32.saturating_sub(self.prefix_len.into()));
+                let mask = mask.unwrap_or(0);
+                u32::from(network) & mask == u32::from(address) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(address)) => {
+                let mask = u128::MAX.checked_shl(128_u32.saturating_sub(self.prefix_len.into()));
+                let mask = mask.unwrap_or(0);
+                u128::from(network) & mask == u128::from(address) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Parse `address/prefix` CIDR notation.
+///
+/// The prefix is at most 32 for IPv4 and 128 for IPv6. Host bits may be set,
+/// so interface notation such as `192.0.2.10/24` is accepted. Codes are
+/// `missing_prefix`, `invalid_address`, and `invalid_prefix`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Cidr;
+
+impl Refine<str> for Cidr {
+    type Output<'input>
+        = CidrBlock
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "cidr"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        let (address, prefix_len) = input
+            .split_once('/')
+            .ok_or_else(|| ValidationError::new("cidr", "missing_prefix"))?;
+        let address: IpAddr = address
+            .parse()
+            .map_err(|_| ValidationError::new("cidr", "invalid_address"))?;
+        let max = if address.is_ipv4() { 32 } else { 128 };
+
+        let prefix_len = Some(prefix_len)
+            .filter(|digits| !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit()))
+            .and_then(|digits| digits.parse::<u8>().ok())
+            .filter(|prefix_len| *prefix_len <= max)
+            .ok_or_else(|| ValidationError::new("cidr", "invalid_prefix"))?;
+
+        Ok(CidrBlock {
+            address,
+            prefix_len,
+        })
+    }
+}
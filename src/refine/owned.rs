@@ -0,0 +1,119 @@
+//! Owned-input refinement for canonicalize-then-prove policies.
+//!
+//! Borrowed [`Refine`](crate::Refine) stages cannot change their input. An
+//! owned refiner takes its input by value and hands a possibly rewritten value
+//! to the next stage, so canonicalization and the checks that depend on it can
+//! live in one policy.
+
+use crate::hlist::{HCons, HList, HNil};
+use crate::proof::Proven;
+use crate::strategy::Strategy;
+use crate::validate::ValidationError;
+
+/// Refine an owned input into an owned output and owned evidence.
+///
+/// `Output` is the value handed to the next stage. `Evidence` is retained in
+/// the resulting [`Proven`] wrapper; stages with nothing to report use `()`.
+pub trait RefineOwned<Input> {
+    type Output;
+    type Evidence;
+
+    fn refine_owned(&self, input: Input)
+    -> Result<(Self::Output, Self::Evidence), ValidationError>;
+}
+
+/// Execute an HList of owned refiners, threading each stage's output into the
+/// next stage and collecting every stage's evidence.
+pub trait ProveOwned<Input>: HList {
+    type Output;
+    type Evidence: HList;
+
+    fn prove_owned(&self, input: Input) -> Result<(Self::Output, Self::Evidence), ValidationError>;
+}
+
+impl<Input> ProveOwned<Input> for HNil {
+    type Output = Input;
+    type Evidence = HNil;
+
+    fn prove_owned(&self, input: Input) -> Result<(Self::Output, Self::Evidence), ValidationError> {
+        Ok((input, HNil))
+    }
+}
+
+impl<Input, Head, Tail> ProveOwned<Input> for HCons<Head, Tail>
+where
+    Head: RefineOwned<Input>,
+    Tail: ProveOwned<Head::Output>,
+{
+    type Output = Tail::Output;
+    type Evidence = HCons<Head::Evidence, Tail::Evidence>;
+
+    fn prove_owned(&self, input: Input) -> Result<(Self::Output, Self::Evidence), ValidationError> {
+        let (intermediate, head) = self.head.refine_owned(input)?;
+        let (output, tail) = self.tail.prove_owned(intermediate)?;
+
+        Ok((output, HCons { head, tail }))
+    }
+}
+
+/// An owned proof policy fixes the exact owned-refiner HList for its proof.
+pub trait OwnedProofPolicy<Input> {
+    type Refiners: ProveOwned<Input>;
+
+    fn refiners() -> Self::Refiners;
+}
+
+/// Execute a policy's owned refiners and return the final value together with
+/// the evidence from every stage.
+#[allow(clippy::type_complexity)]
+pub fn prove_owned<Policy, Input>(
+    input: Input,
+) -> Result<
+    Proven<
+        <Policy::Refiners as ProveOwned<Input>>::Output,
+        Policy,
+        <Policy::Refiners as ProveOwned<Input>>::Evidence,
+    >,
+    ValidationError,
+>
+where
+    Policy: OwnedProofPolicy<Input>,
+{
+    let (value, evidence) = Policy::refiners().prove_owned(input)?;
+    Ok(Proven::new(value, evidence))
+}
+
+/// Run an infallible [`Strategy`] as an owned canonicalization stage.
+///
+/// The strategy's output becomes the next stage's input. The stage produces
+/// `()` evidence.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Canonicalize<S> {
+    strategy: S,
+}
+
+impl<S> Canonicalize<S> {
+    pub const fn new(strategy: S) -> Self {
+        Self { strategy }
+    }
+
+    /// Borrow the wrapped strategy.
+    pub const fn get_ref(&self) -> &S {
+        &self.strategy
+    }
+}
+
+impl<Input, S> RefineOwned<Input> for Canonicalize<S>
+where
+    S: Strategy<Input>,
+{
+    type Output = S::Output;
+    type Evidence = ();
+
+    fn refine_owned(
+        &self,
+        input: Input,
+    ) -> Result<(Self::Output, Self::Evidence), ValidationError> {
+        Ok((self.strategy.apply(input), ()))
+    }
+}
@@ -0,0 +1,388 @@
+//! Refiners that parse borrowed text into owned primitive values.
+//!
+//! Parse failures map to stable, redaction-safe codes: `empty`,
+//! `not_a_number`, `overflow`, `underflow`, `non_finite`, `not_a_boolean`, and
+//! `invalid_syntax`. [`Ensure`] adds a rule check on parsed evidence, so parsing
+//! and range checks share one proof policy.
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::num::{IntErrorKind, ParseIntError};
+use core::str::FromStr;
+
+use crate::refine::Refine;
+use crate::validate::{Rule, ValidationError};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Primitive integer types accepted by [`ParseInt`].
+///
+/// This trait is sealed.
+pub trait Integer: Sized + sealed::Sealed {
+    fn from_str_radix(input: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! integer {
+    ($($integer:ty),* $(,)?) => {
+        $(
+            impl sealed::Sealed for $integer {}
+
+            impl Integer for $integer {
+                fn from_str_radix(input: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    <$integer>::from_str_radix(input, radix)
+                }
+            }
+        )*
+    };
+}
+
+integer!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// Primitive floating-point types accepted by [`ParseFloat`].
+///
+/// This trait is sealed.
+pub trait Float: FromStr + sealed::Sealed {
+    fn is_finite(&self) -> bool;
+}
+
+impl sealed::Sealed for f32 {}
+
+impl Float for f32 {
+    fn is_finite(&self) -> bool {
+        f32::is_finite(*self)
+    }
+}
+
+impl sealed::Sealed for f64 {}
+
+impl Float for f64 {
+    fn is_finite(&self) -> bool {
+        f64::is_finite(*self)
+    }
+}
+
+/// Parse an integer of type `T` in a configured radix.
+///
+/// A leading `+` is accepted, and `-` is accepted for signed types. Whitespace
+/// is not trimmed.
+pub struct ParseInt<T> {
+    radix: u32,
+    _integer: PhantomData<fn() -> T>,
+}
+
+impl<T> ParseInt<T> {
+    /// Parse decimal integers.
+    pub const fn new() -> Self {
+        Self::with_radix(10)
+    }
+
+    /// Parse integers in `radix`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `radix` is outside `2..=36`. In a `const` context this is a
+    /// compile-time error.
+    pub const fn with_radix(radix: u32) -> Self {
+        assert!(2 <= radix && radix <= 36, "radix must be within 2..=36");
+
+        Self {
+            radix,
+            _integer: PhantomData,
+        }
+    }
+
+    pub const fn radix(&self) -> u32 {
+        self.radix
+    }
+}
+
+impl<T> Copy for ParseInt<T> {}
+
+impl<T> Clone for ParseInt<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Default for ParseInt<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for ParseInt<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ParseInt")
+            .field("radix", &self.radix)
+            .finish()
+    }
+}
+
+impl<T: Integer> Refine<str> for ParseInt<T> {
+    type Output<'input>
+        = T
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "parse_int"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        T::from_str_radix(input, self.radix).map_err(|error| {
+            let code = match error.kind() {
+                IntErrorKind::Empty => "empty",
+                IntErrorKind::PosOverflow => "overflow",
+                IntErrorKind::NegOverflow => "underflow",
+                _ => "not_a_number",
+            };
+
+            ValidationError::new("parse_int", code)
+        })
+    }
+}
+
+/// Parse a finite floating-point number of type `T`.
+///
+/// Textual infinities and NaN are rejected with `non_finite`. Decimal values
+/// too large for `T` are rejected with `overflow`, and values too far below
+/// zero with `underflow`, matching [`ParseInt`].
+pub struct ParseFloat<T> {
+    _float: PhantomData<fn() -> T>,
+}
+
+impl<T> ParseFloat<T> {
+    pub const fn new() -> Self {
+        Self {
+            _float: PhantomData,
+        }
+    }
+}
+
+impl<T> Copy for ParseFloat<T> {}
+
+impl<T> Clone for ParseFloat<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Default for ParseFloat<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for ParseFloat<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("ParseFloat")
+    }
+}
+
+impl<T: Float> Refine<str> for ParseFloat<T> {
+    type Output<'input>
+        = T
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "parse_float"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        if input.is_empty() {
+            return Err(ValidationError::new("parse_float", "empty"));
+        }
+
+        let value = input
+            .parse::<T>()
+            .map_err(|_| ValidationError::new("parse_float", "not_a_number"))?;
+
+        if value.is_finite() {
+            return Ok(value);
+        }
+
+        let magnitude = input.strip_prefix(['+', '-']).unwrap_or(input);
+        let textual = ["inf", "infinity", "nan"]
+            .iter()
+            .any(|word| magnitude.eq_ignore_ascii_case(word));
+
+        let code = if textual {
+            "non_finite"
+        } else if input.starts_with('-') {
+            "underflow"
+        } else {
+            "overflow"
+        };
+
+        Err(ValidationError::new("parse_float", code))
+    }
+}
+
+/// Parse a boolean from a configured vocabulary.
+///
+/// The default vocabulary accepts exactly `true` and `false`.
+#[derive(Debug, Clone, Copy)]
+pub struct ParseBool {
+    truthy: &'static [&'static str],
+    falsy: &'static [&'static str],
+    ignore_ascii_case: bool,
+}
+
+impl ParseBool {
+    /// Accept exactly the given words.
+    pub const fn new(truthy: &'static [&'static str], falsy: &'static [&'static str]) -> Self {
+        Self {
+            truthy,
+            falsy,
+            ignore_ascii_case: false,
+        }
+    }
+
+    /// Accept `true`/`yes`/`on`/`1` and `false`/`no`/`off`/`0`, ignoring ASCII
+    /// case.
+    pub const fn permissive() -> Self {
+        Self::new(&["true", "yes", "on", "1"], &["false", "no", "off", "0"]).ignore_ascii_case()
+    }
+
+    /// Compare words without regard to ASCII case.
+    pub const fn ignore_ascii_case(mut self) -> Self {
+        self.ignore_ascii_case = true;
+        self
+    }
+
+    fn matches(&self, words: &[&str], input: &str) -> bool {
+        words.iter().any(|word| {
+            if self.ignore_ascii_case {
+                word.eq_ignore_ascii_case(input)
+            } else {
+                *word == input
+            }
+        })
+    }
+}
+
+impl Default for ParseBool {
+    fn default() -> Self {
+        Self::new(&["true"], &["false"])
+    }
+}
+
+impl Refine<str> for ParseBool {
+    type Output<'input>
+        = bool
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "parse_bool"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        if self.matches(self.truthy, input) {
+            Ok(true)
+        } else if self.matches(self.falsy, input) {
+            Ok(false)
+        } else {
+            Err(ValidationError::new("parse_bool", "not_a_boolean"))
+        }
+    }
+}
+
+/// Parse any [`FromStr`] type, discarding its error value.
+///
+/// The original parse error is dropped so that it cannot echo rejected input.
+pub struct ParseFromStr<T> {
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> ParseFromStr<T> {
+    pub const fn new() -> Self {
+        Self {
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<T> Copy for ParseFromStr<T> {}
+
+impl<T> Clone for ParseFromStr<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Default for ParseFromStr<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> fmt::Debug for ParseFromStr<T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("ParseFromStr")
+    }
+}
+
+impl<T: FromStr> Refine<str> for ParseFromStr<T> {
+    type Output<'input>
+        = T
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "parse_from_str"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        input
+            .parse()
+            .map_err(|_| ValidationError::new("parse_from_str", "invalid_syntax"))
+    }
+}
+
+/// Refine with `P`, then check the resulting evidence with rule `R`.
+///
+/// This lets owned evidence such as a parsed integer meet a range rule such as
+/// [`InclusiveU64`](crate::InclusiveU64) inside the same proof policy.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Ensure<P, R> {
+    refiner: P,
+    rule: R,
+}
+
+impl<P, R> Ensure<P, R> {
+    pub const fn new(refiner: P, rule: R) -> Self {
+        Self { refiner, rule }
+    }
+}
+
+impl<Input, P, R> Refine<Input> for Ensure<P, R>
+where
+    Input: ?Sized + 'static,
+    P: Refine<Input>,
+    R: for<'input> Rule<P::Output<'input>>,
+{
+    type Output<'input>
+        = P::Output<'input>
+    where
+        Input: 'input;
+
+    fn name(&self) -> &'static str {
+        self.refiner.name()
+    }
+
+    fn refine<'input>(
+        &self,
+        input: &'input Input,
+    ) -> Result<Self::Output<'input>, ValidationError> {
+        let evidence = self.refiner.refine(input)?;
+        self.rule.check(&evidence)?;
+        Ok(evidence)
+    }
+}
@@ -1,7 +1,17 @@
 //! Built-in zero-copy refiners.
 
+#[cfg(feature = "alloc")]
+use alloc::borrow::{Cow, ToOwned};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::iter::FusedIterator;
+use core::str::Split;
+#[cfg(feature = "std")]
+use std::path::{Path, PathBuf};
+
 use crate::refine::Refine;
-use crate::validate::ValidationError;
+use crate::refine::owned::RefineOwned;
+use crate::validate::{Rule, ValidationError};
 
 /// Return the trimmed input as a borrowed view.
 #[derive(Debug, Clone, Copy, Default)]
@@ -13,6 +23,10 @@ impl Refine<str> for Trimmed {
     where
         str: 'input;
 
+    fn name(&self) -> &'static str {
+        "trimmed"
+    }
+
     fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
         Ok(input.trim())
     }
@@ -28,6 +42,10 @@ impl Refine<str> for TrimmedNonEmpty {
     where
         str: 'input;
 
+    fn name(&self) -> &'static str {
+        "trimmed_non_empty"
+    }
+
     fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
         let trimmed = input.trim();
 
@@ -49,6 +67,10 @@ impl Refine<str> for TrimmedAsciiIdentifier {
     where
         str: 'input;
 
+    fn name(&self) -> &'static str {
+        "trimmed_ascii_identifier"
+    }
+
     fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
         let trimmed = input.trim();
         let valid = !trimmed.is_empty()
@@ -77,6 +99,10 @@ impl Refine<[u8]> for Utf8 {
     where
         [u8]: 'input;
 
+    fn name(&self) -> &'static str {
+        "utf8"
+    }
+
     fn refine<'input>(&self, input: &'input [u8]) -> Result<Self::Output<'input>, ValidationError> {
         core::str::from_utf8(input).map_err(|_| ValidationError::new("utf8", "invalid_utf8"))
     }
@@ -92,6 +118,10 @@ impl Refine<str> for ByteLen {
     where
         str: 'input;
 
+    fn name(&self) -> &'static str {
+        "byte_len"
+    }
+
     fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
         Ok(input.len())
     }
@@ -103,7 +133,433 @@ impl Refine<[u8]> for ByteLen {
     where
         [u8]: 'input;
 
+    fn name(&self) -> &'static str {
+        "byte_len"
+    }
+
     fn refine<'input>(&self, input: &'input [u8]) -> Result<Self::Output<'input>, ValidationError> {
         Ok(input.len())
     }
 }
+
+/// Run a string validation rule as a refiner that returns the unchanged view.
+///
+/// This lets rules such as [`AsciiIdentifier`](crate::AsciiIdentifier) act as a
+/// stage of a [`RefineChain`](crate::RefineChain), join a proof policy, or check
+/// an owned value between [`RefineOwned`] stages. The rule's own diagnostic is
+/// returned on failure.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Checked<R> {
+    rule: R,
+}
+
+impl<R> Checked<R> {
+    pub const fn new(rule: R) -> Self {
+        Self { rule }
+    }
+
+    /// Borrow the wrapped rule.
+    pub const fn get_ref(&self) -> &R {
+        &self.rule
+    }
+}
+
+impl<R> Refine<str> for Checked<R>
+where
+    R: for<'value> Rule<&'value str>,
+{
+    type Output<'input>
+        = &'input str
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        self.rule.name()
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        self.rule.check(&input)?;
+        Ok(input)
+    }
+}
+
+impl<T, R> RefineOwned<T> for Checked<R>
+where
+    R: Rule<T>,
+{
+    type Output = T;
+    type Evidence = ();
+
+    fn refine_owned(&self, input: T) -> Result<(Self::Output, Self::Evidence), ValidationError> {
+        self.rule.check(&input)?;
+        Ok((input, ()))
+    }
+}
+
+/// Report whether `Cow` evidence had to allocate.
+///
+/// The canonicalizing refiners return [`Cow::Borrowed`] when their input is
+/// already canonical. This lets tests and callers observe that choice.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+pub trait CowExt {
+    /// Return `true` when the evidence owns a newly allocated value.
+    fn allocated(&self) -> bool;
+}
+
+#[cfg(feature = "alloc")]
+impl<B: ?Sized + ToOwned> CowExt for Cow<'_, B> {
+    fn allocated(&self) -> bool {
+        matches!(self, Cow::Owned(_))
+    }
+}
+
+/// Lowercase ASCII letters, borrowing when none are uppercase.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsciiLowercase;
+
+#[cfg(feature = "alloc")]
+impl Refine<str> for AsciiLowercase {
+    type Output<'input>
+        = Cow<'input, str>
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "ascii_lowercase"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        if input.bytes().any(|byte| byte.is_ascii_uppercase()) {
+            Ok(Cow::Owned(input.to_ascii_lowercase()))
+        } else {
+            Ok(Cow::Borrowed(input))
+        }
+    }
+}
+
+/// Trim the input and replace each inner run of whitespace with one ASCII space.
+///
+/// Input that only needs trimming is returned as a borrowed view.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CollapseWhitespace;
+
+#[cfg(feature = "alloc")]
+impl Refine<str> for CollapseWhitespace {
+    type Output<'input>
+        = Cow<'input, str>
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "collapse_whitespace"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        let trimmed = input.trim();
+        let mut previous_was_space = false;
+        let canonical = trimmed.chars().all(|character| {
+            let valid = !character.is_whitespace() || (character == ' ' && !previous_was_space);
+            previous_was_space = character.is_whitespace();
+            valid
+        });
+
+        if canonical {
+            return Ok(Cow::Borrowed(trimmed));
+        }
+
+        let mut collapsed = String::with_capacity(trimmed.len());
+        for word in trimmed.split_whitespace() {
+            if !collapsed.is_empty() {
+                collapsed.push(' ');
+            }
+            collapsed.push_str(word);
+        }
+
+        Ok(Cow::Owned(collapsed))
+    }
+}
+
+/// Remove a configured prefix when present.
+///
+/// Stripping never allocates, so the evidence is always borrowed. The `Cow`
+/// output lets it sit beside the other canonicalizing refiners.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StripPrefix {
+    prefix: &'static str,
+}
+
+#[cfg(feature = "alloc")]
+impl StripPrefix {
+    pub const fn new(prefix: &'static str) -> Self {
+        Self { prefix }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Refine<str> for StripPrefix {
+    type Output<'input>
+        = Cow<'input, str>
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "strip_prefix"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        Ok(Cow::Borrowed(
+            input.strip_prefix(self.prefix).unwrap_or(input),
+        ))
+    }
+}
+
+/// Convert `\r\n` and lone `\r` line endings to `\n`, borrowing when the
+/// input contains no carriage return.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NormalizeLineEndings;
+
+#[cfg(feature = "alloc")]
+impl Refine<str> for NormalizeLineEndings {
+    type Output<'input>
+        = Cow<'input, str>
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "normalize_line_endings"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        if input.contains('\r') {
+            Ok(Cow::Owned(input.replace("\r\n", "\n").replace('\r', "\n")))
+        } else {
+            Ok(Cow::Borrowed(input))
+        }
+    }
+}
+
+/// The normalized, borrowed components of a path accepted by
+/// [`SafeRelativePath`].
+///
+/// Empty and `.` components are skipped, so `a//./b/` has the components `a`
+/// and `b`. No component is `..`, contains a separator or NUL, or starts with
+/// a drive prefix, so [`RelativePath::join_onto`] always stays beneath its
+/// base directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RelativePath<'input> {
+    input: &'input str,
+}
+
+impl<'input> RelativePath<'input> {
+    /// Return the path as supplied, before normalization.
+    pub const fn as_str(self) -> &'input str {
+        self.input
+    }
+
+    pub fn components(self) -> PathComponents<'input> {
+        PathComponents {
+            split: self.input.split('/'),
+        }
+    }
+
+    /// Return the number of normalized components. This is always at least one.
+    pub fn depth(self) -> usize {
+        self.components().count()
+    }
+
+    /// Return the last normalized component.
+    pub fn file_name(self) -> &'input str {
+        self.components().next_back().unwrap_or_default()
+    }
+
+    /// Return the text after the last `.` of the file name.
+    ///
+    /// A leading dot does not start an extension, so `.profile` has none.
+    pub fn extension(self) -> Option<&'input str> {
+        let file_name = self.file_name();
+
+        match file_name.rfind('.') {
+            Some(0) | None => None,
+            Some(dot) => Some(&file_name[dot + 1..]),
+        }
+    }
+
+    /// Join the normalized components onto a trusted base directory.
+    ///
+    /// Requires the `std` feature.
+    #[cfg(feature = "std")]
+    pub fn join_onto(self, base: impl AsRef<Path>) -> PathBuf {
+        let mut path = base.as_ref().to_path_buf();
+        path.extend(self.components());
+        path
+    }
+}
+
+impl<'input> IntoIterator for RelativePath<'input> {
+    type Item = &'input str;
+    type IntoIter = PathComponents<'input>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.components()
+    }
+}
+
+/// An iterator over the normalized components of a [`RelativePath`].
+#[derive(Debug, Clone)]
+pub struct PathComponents<'input> {
+    split: Split<'input, char>,
+}
+
+fn normal_component(component: &&str) -> bool {
+    !matches!(*component, "" | ".")
+}
+
+impl<'input> Iterator for PathComponents<'input> {
+    type Item = &'input str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.split.find(normal_component)
+    }
+}
+
+impl DoubleEndedIterator for PathComponents<'_> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.split.rfind(normal_component)
+    }
+}
+
+impl FusedIterator for PathComponents<'_> {}
+
+fn has_drive_prefix(component: &str) -> bool {
+    matches!(component.as_bytes(), [letter, b':', ..] if letter.is_ascii_alphabetic())
+}
+
+/// Accept a `/`-separated relative path that cannot escape its base directory.
+///
+/// Codes are `nul_byte`, `backslash`, `absolute`, `drive_prefix` (a component
+/// such as `C:`), `parent_dir`, and `empty` when no component remains after
+/// normalization.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SafeRelativePath;
+
+impl Refine<str> for SafeRelativePath {
+    type Output<'input>
+        = RelativePath<'input>
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "safe_relative_path"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        let error = |code| Err(ValidationError::new("safe_relative_path", code));
+
+        if input.contains('\0') {
+            return error("nul_byte");
+        }
+
+        if input.contains('\\') {
+            return error("backslash");
+        }
+
+        if input.starts_with('/') {
+            return error("absolute");
+        }
+
+        for component in input.split('/') {
+            if has_drive_prefix(component) {
+                return error("drive_prefix");
+            }
+
+            if component == ".." {
+                return error("parent_dir");
+            }
+        }
+
+        let path = RelativePath { input };
+
+        if path.components().next().is_none() {
+            error("empty")
+        } else {
+            Ok(path)
+        }
+    }
+}
+
+/// Require the file name of a [`RelativePath`] to have one of a fixed set of
+/// extensions, ignoring ASCII case.
+///
+/// Extensions are listed without the leading dot. A file name without an
+/// extension is rejected with `missing_extension`.
+#[derive(Debug, Clone, Copy)]
+pub struct FileExtensionIn {
+    extensions: &'static [&'static str],
+}
+
+impl FileExtensionIn {
+    pub const fn new(extensions: &'static [&'static str]) -> Self {
+        Self { extensions }
+    }
+
+    pub const fn extensions(&self) -> &'static [&'static str] {
+        self.extensions
+    }
+}
+
+impl Rule<RelativePath<'_>> for FileExtensionIn {
+    fn name(&self) -> &'static str {
+        "file_extension_in"
+    }
+
+    fn check(&self, value: &RelativePath<'_>) -> Result<(), ValidationError> {
+        let extension = value
+            .extension()
+            .ok_or_else(|| ValidationError::new("file_extension_in", "missing_extension"))?;
+
+        if self
+            .extensions
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(extension))
+        {
+            Ok(())
+        } else {
+            Err(ValidationError::new(
+                "file_extension_in",
+                "extension_not_allowed",
+            ))
+        }
+    }
+}
+
+/// Bound the number of normalized components in a [`RelativePath`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaxPathDepth<const MAX: usize>;
+
+impl<const MAX: usize> Rule<RelativePath<'_>> for MaxPathDepth<MAX> {
+    fn name(&self) -> &'static str {
+        "max_path_depth"
+    }
+
+    fn check(&self, value: &RelativePath<'_>) -> Result<(), ValidationError> {
+        if value.components().nth(MAX).is_some() {
+            Err(ValidationError::new("max_path_depth", "too_deep"))
+        } else {
+            Ok(())
+        }
+    }
+}
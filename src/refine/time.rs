@@ -0,0 +1,398 @@
+//! Dependency-free duration and RFC 3339 timestamp refiners.
+//!
+//! Strustegy does not read the system time on its own. [`NotBefore`] and
+//! [`NotAfter`] ask a caller-supplied [`WallClock`], so tests can use a
+//! [`FixedClock`].
+
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::refine::Refine;
+use crate::validate::{Rule, ValidationError};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Duration units in descending order, as nanoseconds.
+const UNITS: [(&str, u128); 7] = [
+    ("d", 86_400 * NANOS_PER_SECOND),
+    ("h", 3_600 * NANOS_PER_SECOND),
+    ("m", 60 * NANOS_PER_SECOND),
+    ("s", NANOS_PER_SECOND),
+    ("ms", 1_000_000),
+    ("us", 1_000),
+    ("ns", 1),
+];
+
+/// Parse a human duration such as `30s`, `1h30m`, or `250ms`.
+///
+/// Units are `d`, `h`, `m`, `s`, `ms`, `us`, and `ns`. Each must appear at
+/// most once, largest first. Codes are `empty`, `invalid_number`,
+/// `invalid_unit`, `unit_order`, and `overflow`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HumanDuration;
+
+impl Refine<str> for HumanDuration {
+    type Output<'input>
+        = Duration
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "human_duration"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        let error = |code| ValidationError::new("human_duration", code);
+
+        if input.is_empty() {
+            return Err(error("empty"));
+        }
+
+        let mut rest = input;
+        let mut previous_unit = None;
+        let mut nanos: u128 = 0;
+
+        while !rest.is_empty() {
+            let digits_end = rest
+                .find(|character: char| !character.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let (digits, after_digits) = rest.split_at(digits_end);
+            let unit_end = after_digits
+                .find(|character: char| character.is_ascii_digit())
+                .unwrap_or(after_digits.len());
+            let (unit, after_unit) = after_digits.split_at(unit_end);
+
+            if digits.is_empty() {
+                return Err(error("invalid_number"));
+            }
+
+            let unit_index = UNITS
+                .iter()
+                .position(|(name, _)| *name == unit)
+                .ok_or_else(|| error("invalid_unit"))?;
+
+            if previous_unit.is_some_and(|previous| unit_index <= previous) {
+                return Err(error("unit_order"));
+            }
+
+            let count: u64 = digits.parse().map_err(|_| error("overflow"))?;
+
+            nanos = u128::from(count)
+                .checked_mul(UNITS[unit_index].1)
+                .and_then(|part| nanos.checked_add(part))
+                .ok_or_else(|| error("overflow"))?;
+            previous_unit = Some(unit_index);
+            rest = after_unit;
+        }
+
+        let seconds = u64::try_from(nanos / NANOS_PER_SECOND).map_err(|_| error("overflow"))?;
+        let subsecond_nanos = (nanos % NANOS_PER_SECOND) as u32;
+
+        Ok(Duration::new(seconds, subsecond_nanos))
+    }
+}
+
+/// Bound a [`Duration`] by a whole number of seconds.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaxDuration<const SECS: u64>;
+
+impl<const SECS: u64> Rule<Duration> for MaxDuration<SECS> {
+    fn name(&self) -> &'static str {
+        "max_duration"
+    }
+
+    fn check(&self, value: &Duration) -> Result<(), ValidationError> {
+        if *value > Duration::from_secs(SECS) {
+            Err(ValidationError::new("max_duration", "too_long"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The date, time, and UTC offset fields of an RFC 3339 timestamp.
+///
+/// Fields keep the offset they were written in; compare instants through
+/// [`Timestamp::unix_seconds`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timestamp {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    /// The second, which is `60` for a leap second.
+    pub second: u8,
+    pub nanosecond: u32,
+    /// The offset from UTC in minutes. `Z` is zero.
+    pub offset_minutes: i16,
+}
+
+/// Count days from 1970-01-01 to a proleptic Gregorian date.
+const fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_from_march = (month + 9) % 12;
+    let day_of_year = (153 * month_from_march + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+impl Timestamp {
+    /// Return the instant as whole seconds since the Unix epoch, in UTC.
+    ///
+    /// A leap second counts as the first second of the next minute.
+    pub const fn unix_seconds(&self) -> i64 {
+        let days = days_from_civil(self.year as i64, self.month as i64, self.day as i64);
+        let seconds = self.hour as i64 * 3_600 + self.minute as i64 * 60 + self.second as i64;
+
+        days * 86_400 + seconds - self.offset_minutes as i64 * 60
+    }
+
+    /// Order this instant against `now`, a duration since the Unix epoch.
+    fn cmp_unix(&self, now: Duration) -> core::cmp::Ordering {
+        let now_seconds = i64::try_from(now.as_secs()).unwrap_or(i64::MAX);
+
+        (self.unix_seconds(), self.nanosecond).cmp(&(now_seconds, now.subsec_nanos()))
+    }
+}
+
+const fn is_leap_year(year: u16) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+const fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Parse exactly `N` ASCII digits.
+fn digits<const N: usize>(bytes: &[u8]) -> Option<u32> {
+    let digits = bytes.get(..N)?;
+
+    digits.iter().try_fold(0, |value, byte| {
+        byte.is_ascii_digit()
+            .then(|| value * 10 + u32::from(byte - b'0'))
+    })
+}
+
+/// Parse an RFC 3339 timestamp such as `2026-10-19T08:30:00.5+02:00`.
+///
+/// The date and time may be separated by `T` or `t`, the fraction may have
+/// one to nine digits, and the offset is `Z`, `z`, or `±HH:MM`. Second `60` is
+/// accepted only at 23:59 UTC, after applying the offset, where leap seconds
+/// occur; the leap-second table itself is not consulted. Codes are
+/// `invalid_syntax`, `invalid_date`, `invalid_time`, and `invalid_offset`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Rfc3339;
+
+impl Refine<str> for Rfc3339 {
+    type Output<'input>
+        = Timestamp
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "rfc3339"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        let error = |code| ValidationError::new("rfc3339", code);
+        let bytes = input.as_bytes();
+
+        if bytes.len() < 20 || !matches!(bytes[10], b'T' | b't') {
+            return Err(error("invalid_syntax"));
+        }
+
+        let (date, time) = (&bytes[..10], &bytes[11..]);
+
+        let (year, month, day) = match (
+            digits::<4>(date),
+            digits::<2>(&date[5..]),
+            digits::<2>(&date[8..]),
+        ) {
+            (Some(year), Some(month), Some(day)) if date[4] == b'-' && date[7] == b'-' => {
+                (year as u16, month as u8, day as u8)
+            }
+            _ => return Err(error("invalid_date")),
+        };
+
+        if !(1..=12).contains(&month) || !(1..=days_in_month(year, month)).contains(&day) {
+            return Err(error("invalid_date"));
+        }
+
+        let (hour, minute, second) = match (
+            digits::<2>(time),
+            digits::<2>(&time[3..]),
+            digits::<2>(&time[6..]),
+        ) {
+            (Some(hour), Some(minute), Some(second)) if time[2] == b':' && time[5] == b':' => {
+                (hour as u8, minute as u8, second as u8)
+            }
+            _ => return Err(error("invalid_time")),
+        };
+
+        if hour > 23 || minute > 59 || second > 60 {
+            return Err(error("invalid_time"));
+        }
+
+        let mut offset = &time[8..];
+        let mut nanosecond = 0;
+
+        if let Some(fraction) = offset.strip_prefix(b".") {
+            let length = fraction
+                .iter()
+                .take_while(|byte| byte.is_ascii_digit())
+                .count();
+
+            if !(1..=9).contains(&length) {
+                return Err(error("invalid_time"));
+            }
+
+            nanosecond = fraction[..length]
+                .iter()
+                .fold(0, |value, byte| value * 10 + u32::from(byte - b'0'))
+                * 10_u32.pow(9 - length as u32);
+            offset = &fraction[length..];
+        }
+
+        let offset_minutes = match offset {
+            [b'Z' | b'z'] => 0,
+            [sign @ (b'+' | b'-'), rest @ ..] if rest.len() == 5 && rest[2] == b':' => {
+                match (digits::<2>(rest), digits::<2>(&rest[3..])) {
+                    (Some(hours), Some(minutes)) if hours <= 23 && minutes <= 59 => {
+                        let minutes = (hours * 60 + minutes) as i16;
+
+                        if *sign == b'-' { -minutes } else { minutes }
+                    }
+                    _ => return Err(error("invalid_offset")),
+                }
+            }
+            _ => return Err(error("invalid_offset")),
+        };
+
+        let utc_minute =
+            (i16::from(hour) * 60 + i16::from(minute) - offset_minutes).rem_euclid(1_440);
+
+        if second == 60 && utc_minute != 23 * 60 + 59 {
+            return Err(error("invalid_time"));
+        }
+
+        Ok(Timestamp {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond,
+            offset_minutes,
+        })
+    }
+}
+
+/// A caller-supplied source of the current wall-clock time.
+pub trait WallClock {
+    /// Return the time elapsed since the Unix epoch.
+    fn now(&self) -> Duration;
+}
+
+impl<C: WallClock + ?Sized> WallClock for &C {
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+}
+
+/// Read the current time from [`SystemTime`].
+///
+/// A system clock set before the Unix epoch reads as the epoch. It implements
+/// [`WallClock`] only with the `std` feature.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl WallClock for SystemClock {
+    fn now(&self) -> Duration {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+}
+
+/// A clock stopped at a fixed time since the Unix epoch, for tests and
+/// replays.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FixedClock {
+    now: Duration,
+}
+
+impl FixedClock {
+    pub const fn new(now: Duration) -> Self {
+        Self { now }
+    }
+}
+
+impl WallClock for FixedClock {
+    fn now(&self) -> Duration {
+        self.now
+    }
+}
+
+/// Reject timestamps earlier than the clock's current time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NotBefore<C = SystemClock> {
+    clock: C,
+}
+
+impl<C> NotBefore<C> {
+    pub const fn new(clock: C) -> Self {
+        Self { clock }
+    }
+}
+
+impl<C: WallClock> Rule<Timestamp> for NotBefore<C> {
+    fn name(&self) -> &'static str {
+        "not_before"
+    }
+
+    fn check(&self, value: &Timestamp) -> Result<(), ValidationError> {
+        if value.cmp_unix(self.clock.now()).is_lt() {
+            Err(ValidationError::new("not_before", "too_early"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Reject timestamps later than the clock's current time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NotAfter<C = SystemClock> {
+    clock: C,
+}
+
+impl<C> NotAfter<C> {
+    pub const fn new(clock: C) -> Self {
+        Self { clock }
+    }
+}
+
+impl<C: WallClock> Rule<Timestamp> for NotAfter<C> {
+    fn name(&self) -> &'static str {
+        "not_after"
+    }
+
+    fn check(&self, value: &Timestamp) -> Result<(), ValidationError> {
+        if value.cmp_unix(self.clock.now()).is_gt() {
+            Err(ValidationError::new("not_after", "too_late"))
+        } else {
+            Ok(())
+        }
+    }
+}
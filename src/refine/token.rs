@@ -0,0 +1,194 @@
+//! Zero-copy tokenizing refiners.
+//!
+//! Every token is a view into the original input. Arity failures share the
+//! codes `missing_token` and `too_many_tokens`; empty tokens that a refiner
+//! rejects use `empty_token`.
+
+use core::str::Split;
+
+use crate::refine::Refine;
+use crate::validate::ValidationError;
+
+/// Split the input at the first `DELIM` into a `(before, after)` pair.
+///
+/// Either side may be empty. Input without `DELIM` is rejected with
+/// `missing_token`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SplitOnce<const DELIM: char>;
+
+impl<const DELIM: char> Refine<str> for SplitOnce<DELIM> {
+    type Output<'input>
+        = (&'input str, &'input str)
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "split_once"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        input
+            .split_once(DELIM)
+            .ok_or_else(|| ValidationError::new("split_once", "missing_token"))
+    }
+}
+
+/// Split the input into exactly `N` non-empty `DELIM`-separated tokens.
+///
+/// Fewer tokens are rejected with `missing_token`, more with
+/// `too_many_tokens`. A leading, trailing, or repeated `DELIM` would produce an
+/// empty token and is rejected with `empty_token`. `SplitExact<DELIM, 0>`
+/// accepts only empty input.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SplitExact<const DELIM: char, const N: usize>;
+
+impl<const DELIM: char, const N: usize> Refine<str> for SplitExact<DELIM, N> {
+    type Output<'input>
+        = [&'input str; N]
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "split_exact"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        let error = |code| Err(ValidationError::new("split_exact", code));
+
+        if input.is_empty() {
+            return if N == 0 {
+                Ok([""; N])
+            } else {
+                error("missing_token")
+            };
+        }
+
+        let mut tokens = input.split(DELIM);
+        let mut missing = false;
+        let array = core::array::from_fn(|_| {
+            tokens.next().unwrap_or_else(|| {
+                missing = true;
+                ""
+            })
+        });
+
+        if missing {
+            error("missing_token")
+        } else if tokens.next().is_some() {
+            error("too_many_tokens")
+        } else if array.iter().any(|token| token.is_empty()) {
+            error("empty_token")
+        } else {
+            Ok(array)
+        }
+    }
+}
+
+/// A borrowed `key` and `value` produced by [`KeyValue`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KeyValuePair<'input> {
+    pub key: &'input str,
+    pub value: &'input str,
+}
+
+/// Split the input at the first `SEP` into a non-empty key and a value.
+///
+/// The value may be empty or contain further separators. Input without `SEP`
+/// is rejected with `missing_token`, and an empty key with `empty_token`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct KeyValue<const SEP: char>;
+
+impl<const SEP: char> Refine<str> for KeyValue<SEP> {
+    type Output<'input>
+        = KeyValuePair<'input>
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "key_value"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        let (key, value) = input
+            .split_once(SEP)
+            .ok_or_else(|| ValidationError::new("key_value", "missing_token"))?;
+
+        if key.is_empty() {
+            Err(ValidationError::new("key_value", "empty_token"))
+        } else {
+            Ok(KeyValuePair { key, value })
+        }
+    }
+}
+
+/// A borrowed view over `DELIM`-separated tokens produced by [`Segments`] and
+/// [`Hostname`](crate::refine::net::Hostname).
+///
+/// The view is `Copy`; each call to [`SegmentsView::iter`] starts a fresh,
+/// allocation-free iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SegmentsView<'input, const DELIM: char> {
+    input: &'input str,
+}
+
+impl<'input, const DELIM: char> SegmentsView<'input, DELIM> {
+    pub(crate) const fn new(input: &'input str) -> Self {
+        Self { input }
+    }
+
+    /// Return the whole tokenized input.
+    pub const fn as_str(self) -> &'input str {
+        self.input
+    }
+
+    pub fn iter(self) -> Split<'input, char> {
+        self.input.split(DELIM)
+    }
+
+    /// Return the number of tokens. This is always at least one.
+    pub fn count(self) -> usize {
+        self.iter().count()
+    }
+}
+
+impl<'input, const DELIM: char> IntoIterator for SegmentsView<'input, DELIM> {
+    type Item = &'input str;
+    type IntoIter = Split<'input, char>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Require one or more non-empty `DELIM`-separated tokens.
+///
+/// Empty input is rejected with `missing_token`. A leading, trailing, or
+/// repeated `DELIM` would produce an empty token and is rejected with
+/// `empty_token`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Segments<const DELIM: char>;
+
+impl<const DELIM: char> Refine<str> for Segments<DELIM> {
+    type Output<'input>
+        = SegmentsView<'input, DELIM>
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "segments"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        if input.is_empty() {
+            return Err(ValidationError::new("segments", "missing_token"));
+        }
+
+        let view = SegmentsView::new(input);
+
+        if view.iter().any(str::is_empty) {
+            Err(ValidationError::new("segments", "empty_token"))
+        } else {
+            Ok(view)
+        }
+    }
+}
@@ -0,0 +1,427 @@
+//! Unicode-aware rules and refiners for display names and identifiers.
+//!
+//! This module requires the `unicode` feature. Its property tables are
+//! generated from the Unicode 14.0.0 Character Database and ship with the
+//! crate, so no runtime data or dependencies are needed. Every rule accepts
+//! any `T: AsRef<str>` and combines with the other string rules in a
+//! [`Policy`](crate::Policy).
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::FusedIterator;
+
+use crate::refine::Refine;
+use crate::validate::{Rule, ValidationError};
+
+#[rustfmt::skip]
+mod tables;
+
+fn lookup<T: Copy>(table: &[(u32, u32, T)], character: char) -> Option<T> {
+    let code_point = u32::from(character);
+
+    table
+        .binary_search_by(|(start, end, _)| {
+            if *end < code_point {
+                Ordering::Less
+            } else if *start > code_point {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|index| table[index].2)
+}
+
+const HANGUL_SYLLABLE_BASE: u32 = 0xAC00;
+const HANGUL_L_BASE: u32 = 0x1100;
+const HANGUL_V_BASE: u32 = 0x1161;
+const HANGUL_T_BASE: u32 = 0x11A7;
+const HANGUL_V_COUNT: u32 = 21;
+const HANGUL_T_COUNT: u32 = 28;
+const HANGUL_SYLLABLE_COUNT: u32 = 19 * HANGUL_V_COUNT * HANGUL_T_COUNT;
+
+fn hangul_syllable_index(character: char) -> Option<u32> {
+    u32::from(character)
+        .checked_sub(HANGUL_SYLLABLE_BASE)
+        .filter(|index| *index < HANGUL_SYLLABLE_COUNT)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GraphemeBreak {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    ExtendedPictographic,
+}
+
+impl GraphemeBreak {
+    fn of(character: char) -> Self {
+        match hangul_syllable_index(character) {
+            Some(index) if index % HANGUL_T_COUNT == 0 => Self::Lv,
+            Some(_) => Self::Lvt,
+            None => lookup(tables::GRAPHEME_BREAK, character).unwrap_or(Self::Other),
+        }
+    }
+}
+
+/// An iterator over the extended grapheme clusters of a string.
+///
+/// Clusters follow the default rules of Unicode Standard Annex #29 and borrow
+/// from the input.
+#[derive(Debug, Clone)]
+pub struct Graphemes<'input> {
+    rest: &'input str,
+}
+
+/// Split `input` into extended grapheme clusters.
+pub fn graphemes(input: &str) -> Graphemes<'_> {
+    Graphemes { rest: input }
+}
+
+impl<'input> Iterator for Graphemes<'input> {
+    type Item = &'input str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use GraphemeBreak::*;
+
+        let mut characters = self.rest.char_indices();
+        let (_, first) = characters.next()?;
+        let mut previous = GraphemeBreak::of(first);
+        let mut pictographic = previous == ExtendedPictographic;
+        let mut pictographic_zwj = false;
+        let mut regional_indicators = usize::from(previous == RegionalIndicator);
+        let mut end = self.rest.len();
+
+        for (index, character) in characters {
+            let next = GraphemeBreak::of(character);
+            let joined = match (previous, next) {
+                (Cr, Lf) => true,
+                (Control | Cr | Lf, _) | (_, Control | Cr | Lf) => false,
+                (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => true,
+                (_, Extend | Zwj | SpacingMark) | (Prepend, _) => true,
+                (Zwj, ExtendedPictographic) => pictographic_zwj,
+                (RegionalIndicator, RegionalIndicator) => regional_indicators % 2 == 1,
+                _ => false,
+            };
+
+            if !joined {
+                end = index;
+                break;
+            }
+
+            pictographic_zwj = next == Zwj && pictographic;
+            pictographic = next == ExtendedPictographic || (next == Extend && pictographic);
+            regional_indicators = if next == RegionalIndicator {
+                regional_indicators + 1
+            } else {
+                0
+            };
+            previous = next;
+        }
+
+        let (cluster, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(cluster)
+    }
+}
+
+impl FusedIterator for Graphemes<'_> {}
+
+/// Count the extended grapheme clusters in the input.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GraphemeCount;
+
+impl Refine<str> for GraphemeCount {
+    type Output<'input>
+        = usize
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "grapheme_count"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        Ok(graphemes(input).count())
+    }
+}
+
+/// Bound a string-like value by its extended grapheme cluster count.
+///
+/// This approximates user-perceived characters more closely than
+/// [`MaxUnicodeScalars`](crate::MaxUnicodeScalars).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaxGraphemes<const MAX: usize>;
+
+impl<T, const MAX: usize> Rule<T> for MaxGraphemes<MAX>
+where
+    T: AsRef<str>,
+{
+    fn name(&self) -> &'static str {
+        "max_graphemes"
+    }
+
+    fn check(&self, value: &T) -> Result<(), ValidationError> {
+        if graphemes(value.as_ref()).nth(MAX).is_some() {
+            Err(ValidationError::new("max_graphemes", "too_long"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn combining_class(character: char) -> u8 {
+    if character < '\u{300}' {
+        0
+    } else {
+        lookup(tables::COMBINING_CLASS, character).unwrap_or(0)
+    }
+}
+
+fn decompose(character: char, output: &mut Vec<char>) {
+    if let Some(index) = hangul_syllable_index(character) {
+        let jamo = [
+            HANGUL_L_BASE + index / (HANGUL_V_COUNT * HANGUL_T_COUNT),
+            HANGUL_V_BASE + index % (HANGUL_V_COUNT * HANGUL_T_COUNT) / HANGUL_T_COUNT,
+            HANGUL_T_BASE + index % HANGUL_T_COUNT,
+        ];
+
+        output.extend(
+            jamo.into_iter()
+                .filter(|jamo| *jamo != HANGUL_T_BASE)
+                .filter_map(char::from_u32),
+        );
+        return;
+    }
+
+    match tables::CANONICAL_DECOMPOSITION.binary_search_by_key(&character, |entry| entry.0) {
+        Ok(index) => {
+            let (_, first, second) = tables::CANONICAL_DECOMPOSITION[index];
+
+            decompose(first, output);
+
+            if let Some(second) = second {
+                decompose(second, output);
+            }
+        }
+        Err(_) => output.push(character),
+    }
+}
+
+fn compose(first: char, second: char) -> Option<char> {
+    let (first_code, second_code) = (u32::from(first), u32::from(second));
+
+    if (HANGUL_L_BASE..HANGUL_L_BASE + 19).contains(&first_code)
+        && (HANGUL_V_BASE..HANGUL_V_BASE + HANGUL_V_COUNT).contains(&second_code)
+    {
+        let l_index = first_code - HANGUL_L_BASE;
+        let v_index = second_code - HANGUL_V_BASE;
+        let index = (l_index * HANGUL_V_COUNT + v_index) * HANGUL_T_COUNT;
+        return char::from_u32(HANGUL_SYLLABLE_BASE + index);
+    }
+
+    if hangul_syllable_index(first).is_some_and(|index| index % HANGUL_T_COUNT == 0)
+        && (HANGUL_T_BASE + 1..HANGUL_T_BASE + HANGUL_T_COUNT).contains(&second_code)
+    {
+        return char::from_u32(first_code + second_code - HANGUL_T_BASE);
+    }
+
+    tables::CANONICAL_COMPOSITION
+        .binary_search_by_key(&(first, second), |entry| (entry.0, entry.1))
+        .ok()
+        .map(|index| tables::CANONICAL_COMPOSITION[index].2)
+}
+
+/// Normalize `input` to NFC.
+fn to_nfc(input: &str) -> Vec<char> {
+    let mut characters = Vec::with_capacity(input.len());
+
+    for character in input.chars() {
+        decompose(character, &mut characters);
+    }
+
+    for index in 1..characters.len() {
+        let mut position = index;
+
+        while position > 0 {
+            let class = combining_class(characters[position]);
+
+            if class == 0 || combining_class(characters[position - 1]) <= class {
+                break;
+            }
+
+            characters.swap(position - 1, position);
+            position -= 1;
+        }
+    }
+
+    let Some(&first) = characters.first() else {
+        return characters;
+    };
+
+    let mut starter = 0;
+    let mut starter_character = first;
+    let mut last_class = match combining_class(first) {
+        0 => 0,
+        _ => u16::MAX,
+    };
+    let mut composed = 1;
+
+    for index in 1..characters.len() {
+        let character = characters[index];
+        let class = u16::from(combining_class(character));
+
+        match compose(starter_character, character) {
+            Some(composite) if last_class < class || last_class == 0 => {
+                characters[starter] = composite;
+                starter_character = composite;
+            }
+            _ => {
+                if class == 0 {
+                    starter = composed;
+                    starter_character = character;
+                }
+
+                last_class = class;
+                characters[composed] = character;
+                composed += 1;
+            }
+        }
+    }
+
+    characters.truncate(composed);
+    characters
+}
+
+/// Return whether `input` is in Normalization Form C.
+///
+/// The NFC quick-check property settles most input without allocating; only
+/// input containing possibly composing marks is normalized in full.
+fn is_nfc(input: &str) -> bool {
+    let mut last_class = 0;
+    let mut maybe = false;
+
+    for character in input.chars() {
+        if character < '\u{300}' {
+            last_class = 0;
+            continue;
+        }
+
+        let class = combining_class(character);
+
+        if class != 0 && last_class > class {
+            return false;
+        }
+
+        match lookup(tables::NFC_QUICK_CHECK, character) {
+            Some(false) => return false,
+            Some(true) => maybe = true,
+            None => {}
+        }
+
+        last_class = class;
+    }
+
+    !maybe || input.chars().eq(to_nfc(input))
+}
+
+/// Require a string-like value to already be in Normalization Form C.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Nfc;
+
+impl<T> Rule<T> for Nfc
+where
+    T: AsRef<str>,
+{
+    fn name(&self) -> &'static str {
+        "nfc"
+    }
+
+    fn check(&self, value: &T) -> Result<(), ValidationError> {
+        if is_nfc(value.as_ref()) {
+            Ok(())
+        } else {
+            Err(ValidationError::new("nfc", "not_normalized"))
+        }
+    }
+}
+
+/// Reject the bidirectional formatting characters that can reorder displayed
+/// text, such as U+202E RIGHT-TO-LEFT OVERRIDE.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoBidiControls;
+
+impl<T> Rule<T> for NoBidiControls
+where
+    T: AsRef<str>,
+{
+    fn name(&self) -> &'static str {
+        "no_bidi_controls"
+    }
+
+    fn check(&self, value: &T) -> Result<(), ValidationError> {
+        let bidi_control = value.as_ref().chars().any(|character| {
+            matches!(
+                character,
+                '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+            )
+        });
+
+        if bidi_control {
+            Err(ValidationError::new("no_bidi_controls", "bidi_control"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Require every character to share at least one script.
+///
+/// This is the single-script test of Unicode Technical Standard #39: Common
+/// and Inherited characters such as digits and punctuation fit any script,
+/// Script_Extensions are honored, and Han may combine with Hiragana and
+/// Katakana, Hangul, or Bopomofo. It rejects the common mixed-script spoofs,
+/// such as a Cyrillic `а` inside a Latin name.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SingleScript;
+
+impl<T> Rule<T> for SingleScript
+where
+    T: AsRef<str>,
+{
+    fn name(&self) -> &'static str {
+        "single_script"
+    }
+
+    fn check(&self, value: &T) -> Result<(), ValidationError> {
+        let mut resolved = [u64::MAX; 3];
+
+        for character in value.as_ref().chars() {
+            let set =
+                lookup(tables::SCRIPT_EXTENSIONS, character).unwrap_or(tables::UNKNOWN_SCRIPT);
+            let scripts = tables::SCRIPT_SETS[usize::from(set)];
+
+            for (resolved, scripts) in resolved.iter_mut().zip(scripts) {
+                *resolved &= scripts;
+            }
+
+            if resolved == [0; 3] {
+                return Err(ValidationError::new("single_script", "mixed_script"));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,1595 @@
+//! Unicode 14.0.0 property tables.
+//!
+//! Generated from the Unicode Character Database. Do not edit by hand.
+
+use super::GraphemeBreak::{self, *};
+
+#[rustfmt::skip]
+pub(super) const GRAPHEME_BREAK: &[(u32, u32, GraphemeBreak)] = &[
+    (0x0, 0x9, Control), (0xA, 0xA, Lf), (0xB, 0xC, Control),
+    (0xD, 0xD, Cr), (0xE, 0x1F, Control), (0x7F, 0x9F, Control),
+    (0xA9, 0xA9, ExtendedPictographic), (0xAD, 0xAD, Control), (0xAE, 0xAE, ExtendedPictographic),
+    (0x300, 0x36F, Extend), (0x483, 0x489, Extend), (0x591, 0x5BD, Extend),
+    (0x5BF, 0x5BF, Extend), (0x5C1, 0x5C2, Extend), (0x5C4, 0x5C5, Extend),
+    (0x5C7, 0x5C7, Extend), (0x600, 0x605, Prepend), (0x610, 0x61A, Extend),
+    (0x61C, 0x61C, Control), (0x64B, 0x65F, Extend), (0x670, 0x670, Extend),
+    (0x6D6, 0x6DC, Extend), (0x6DD, 0x6DD, Prepend), (0x6DF, 0x6E4, Extend),
+    (0x6E7, 0x6E8, Extend), (0x6EA, 0x6ED, Extend), (0x70F, 0x70F, Prepend),
+    (0x711, 0x711, Extend), (0x730, 0x74A, Extend), (0x7A6, 0x7B0, Extend),
+    (0x7EB, 0x7F3, Extend), (0x7FD, 0x7FD, Extend), (0x816, 0x819, Extend),
+    (0x81B, 0x823, Extend), (0x825, 0x827, Extend), (0x829, 0x82D, Extend),
+    (0x859, 0x85B, Extend), (0x890, 0x891, Prepend), (0x898, 0x89F, Extend),
+    (0x8CA, 0x8E1, Extend), (0x8E2, 0x8E2, Prepend), (0x8E3, 0x902, Extend),
+    (0x903, 0x903, SpacingMark), (0x93A, 0x93A, Extend), (0x93B, 0x93B, SpacingMark),
+    (0x93C, 0x93C, Extend), (0x93E, 0x940, SpacingMark), (0x941, 0x948, Extend),
+    (0x949, 0x94C, SpacingMark), (0x94D, 0x94D, Extend), (0x94E, 0x94F, SpacingMark),
+    (0x951, 0x957, Extend), (0x962, 0x963, Extend), (0x981, 0x981, Extend),
+    (0x982, 0x983, SpacingMark), (0x9BC, 0x9BC, Extend), (0x9BE, 0x9BE, Extend),
+    (0x9BF, 0x9C0, SpacingMark), (0x9C1, 0x9C4, Extend), (0x9C7, 0x9C8, SpacingMark),
+    (0x9CB, 0x9CC, SpacingMark), (0x9CD, 0x9CD, Extend), (0x9D7, 0x9D7, Extend),
+    (0x9E2, 0x9E3, Extend), (0x9FE, 0x9FE, Extend), (0xA01, 0xA02, Extend),
+    (0xA03, 0xA03, SpacingMark), (0xA3C, 0xA3C, Extend), (0xA3E, 0xA40, SpacingMark),
+    (0xA41, 0xA42, Extend), (0xA47, 0xA48, Extend), (0xA4B, 0xA4D, Extend),
+    (0xA51, 0xA51, Extend), (0xA70, 0xA71, Extend), (0xA75, 0xA75, Extend),
+    (0xA81, 0xA82, Extend), (0xA83, 0xA83, SpacingMark), (0xABC, 0xABC, Extend),
+    (0xABE, 0xAC0, SpacingMark), (0xAC1, 0xAC5, Extend), (0xAC7, 0xAC8, Extend),
+    (0xAC9, 0xAC9, SpacingMark), (0xACB, 0xACC, SpacingMark), (0xACD, 0xACD, Extend),
+    (0xAE2, 0xAE3, Extend), (0xAFA, 0xAFF, Extend), (0xB01, 0xB01, Extend),
+    (0xB02, 0xB03, SpacingMark), (0xB3C, 0xB3C, Extend), (0xB3E, 0xB3F, Extend),
+    (0xB40, 0xB40, SpacingMark), (0xB41, 0xB44, Extend), (0xB47, 0xB48, SpacingMark),
+    (0xB4B, 0xB4C, SpacingMark), (0xB4D, 0xB4D, Extend), (0xB55, 0xB57, Extend),
+    (0xB62, 0xB63, Extend), (0xB82, 0xB82, Extend), (0xBBE, 0xBBE, Extend),
+    (0xBBF, 0xBBF, SpacingMark), (0xBC0, 0xBC0, Extend), (0xBC1, 0xBC2, SpacingMark),
+    (0xBC6, 0xBC8, SpacingMark), (0xBCA, 0xBCC, SpacingMark), (0xBCD, 0xBCD, Extend),
+    (0xBD7, 0xBD7, Extend), (0xC00, 0xC00, Extend), (0xC01, 0xC03, SpacingMark),
+    (0xC04, 0xC04, Extend), (0xC3C, 0xC3C, Extend), (0xC3E, 0xC40, Extend),
+    (0xC41, 0xC44, SpacingMark), (0xC46, 0xC48, Extend), (0xC4A, 0xC4D, Extend),
+    (0xC55, 0xC56, Extend), (0xC62, 0xC63, Extend), (0xC81, 0xC81, Extend),
+    (0xC82, 0xC83, SpacingMark), (0xCBC, 0xCBC, Extend), (0xCBE, 0xCBE, SpacingMark),
+    (0xCBF, 0xCBF, Extend), (0xCC0, 0xCC1, SpacingMark), (0xCC2, 0xCC2, Extend),
+    (0xCC3, 0xCC4, SpacingMark), (0xCC6, 0xCC6, Extend), (0xCC7, 0xCC8, SpacingMark),
+    (0xCCA, 0xCCB, SpacingMark), (0xCCC, 0xCCD, Extend), (0xCD5, 0xCD6, Extend),
+    (0xCE2, 0xCE3, Extend), (0xD00, 0xD01, Extend), (0xD02, 0xD03, SpacingMark),
+    (0xD3B, 0xD3C, Extend), (0xD3E, 0xD3E, Extend), (0xD3F, 0xD40, SpacingMark),
+    (0xD41, 0xD44, Extend), (0xD46, 0xD48, SpacingMark), (0xD4A, 0xD4C, SpacingMark),
+    (0xD4D, 0xD4D, Extend), (0xD4E, 0xD4E, Prepend), (0xD57, 0xD57, Extend),
+    (0xD62, 0xD63, Extend), (0xD81, 0xD81, Extend), (0xD82, 0xD83, SpacingMark),
+    (0xDCA, 0xDCA, Extend), (0xDCF, 0xDCF, Extend), (0xDD0, 0xDD1, SpacingMark),
+    (0xDD2, 0xDD4, Extend), (0xDD6, 0xDD6, Extend), (0xDD8, 0xDDE, SpacingMark),
+    (0xDDF, 0xDDF, Extend), (0xDF2, 0xDF3, SpacingMark), (0xE31, 0xE31, Extend),
+    (0xE33, 0xE33, SpacingMark), (0xE34, 0xE3A, Extend), (0xE47, 0xE4E, Extend),
+    (0xEB1, 0xEB1, Extend), (0xEB3, 0xEB3, SpacingMark), (0xEB4, 0xEBC, Extend),
+    (0xEC8, 0xECD, Extend), (0xF18, 0xF19, Extend), (0xF35, 0xF35, Extend),
+    (0xF37, 0xF37, Extend), (0xF39, 0xF39, Extend), (0xF3E, 0xF3F, SpacingMark),
+    (0xF71, 0xF7E, Extend), (0xF7F, 0xF7F, SpacingMark), (0xF80, 0xF84, Extend),
+    (0xF86, 0xF87, Extend), (0xF8D, 0xF97, Extend), (0xF99, 0xFBC, Extend),
+    (0xFC6, 0xFC6, Extend), (0x102D, 0x1030, Extend), (0x1031, 0x1031, SpacingMark),
+    (0x1032, 0x1037, Extend), (0x1039, 0x103A, Extend), (0x103B, 0x103C, SpacingMark),
+    (0x103D, 0x103E, Extend), (0x1056, 0x1057, SpacingMark), (0x1058, 0x1059, Extend),
+    (0x105E, 0x1060, Extend), (0x1071, 0x1074, Extend), (0x1082, 0x1082, Extend),
+    (0x1084, 0x1084, SpacingMark), (0x1085, 0x1086, Extend), (0x108D, 0x108D, Extend),
+    (0x109D, 0x109D, Extend), (0x1100, 0x115F, L), (0x1160, 0x11A7, V),
+    (0x11A8, 0x11FF, T), (0x135D, 0x135F, Extend), (0x1712, 0x1714, Extend),
+    (0x1715, 0x1715, SpacingMark), (0x1732, 0x1733, Extend), (0x1734, 0x1734, SpacingMark),
+    (0x1752, 0x1753, Extend), (0x1772, 0x1773, Extend), (0x17B4, 0x17B5, Extend),
+    (0x17B6, 0x17B6, SpacingMark), (0x17B7, 0x17BD, Extend), (0x17BE, 0x17C5, SpacingMark),
+    (0x17C6, 0x17C6, Extend), (0x17C7, 0x17C8, SpacingMark), (0x17C9, 0x17D3, Extend),
+    (0x17DD, 0x17DD, Extend), (0x180B, 0x180D, Extend), (0x180E, 0x180E, Control),
+    (0x180F, 0x180F, Extend), (0x1885, 0x1886, Extend), (0x18A9, 0x18A9, Extend),
+    (0x1920, 0x1922, Extend), (0x1923, 0x1926, SpacingMark), (0x1927, 0x1928, Extend),
+    (0x1929, 0x192B, SpacingMark), (0x1930, 0x1931, SpacingMark), (0x1932, 0x1932, Extend),
+    (0x1933, 0x1938, SpacingMark), (0x1939, 0x193B, Extend), (0x1A17, 0x1A18, Extend),
+    (0x1A19, 0x1A1A, SpacingMark), (0x1A1B, 0x1A1B, Extend), (0x1A55, 0x1A55, SpacingMark),
+    (0x1A56, 0x1A56, Extend), (0x1A57, 0x1A57, SpacingMark), (0x1A58, 0x1A5E, Extend),
+    (0x1A60, 0x1A60, Extend), (0x1A62, 0x1A62, Extend), (0x1A65, 0x1A6C, Extend),
+    (0x1A6D, 0x1A72, SpacingMark), (0x1A73, 0x1A7C, Extend), (0x1A7F, 0x1A7F, Extend),
+    (0x1AB0, 0x1ACE, Extend), (0x1B00, 0x1B03, Extend), (0x1B04, 0x1B04, SpacingMark),
+    (0x1B34, 0x1B3A, Extend), (0x1B3B, 0x1B3B, SpacingMark), (0x1B3C, 0x1B3C, Extend),
+    (0x1B3D, 0x1B41, SpacingMark), (0x1B42, 0x1B42, Extend), (0x1B43, 0x1B44, SpacingMark),
+    (0x1B6B, 0x1B73, Extend), (0x1B80, 0x1B81, Extend), (0x1B82, 0x1B82, SpacingMark),
+    (0x1BA1, 0x1BA1, SpacingMark), (0x1BA2, 0x1BA5, Extend), (0x1BA6, 0x1BA7, SpacingMark),
+    (0x1BA8, 0x1BA9, Extend), (0x1BAA, 0x1BAA, SpacingMark), (0x1BAB, 0x1BAD, Extend),
+    (0x1BE6, 0x1BE6, Extend), (0x1BE7, 0x1BE7, SpacingMark), (0x1BE8, 0x1BE9, Extend),
+    (0x1BEA, 0x1BEC, SpacingMark), (0x1BED, 0x1BED, Extend), (0x1BEE, 0x1BEE, SpacingMark),
+    (0x1BEF, 0x1BF1, Extend), (0x1BF2, 0x1BF3, SpacingMark), (0x1C24, 0x1C2B, SpacingMark),
+    (0x1C2C, 0x1C33, Extend), (0x1C34, 0x1C35, SpacingMark), (0x1C36, 0x1C37, Extend),
+    (0x1CD0, 0x1CD2, Extend), (0x1CD4, 0x1CE0, Extend), (0x1CE1, 0x1CE1, SpacingMark),
+    (0x1CE2, 0x1CE8, Extend), (0x1CED, 0x1CED, Extend), (0x1CF4, 0x1CF4, Extend),
+    (0x1CF7, 0x1CF7, SpacingMark), (0x1CF8, 0x1CF9, Extend), (0x1DC0, 0x1DFF, Extend),
+    (0x200B, 0x200B, Control), (0x200C, 0x200C, Extend), (0x200D, 0x200D, Zwj),
+    (0x200E, 0x200F, Control), (0x2028, 0x202E, Control), (0x203C, 0x203C, ExtendedPictographic),
+    (0x2049, 0x2049, ExtendedPictographic), (0x2060, 0x206F, Control), (0x20D0, 0x20F0, Extend),
+    (0x2122, 0x2122, ExtendedPictographic), (0x2139, 0x2139, ExtendedPictographic), (0x2194, 0x2199, ExtendedPictographic),
+    (0x21A9, 0x21AA, ExtendedPictographic), (0x231A, 0x231B, ExtendedPictographic), (0x2328, 0x2328, ExtendedPictographic),
+    (0x2388, 0x2388, ExtendedPictographic), (0x23CF, 0x23CF, ExtendedPictographic), (0x23E9, 0x23F3, ExtendedPictographic),
+    (0x23F8, 0x23FA, ExtendedPictographic), (0x24C2, 0x24C2, ExtendedPictographic), (0x25AA, 0x25AB, ExtendedPictographic),
+    (0x25B6, 0x25B6, ExtendedPictographic), (0x25C0, 0x25C0, ExtendedPictographic), (0x25FB, 0x25FE, ExtendedPictographic),
+    (0x2600, 0x2605, ExtendedPictographic), (0x2607, 0x2612, ExtendedPictographic), (0x2614, 0x2685, ExtendedPictographic),
+    (0x2690, 0x2705, ExtendedPictographic), (0x2708, 0x2712, ExtendedPictographic), (0x2714, 0x2714, ExtendedPictographic),
+    (0x2716, 0x2716, ExtendedPictographic), (0x271D, 0x271D, ExtendedPictographic), (0x2721, 0x2721, ExtendedPictographic),
+    (0x2728, 0x2728, ExtendedPictographic), (0x2733, 0x2734, ExtendedPictographic), (0x2744, 0x2744, ExtendedPictographic),
+    (0x2747, 0x2747, ExtendedPictographic), (0x274C, 0x274C, ExtendedPictographic), (0x274E, 0x274E, ExtendedPictographic),
+    (0x2753, 0x2755, ExtendedPictographic), (0x2757, 0x2757, ExtendedPictographic), (0x2763, 0x2767, ExtendedPictographic),
+    (0x2795, 0x2797, ExtendedPictographic), (0x27A1, 0x27A1, ExtendedPictographic), (0x27B0, 0x27B0, ExtendedPictographic),
+    (0x27BF, 0x27BF, ExtendedPictographic), (0x2934, 0x2935, ExtendedPictographic), (0x2B05, 0x2B07, ExtendedPictographic),
+    (0x2B1B, 0x2B1C, ExtendedPictographic), (0x2B50, 0x2B50, ExtendedPictographic), (0x2B55, 0x2B55, ExtendedPictographic),
+    (0x2CEF, 0x2CF1, Extend), (0x2D7F, 0x2D7F, Extend), (0x2DE0, 0x2DFF, Extend),
+    (0x302A, 0x302F, Extend), (0x3030, 0x3030, ExtendedPictographic), (0x303D, 0x303D, ExtendedPictographic),
+    (0x3099, 0x309A, Extend), (0x3297, 0x3297, ExtendedPictographic), (0x3299, 0x3299, ExtendedPictographic),
+    (0xA66F, 0xA672, Extend), (0xA674, 0xA67D, Extend), (0xA69E, 0xA69F, Extend),
+    (0xA6F0, 0xA6F1, Extend), (0xA802, 0xA802, Extend), (0xA806, 0xA806, Extend),
+    (0xA80B, 0xA80B, Extend), (0xA823, 0xA824, SpacingMark), (0xA825, 0xA826, Extend),
+    (0xA827, 0xA827, SpacingMark), (0xA82C, 0xA82C, Extend), (0xA880, 0xA881, SpacingMark),
+    (0xA8B4, 0xA8C3, SpacingMark), (0xA8C4, 0xA8C5, Extend), (0xA8E0, 0xA8F1, Extend),
+    (0xA8FF, 0xA8FF, Extend), (0xA926, 0xA92D, Extend), (0xA947, 0xA951, Extend),
+    (0xA952, 0xA953, SpacingMark), (0xA960, 0xA97C, L), (0xA980, 0xA982, Extend),
+    (0xA983, 0xA983, SpacingMark), (0xA9B3, 0xA9B3, Extend), (0xA9B4, 0xA9B5, SpacingMark),
+    (0xA9B6, 0xA9B9, Extend), (0xA9BA, 0xA9BB, SpacingMark), (0xA9BC, 0xA9BD, Extend),
+    (0xA9BE, 0xA9C0, SpacingMark), (0xA9E5, 0xA9E5, Extend), (0xAA29, 0xAA2E, Extend),
+    (0xAA2F, 0xAA30, SpacingMark), (0xAA31, 0xAA32, Extend), (0xAA33, 0xAA34, SpacingMark),
+    (0xAA35, 0xAA36, Extend), (0xAA43, 0xAA43, Extend), (0xAA4C, 0xAA4C, Extend),
+    (0xAA4D, 0xAA4D, SpacingMark), (0xAA7C, 0xAA7C, Extend), (0xAAB0, 0xAAB0, Extend),
+    (0xAAB2, 0xAAB4, Extend), (0xAAB7, 0xAAB8, Extend), (0xAABE, 0xAABF, Extend),
+    (0xAAC1, 0xAAC1, Extend), (0xAAEB, 0xAAEB, SpacingMark), (0xAAEC, 0xAAED, Extend),
+    (0xAAEE, 0xAAEF, SpacingMark), (0xAAF5, 0xAAF5, SpacingMark), (0xAAF6, 0xAAF6, Extend),
+    (0xABE3, 0xABE4, SpacingMark), (0xABE5, 0xABE5, Extend), (0xABE6, 0xABE7, SpacingMark),
+    (0xABE8, 0xABE8, Extend), (0xABE9, 0xABEA, SpacingMark), (0xABEC, 0xABEC, SpacingMark),
+    (0xABED, 0xABED, Extend), (0xD7B0, 0xD7C6, V), (0xD7CB, 0xD7FB, T),
+    (0xFB1E, 0xFB1E, Extend), (0xFE00, 0xFE0F, Extend), (0xFE20, 0xFE2F, Extend),
+    (0xFEFF, 0xFEFF, Control), (0xFF9E, 0xFF9F, Extend), (0xFFF0, 0xFFFB, Control),
+    (0x101FD, 0x101FD, Extend), (0x102E0, 0x102E0, Extend), (0x10376, 0x1037A, Extend),
+    (0x10A01, 0x10A03, Extend), (0x10A05, 0x10A06, Extend), (0x10A0C, 0x10A0F, Extend),
+    (0x10A38, 0x10A3A, Extend), (0x10A3F, 0x10A3F, Extend), (0x10AE5, 0x10AE6, Extend),
+    (0x10D24, 0x10D27, Extend), (0x10EAB, 0x10EAC, Extend), (0x10F46, 0x10F50, Extend),
+    (0x10F82, 0x10F85, Extend), (0x11000, 0x11000, SpacingMark), (0x11001, 0x11001, Extend),
+    (0x11002, 0x11002, SpacingMark), (0x11038, 0x11046, Extend), (0x11070, 0x11070, Extend),
+    (0x11073, 0x11074, Extend), (0x1107F, 0x11081, Extend), (0x11082, 0x11082, SpacingMark),
+    (0x110B0, 0x110B2, SpacingMark), (0x110B3, 0x110B6, Extend), (0x110B7, 0x110B8, SpacingMark),
+    (0x110B9, 0x110BA, Extend), (0x110BD, 0x110BD, Prepend), (0x110C2, 0x110C2, Extend),
+    (0x110CD, 0x110CD, Prepend), (0x11100, 0x11102, Extend), (0x11127, 0x1112B, Extend),
+    (0x1112C, 0x1112C, SpacingMark), (0x1112D, 0x11134, Extend), (0x11145, 0x11146, SpacingMark),
+    (0x11173, 0x11173, Extend), (0x11180, 0x11181, Extend), (0x11182, 0x11182, SpacingMark),
+    (0x111B3, 0x111B5, SpacingMark), (0x111B6, 0x111BE, Extend), (0x111BF, 0x111C0, SpacingMark),
+    (0x111C2, 0x111C3, Prepend), (0x111C9, 0x111CC, Extend), (0x111CE, 0x111CE, SpacingMark),
+    (0x111CF, 0x111CF, Extend), (0x1122C, 0x1122E, SpacingMark), (0x1122F, 0x11231, Extend),
+    (0x11232, 0x11233, SpacingMark), (0x11234, 0x11234, Extend), (0x11235, 0x11235, SpacingMark),
+    (0x11236, 0x11237, Extend), (0x1123E, 0x1123E, Extend), (0x112DF, 0x112DF, Extend),
+    (0x112E0, 0x112E2, SpacingMark), (0x112E3, 0x112EA, Extend), (0x11300, 0x11301, Extend),
+    (0x11302, 0x11303, SpacingMark), (0x1133B, 0x1133C, Extend), (0x1133E, 0x1133E, Extend),
+    (0x1133F, 0x1133F, SpacingMark), (0x11340, 0x11340, Extend), (0x11341, 0x11344, SpacingMark),
+    (0x11347, 0x11348, SpacingMark), (0x1134B, 0x1134D, SpacingMark), (0x11357, 0x11357, Extend),
+    (0x11362, 0x11363, SpacingMark), (0x11366, 0x1136C, Extend), (0x11370, 0x11374, Extend),
+    (0x11435, 0x11437, SpacingMark), (0x11438, 0x1143F, Extend), (0x11440, 0x11441, SpacingMark),
+    (0x11442, 0x11444, Extend), (0x11445, 0x11445, SpacingMark), (0x11446, 0x11446, Extend),
+    (0x1145E, 0x1145E, Extend), (0x114B0, 0x114B0, Extend), (0x114B1, 0x114B2, SpacingMark),
+    (0x114B3, 0x114B8, Extend), (0x114B9, 0x114B9, SpacingMark), (0x114BA, 0x114BA, Extend),
+    (0x114BB, 0x114BC, SpacingMark), (0x114BD, 0x114BD, Extend), (0x114BE, 0x114BE, SpacingMark),
+    (0x114BF, 0x114C0, Extend), (0x114C1, 0x114C1, SpacingMark), (0x114C2, 0x114C3, Extend),
+    (0x115AF, 0x115AF, Extend), (0x115B0, 0x115B1, SpacingMark), (0x115B2, 0x115B5, Extend),
+    (0x115B8, 0x115BB, SpacingMark), (0x115BC, 0x115BD, Extend), (0x115BE, 0x115BE, SpacingMark),
+    (0x115BF, 0x115C0, Extend), (0x115DC, 0x115DD, Extend), (0x11630, 0x11632, SpacingMark),
+    (0x11633, 0x1163A, Extend), (0x1163B, 0x1163C, SpacingMark), (0x1163D, 0x1163D, Extend),
+    (0x1163E, 0x1163E, SpacingMark), (0x1163F, 0x11640, Extend), (0x116AB, 0x116AB, Extend),
+    (0x116AC, 0x116AC, SpacingMark), (0x116AD, 0x116AD, Extend), (0x116AE, 0x116AF, SpacingMark),
+    (0x116B0, 0x116B5, Extend), (0x116B6, 0x116B6, SpacingMark), (0x116B7, 0x116B7, Extend),
+    (0x1171D, 0x1171F, Extend), (0x11722, 0x11725, Extend), (0x11726, 0x11726, SpacingMark),
+    (0x11727, 0x1172B, Extend), (0x1182C, 0x1182E, SpacingMark), (0x1182F, 0x11837, Extend),
+    (0x11838, 0x11838, SpacingMark), (0x11839, 0x1183A, Extend), (0x11930, 0x11930, Extend),
+    (0x11931, 0x11935, SpacingMark), (0x11937, 0x11938, SpacingMark), (0x1193B, 0x1193C, Extend),
+    (0x1193D, 0x1193D, SpacingMark), (0x1193E, 0x1193E, Extend), (0x1193F, 0x1193F, Prepend),
+    (0x11940, 0x11940, SpacingMark), (0x11941, 0x11941, Prepend), (0x11942, 0x11942, SpacingMark),
+    (0x11943, 0x11943, Extend), (0x119D1, 0x119D3, SpacingMark), (0x119D4, 0x119D7, Extend),
+    (0x119DA, 0x119DB, Extend), (0x119DC, 0x119DF, SpacingMark), (0x119E0, 0x119E0, Extend),
+    (0x119E4, 0x119E4, SpacingMark), (0x11A01, 0x11A0A, Extend), (0x11A33, 0x11A38, Extend),
+    (0x11A39, 0x11A39, SpacingMark), (0x11A3A, 0x11A3A, Prepend), (0x11A3B, 0x11A3E, Extend),
+    (0x11A47, 0x11A47, Extend), (0x11A51, 0x11A56, Extend), (0x11A57, 0x11A58, SpacingMark),
+    (0x11A59, 0x11A5B, Extend), (0x11A84, 0x11A89, Prepend), (0x11A8A, 0x11A96, Extend),
+    (0x11A97, 0x11A97, SpacingMark), (0x11A98, 0x11A99, Extend), (0x11C2F, 0x11C2F, SpacingMark),
+    (0x11C30, 0x11C36, Extend), (0x11C38, 0x11C3D, Extend), (0x11C3E, 0x11C3E, SpacingMark),
+    (0x11C3F, 0x11C3F, Extend), (0x11C92, 0x11CA7, Extend), (0x11CA9, 0x11CA9, SpacingMark),
+    (0x11CAA, 0x11CB0, Extend), (0x11CB1, 0x11CB1, SpacingMark), (0x11CB2, 0x11CB3, Extend),
+    (0x11CB4, 0x11CB4, SpacingMark), (0x11CB5, 0x11CB6, Extend), (0x11D31, 0x11D36, Extend),
+    (0x11D3A, 0x11D3A, Extend), (0x11D3C, 0x11D3D, Extend), (0x11D3F, 0x11D45, Extend),
+    (0x11D46, 0x11D46, Prepend), (0x11D47, 0x11D47, Extend), (0x11D8A, 0x11D8E, SpacingMark),
+    (0x11D90, 0x11D91, Extend), (0x11D93, 0x11D94, SpacingMark), (0x11D95, 0x11D95, Extend),
+    (0x11D96, 0x11D96, SpacingMark), (0x11D97, 0x11D97, Extend), (0x11EF3, 0x11EF4, Extend),
+    (0x11EF5, 0x11EF6, SpacingMark), (0x13430, 0x13438, Control), (0x16AF0, 0x16AF4, Extend),
+    (0x16B30, 0x16B36, Extend), (0x16F4F, 0x16F4F, Extend), (0x16F51, 0x16F87, SpacingMark),
+    (0x16F8F, 0x16F92, Extend), (0x16FE4, 0x16FE4, Extend), (0x16FF0, 0x16FF1, SpacingMark),
+    (0x1BC9D, 0x1BC9E, Extend), (0x1BCA0, 0x1BCA3, Control), (0x1CF00, 0x1CF2D, Extend),
+    (0x1CF30, 0x1CF46, Extend), (0x1D165, 0x1D165, Extend), (0x1D166, 0x1D166, SpacingMark),
+    (0x1D167, 0x1D169, Extend), (0x1D16D, 0x1D16D, SpacingMark), (0x1D16E, 0x1D172, Extend),
+    (0x1D173, 0x1D17A, Control), (0x1D17B, 0x1D182, Extend), (0x1D185, 0x1D18B, Extend),
+    (0x1D1AA, 0x1D1AD, Extend), (0x1D242, 0x1D244, Extend), (0x1DA00, 0x1DA36, Extend),
+    (0x1DA3B, 0x1DA6C, Extend), (0x1DA75, 0x1DA75, Extend), (0x1DA84, 0x1DA84, Extend),
+    (0x1DA9B, 0x1DA9F, Extend), (0x1DAA1, 0x1DAAF, Extend), (0x1E000, 0x1E006, Extend),
+    (0x1E008, 0x1E018, Extend), (0x1E01B, 0x1E021, Extend), (0x1E023, 0x1E024, Extend),
+    (0x1E026, 0x1E02A, Extend), (0x1E130, 0x1E136, Extend), (0x1E2AE, 0x1E2AE, Extend),
+    (0x1E2EC, 0x1E2EF, Extend), (0x1E8D0, 0x1E8D6, Extend), (0x1E944, 0x1E94A, Extend),
+    (0x1F000, 0x1F0FF, ExtendedPictographic), (0x1F10D, 0x1F10F, ExtendedPictographic), (0x1F12F, 0x1F12F, ExtendedPictographic),
+    (0x1F16C, 0x1F171, ExtendedPictographic), (0x1F17E, 0x1F17F, ExtendedPictographic), (0x1F18E, 0x1F18E, ExtendedPictographic),
+    (0x1F191, 0x1F19A, ExtendedPictographic), (0x1F1AD, 0x1F1E5, ExtendedPictographic), (0x1F1E6, 0x1F1FF, RegionalIndicator),
+    (0x1F201, 0x1F20F, ExtendedPictographic), (0x1F21A, 0x1F21A, ExtendedPictographic), (0x1F22F, 0x1F22F, ExtendedPictographic),
+    (0x1F232, 0x1F23A, ExtendedPictographic), (0x1F23C, 0x1F23F, ExtendedPictographic), (0x1F249, 0x1F3FA, ExtendedPictographic),
+    (0x1F3FB, 0x1F3FF, Extend), (0x1F400, 0x1F53D, ExtendedPictographic), (0x1F546, 0x1F64F, ExtendedPictographic),
+    (0x1F680, 0x1F6FF, ExtendedPictographic), (0x1F774, 0x1F77F, ExtendedPictographic), (0x1F7D5, 0x1F7FF, ExtendedPictographic),
+    (0x1F80C, 0x1F80F, ExtendedPictographic), (0x1F848, 0x1F84F, ExtendedPictographic), (0x1F85A, 0x1F85F, ExtendedPictographic),
+    (0x1F888, 0x1F88F, ExtendedPictographic), (0x1F8AE, 0x1F8FF, ExtendedPictographic), (0x1F90C, 0x1F93A, ExtendedPictographic),
+    (0x1F93C, 0x1F945, ExtendedPictographic), (0x1F947, 0x1FAFF, ExtendedPictographic), (0x1FC00, 0x1FFFD, ExtendedPictographic),
+    (0xE0000, 0xE001F, Control), (0xE0020, 0xE007F, Extend), (0xE0080, 0xE00FF, Control),
+    (0xE0100, 0xE01EF, Extend), (0xE01F0, 0xE0FFF, Control),
+];
+
+#[rustfmt::skip]
+pub(super) const COMBINING_CLASS: &[(u32, u32, u8)] = &[
+    (0x300, 0x314, 230), (0x315, 0x315, 232), (0x316, 0x319, 220), (0x31A, 0x31A, 232),
+    (0x31B, 0x31B, 216), (0x31C, 0x320, 220), (0x321, 0x322, 202), (0x323, 0x326, 220),
+    (0x327, 0x328, 202), (0x329, 0x333, 220), (0x334, 0x338, 1), (0x339, 0x33C, 220),
+    (0x33D, 0x344, 230), (0x345, 0x345, 240), (0x346, 0x346, 230), (0x347, 0x349, 220),
+    (0x34A, 0x34C, 230), (0x34D, 0x34E, 220), (0x350, 0x352, 230), (0x353, 0x356, 220),
+    (0x357, 0x357, 230), (0x358, 0x358, 232), (0x359, 0x35A, 220), (0x35B, 0x35B, 230),
+    (0x35C, 0x35C, 233), (0x35D, 0x35E, 234), (0x35F, 0x35F, 233), (0x360, 0x361, 234),
+    (0x362, 0x362, 233), (0x363, 0x36F, 230), (0x483, 0x487, 230), (0x591, 0x591, 220),
+    (0x592, 0x595, 230), (0x596, 0x596, 220), (0x597, 0x599, 230), (0x59A, 0x59A, 222),
+    (0x59B, 0x59B, 220), (0x59C, 0x5A1, 230), (0x5A2, 0x5A7, 220), (0x5A8, 0x5A9, 230),
+    (0x5AA, 0x5AA, 220), (0x5AB, 0x5AC, 230), (0x5AD, 0x5AD, 222), (0x5AE, 0x5AE, 228),
+    (0x5AF, 0x5AF, 230), (0x5B0, 0x5B0, 10), (0x5B1, 0x5B1, 11), (0x5B2, 0x5B2, 12),
+    (0x5B3, 0x5B3, 13), (0x5B4, 0x5B4, 14), (0x5B5, 0x5B5, 15), (0x5B6, 0x5B6, 16),
+    (0x5B7, 0x5B7, 17), (0x5B8, 0x5B8, 18), (0x5B9, 0x5BA, 19), (0x5BB, 0x5BB, 20),
+    (0x5BC, 0x5BC, 21), (0x5BD, 0x5BD, 22), (0x5BF, 0x5BF, 23), (0x5C1, 0x5C1, 24),
+    (0x5C2, 0x5C2, 25), (0x5C4, 0x5C4, 230), (0x5C5, 0x5C5, 220), (0x5C7, 0x5C7, 18),
+    (0x610, 0x617, 230), (0x618, 0x618, 30), (0x619, 0x619, 31), (0x61A, 0x61A, 32),
+    (0x64B, 0x64B, 27), (0x64C, 0x64C, 28), (0x64D, 0x64D, 29), (0x64E, 0x64E, 30),
+    (0x64F, 0x64F, 31), (0x650, 0x650, 32), (0x651, 0x651, 33), (0x652, 0x652, 34),
+    (0x653, 0x654, 230), (0x655, 0x656, 220), (0x657, 0x65B, 230), (0x65C, 0x65C, 220),
+    (0x65D, 0x65E, 230), (0x65F, 0x65F, 220), (0x670, 0x670, 35), (0x6D6, 0x6DC, 230),
+    (0x6DF, 0x6E2, 230), (0x6E3, 0x6E3, 220), (0x6E4, 0x6E4, 230), (0x6E7, 0x6E8, 230),
+    (0x6EA, 0x6EA, 220), (0x6EB, 0x6EC, 230), (0x6ED, 0x6ED, 220), (0x711, 0x711, 36),
+    (0x730, 0x730, 230), (0x731, 0x731, 220), (0x732, 0x733, 230), (0x734, 0x734, 220),
+    (0x735, 0x736, 230), (0x737, 0x739, 220), (0x73A, 0x73A, 230), (0x73B, 0x73C, 220),
+    (0x73D, 0x73D, 230), (0x73E, 0x73E, 220), (0x73F, 0x741, 230), (0x742, 0x742, 220),
+    (0x743, 0x743, 230), (0x744, 0x744, 220), (0x745, 0x745, 230), (0x746, 0x746, 220),
+    (0x747, 0x747, 230), (0x748, 0x748, 220), (0x749, 0x74A, 230), (0x7EB, 0x7F1, 230),
+    (0x7F2, 0x7F2, 220), (0x7F3, 0x7F3, 230), (0x7FD, 0x7FD, 220), (0x816, 0x819, 230),
+    (0x81B, 0x823, 230), (0x825, 0x827, 230), (0x829, 0x82D, 230), (0x859, 0x85B, 220),
+    (0x898, 0x898, 230), (0x899, 0x89B, 220), (0x89C, 0x89F, 230), (0x8CA, 0x8CE, 230),
+    (0x8CF, 0x8D3, 220), (0x8D4, 0x8E1, 230), (0x8E3, 0x8E3, 220), (0x8E4, 0x8E5, 230),
+    (0x8E6, 0x8E6, 220), (0x8E7, 0x8E8, 230), (0x8E9, 0x8E9, 220), (0x8EA, 0x8EC, 230),
+    (0x8ED, 0x8EF, 220), (0x8F0, 0x8F0, 27), (0x8F1, 0x8F1, 28), (0x8F2, 0x8F2, 29),
+    (0x8F3, 0x8F5, 230), (0x8F6, 0x8F6, 220), (0x8F7, 0x8F8, 230), (0x8F9, 0x8FA, 220),
+    (0x8FB, 0x8FF, 230), (0x93C, 0x93C, 7), (0x94D, 0x94D, 9), (0x951, 0x951, 230),
+    (0x952, 0x952, 220), (0x953, 0x954, 230), (0x9BC, 0x9BC, 7), (0x9CD, 0x9CD, 9),
+    (0x9FE, 0x9FE, 230), (0xA3C, 0xA3C, 7), (0xA4D, 0xA4D, 9), (0xABC, 0xABC, 7),
+    (0xACD, 0xACD, 9), (0xB3C, 0xB3C, 7), (0xB4D, 0xB4D, 9), (0xBCD, 0xBCD, 9),
+    (0xC3C, 0xC3C, 7), (0xC4D, 0xC4D, 9), (0xC55, 0xC55, 84), (0xC56, 0xC56, 91),
+    (0xCBC, 0xCBC, 7), (0xCCD, 0xCCD, 9), (0xD3B, 0xD3C, 9), (0xD4D, 0xD4D, 9),
+    (0xDCA, 0xDCA, 9), (0xE38, 0xE39, 103), (0xE3A, 0xE3A, 9), (0xE48, 0xE4B, 107),
+    (0xEB8, 0xEB9, 118), (0xEBA, 0xEBA, 9), (0xEC8, 0xECB, 122), (0xF18, 0xF19, 220),
+    (0xF35, 0xF35, 220), (0xF37, 0xF37, 220), (0xF39, 0xF39, 216), (0xF71, 0xF71, 129),
+    (0xF72, 0xF72, 130), (0xF74, 0xF74, 132), (0xF7A, 0xF7D, 130), (0xF80, 0xF80, 130),
+    (0xF82, 0xF83, 230), (0xF84, 0xF84, 9), (0xF86, 0xF87, 230), (0xFC6, 0xFC6, 220),
+    (0x1037, 0x1037, 7), (0x1039, 0x103A, 9), (0x108D, 0x108D, 220), (0x135D, 0x135F, 230),
+    (0x1714, 0x1715, 9), (0x1734, 0x1734, 9), (0x17D2, 0x17D2, 9), (0x17DD, 0x17DD, 230),
+    (0x18A9, 0x18A9, 228), (0x1939, 0x1939, 222), (0x193A, 0x193A, 230), (0x193B, 0x193B, 220),
+    (0x1A17, 0x1A17, 230), (0x1A18, 0x1A18, 220), (0x1A60, 0x1A60, 9), (0x1A75, 0x1A7C, 230),
+    (0x1A7F, 0x1A7F, 220), (0x1AB0, 0x1AB4, 230), (0x1AB5, 0x1ABA, 220), (0x1ABB, 0x1ABC, 230),
+    (0x1ABD, 0x1ABD, 220), (0x1ABF, 0x1AC0, 220), (0x1AC1, 0x1AC2, 230), (0x1AC3, 0x1AC4, 220),
+    (0x1AC5, 0x1AC9, 230), (0x1ACA, 0x1ACA, 220), (0x1ACB, 0x1ACE, 230), (0x1B34, 0x1B34, 7),
+    (0x1B44, 0x1B44, 9), (0x1B6B, 0x1B6B, 230), (0x1B6C, 0x1B6C, 220), (0x1B6D, 0x1B73, 230),
+    (0x1BAA, 0x1BAB, 9), (0x1BE6, 0x1BE6, 7), (0x1BF2, 0x1BF3, 9), (0x1C37, 0x1C37, 7),
+    (0x1CD0, 0x1CD2, 230), (0x1CD4, 0x1CD4, 1), (0x1CD5, 0x1CD9, 220), (0x1CDA, 0x1CDB, 230),
+    (0x1CDC, 0x1CDF, 220), (0x1CE0, 0x1CE0, 230), (0x1CE2, 0x1CE8, 1), (0x1CED, 0x1CED, 220),
+    (0x1CF4, 0x1CF4, 230), (0x1CF8, 0x1CF9, 230), (0x1DC0, 0x1DC1, 230), (0x1DC2, 0x1DC2, 220),
+    (0x1DC3, 0x1DC9, 230), (0x1DCA, 0x1DCA, 220), (0x1DCB, 0x1DCC, 230), (0x1DCD, 0x1DCD, 234),
+    (0x1DCE, 0x1DCE, 214), (0x1DCF, 0x1DCF, 220), (0x1DD0, 0x1DD0, 202), (0x1DD1, 0x1DF5, 230),
+    (0x1DF6, 0x1DF6, 232), (0x1DF7, 0x1DF8, 228), (0x1DF9, 0x1DF9, 220), (0x1DFA, 0x1DFA, 218),
+    (0x1DFB, 0x1DFB, 230), (0x1DFC, 0x1DFC, 233), (0x1DFD, 0x1DFD, 220), (0x1DFE, 0x1DFE, 230),
+    (0x1DFF, 0x1DFF, 220), (0x20D0, 0x20D1, 230), (0x20D2, 0x20D3, 1), (0x20D4, 0x20D7, 230),
+    (0x20D8, 0x20DA, 1), (0x20DB, 0x20DC, 230), (0x20E1, 0x20E1, 230), (0x20E5, 0x20E6, 1),
+    (0x20E7, 0x20E7, 230), (0x20E8, 0x20E8, 220), (0x20E9, 0x20E9, 230), (0x20EA, 0x20EB, 1),
+    (0x20EC, 0x20EF, 220), (0x20F0, 0x20F0, 230), (0x2CEF, 0x2CF1, 230), (0x2D7F, 0x2D7F, 9),
+    (0x2DE0, 0x2DFF, 230), (0x302A, 0x302A, 218), (0x302B, 0x302B, 228), (0x302C, 0x302C, 232),
+    (0x302D, 0x302D, 222), (0x302E, 0x302F, 224), (0x3099, 0x309A, 8), (0xA66F, 0xA66F, 230),
+    (0xA674, 0xA67D, 230), (0xA69E, 0xA69F, 230), (0xA6F0, 0xA6F1, 230), (0xA806, 0xA806, 9),
+    (0xA82C, 0xA82C, 9), (0xA8C4, 0xA8C4, 9), (0xA8E0, 0xA8F1, 230), (0xA92B, 0xA92D, 220),
+    (0xA953, 0xA953, 9), (0xA9B3, 0xA9B3, 7), (0xA9C0, 0xA9C0, 9), (0xAAB0, 0xAAB0, 230),
+    (0xAAB2, 0xAAB3, 230), (0xAAB4, 0xAAB4, 220), (0xAAB7, 0xAAB8, 230), (0xAABE, 0xAABF, 230),
+    (0xAAC1, 0xAAC1, 230), (0xAAF6, 0xAAF6, 9), (0xABED, 0xABED, 9), (0xFB1E, 0xFB1E, 26),
+    (0xFE20, 0xFE26, 230), (0xFE27, 0xFE2D, 220), (0xFE2E, 0xFE2F, 230), (0x101FD, 0x101FD, 220),
+    (0x102E0, 0x102E0, 220), (0x10376, 0x1037A, 230), (0x10A0D, 0x10A0D, 220), (0x10A0F, 0x10A0F, 230),
+    (0x10A38, 0x10A38, 230), (0x10A39, 0x10A39, 1), (0x10A3A, 0x10A3A, 220), (0x10A3F, 0x10A3F, 9),
+    (0x10AE5, 0x10AE5, 230), (0x10AE6, 0x10AE6, 220), (0x10D24, 0x10D27, 230), (0x10EAB, 0x10EAC, 230),
+    (0x10F46, 0x10F47, 220), (0x10F48, 0x10F4A, 230), (0x10F4B, 0x10F4B, 220), (0x10F4C, 0x10F4C, 230),
+    (0x10F4D, 0x10F50, 220), (0x10F82, 0x10F82, 230), (0x10F83, 0x10F83, 220), (0x10F84, 0x10F84, 230),
+    (0x10F85, 0x10F85, 220), (0x11046, 0x11046, 9), (0x11070, 0x11070, 9), (0x1107F, 0x1107F, 9),
+    (0x110B9, 0x110B9, 9), (0x110BA, 0x110BA, 7), (0x11100, 0x11102, 230), (0x11133, 0x11134, 9),
+    (0x11173, 0x11173, 7), (0x111C0, 0x111C0, 9), (0x111CA, 0x111CA, 7), (0x11235, 0x11235, 9),
+    (0x11236, 0x11236, 7), (0x112E9, 0x112E9, 7), (0x112EA, 0x112EA, 9), (0x1133B, 0x1133C, 7),
+    (0x1134D, 0x1134D, 9), (0x11366, 0x1136C, 230), (0x11370, 0x11374, 230), (0x11442, 0x11442, 9),
+    (0x11446, 0x11446, 7), (0x1145E, 0x1145E, 230), (0x114C2, 0x114C2, 9), (0x114C3, 0x114C3, 7),
+    (0x115BF, 0x115BF, 9), (0x115C0, 0x115C0, 7), (0x1163F, 0x1163F, 9), (0x116B6, 0x116B6, 9),
+    (0x116B7, 0x116B7, 7), (0x1172B, 0x1172B, 9), (0x11839, 0x11839, 9), (0x1183A, 0x1183A, 7),
+    (0x1193D, 0x1193E, 9), (0x11943, 0x11943, 7), (0x119E0, 0x119E0, 9), (0x11A34, 0x11A34, 9),
+    (0x11A47, 0x11A47, 9), (0x11A99, 0x11A99, 9), (0x11C3F, 0x11C3F, 9), (0x11D42, 0x11D42, 7),
+    (0x11D44, 0x11D45, 9), (0x11D97, 0x11D97, 9), (0x16AF0, 0x16AF4, 1), (0x16B30, 0x16B36, 230),
+    (0x16FF0, 0x16FF1, 6), (0x1BC9E, 0x1BC9E, 1), (0x1D165, 0x1D166, 216), (0x1D167, 0x1D169, 1),
+    (0x1D16D, 0x1D16D, 226), (0x1D16E, 0x1D172, 216), (0x1D17B, 0x1D182, 220), (0x1D185, 0x1D189, 230),
+    (0x1D18A, 0x1D18B, 220), (0x1D1AA, 0x1D1AD, 230), (0x1D242, 0x1D244, 230), (0x1E000, 0x1E006, 230),
+    (0x1E008, 0x1E018, 230), (0x1E01B, 0x1E021, 230), (0x1E023, 0x1E024, 230), (0x1E026, 0x1E02A, 230),
+    (0x1E130, 0x1E136, 230), (0x1E2AE, 0x1E2AE, 230), (0x1E2EC, 0x1E2EF, 230), (0x1E8D0, 0x1E8D6, 220),
+    (0x1E944, 0x1E949, 230), (0x1E94A, 0x1E94A, 7),
+];
+
+#[rustfmt::skip]
+pub(super) const NFC_QUICK_CHECK: &[(u32, u32, bool)] = &[
+    (0x300, 0x304, true), (0x306, 0x30C, true), (0x30F, 0x30F, true), (0x311, 0x311, true),
+    (0x313, 0x314, true), (0x31B, 0x31B, true), (0x323, 0x328, true), (0x32D, 0x32E, true),
+    (0x330, 0x331, true), (0x338, 0x338, true), (0x340, 0x341, false), (0x342, 0x342, true),
+    (0x343, 0x344, false), (0x345, 0x345, true), (0x374, 0x374, false), (0x37E, 0x37E, false),
+    (0x387, 0x387, false), (0x653, 0x655, true), (0x93C, 0x93C, true), (0x958, 0x95F, false),
+    (0x9BE, 0x9BE, true), (0x9D7, 0x9D7, true), (0x9DC, 0x9DD, false), (0x9DF, 0x9DF, false),
+    (0xA33, 0xA33, false), (0xA36, 0xA36, false), (0xA59, 0xA5B, false), (0xA5E, 0xA5E, false),
+    (0xB3E, 0xB3E, true), (0xB56, 0xB57, true), (0xB5C, 0xB5D, false), (0xBBE, 0xBBE, true),
+    (0xBD7, 0xBD7, true), (0xC56, 0xC56, true), (0xCC2, 0xCC2, true), (0xCD5, 0xCD6, true),
+    (0xD3E, 0xD3E, true), (0xD57, 0xD57, true), (0xDCA, 0xDCA, true), (0xDCF, 0xDCF, true),
+    (0xDDF, 0xDDF, true), (0xF43, 0xF43, false), (0xF4D, 0xF4D, false), (0xF52, 0xF52, false),
+    (0xF57, 0xF57, false), (0xF5C, 0xF5C, false), (0xF69, 0xF69, false), (0xF73, 0xF73, false),
+    (0xF75, 0xF76, false), (0xF78, 0xF78, false), (0xF81, 0xF81, false), (0xF93, 0xF93, false),
+    (0xF9D, 0xF9D, false), (0xFA2, 0xFA2, false), (0xFA7, 0xFA7, false), (0xFAC, 0xFAC, false),
+    (0xFB9, 0xFB9, false), (0x102E, 0x102E, true), (0x1161, 0x1175, true), (0x11A8, 0x11C2, true),
+    (0x1B35, 0x1B35, true), (0x1F71, 0x1F71, false), (0x1F73, 0x1F73, false), (0x1F75, 0x1F75, false),
+    (0x1F77, 0x1F77, false), (0x1F79, 0x1F79, false), (0x1F7B, 0x1F7B, false), (0x1F7D, 0x1F7D, false),
+    (0x1FBB, 0x1FBB, false), (0x1FBE, 0x1FBE, false), (0x1FC9, 0x1FC9, false), (0x1FCB, 0x1FCB, false),
+    (0x1FD3, 0x1FD3, false), (0x1FDB, 0x1FDB, false), (0x1FE3, 0x1FE3, false), (0x1FEB, 0x1FEB, false),
+    (0x1FEE, 0x1FEF, false), (0x1FF9, 0x1FF9, false), (0x1FFB, 0x1FFB, false), (0x1FFD, 0x1FFD, false),
+    (0x2000, 0x2001, false), (0x2126, 0x2126, false), (0x212A, 0x212B, false), (0x2329, 0x232A, false),
+    (0x2ADC, 0x2ADC, false), (0x3099, 0x309A, true), (0xF900, 0xFA0D, false), (0xFA10, 0xFA10, false),
+    (0xFA12, 0xFA12, false), (0xFA15, 0xFA1E, false), (0xFA20, 0xFA20, false), (0xFA22, 0xFA22, false),
+    (0xFA25, 0xFA26, false), (0xFA2A, 0xFA6D, false), (0xFA70, 0xFAD9, false), (0xFB1D, 0xFB1D, false),
+    (0xFB1F, 0xFB1F, false), (0xFB2A, 0xFB36, false), (0xFB38, 0xFB3C, false), (0xFB3E, 0xFB3E, false),
+    (0xFB40, 0xFB41, false), (0xFB43, 0xFB44, false), (0xFB46, 0xFB4E, false), (0x110BA, 0x110BA, true),
+    (0x11127, 0x11127, true), (0x1133E, 0x1133E, true), (0x11357, 0x11357, true), (0x114B0, 0x114B0, true),
+    (0x114BA, 0x114BA, true), (0x114BD, 0x114BD, true), (0x115AF, 0x115AF, true), (0x11930, 0x11930, true),
+    (0x1D15E, 0x1D164, false), (0x1D1BB, 0x1D1C0, false), (0x2F800, 0x2FA1D, false),
+];
+
+#[rustfmt::skip]
+pub(super) const CANONICAL_DECOMPOSITION: &[(char, char, Option<char>)] = &[
+    ('\u{C0}', '\u{41}', Some('\u{300}')), ('\u{C1}', '\u{41}', Some('\u{301}')), ('\u{C2}', '\u{41}', Some('\u{302}')), ('\u{C3}', '\u{41}', Some('\u{303}')),
+    ('\u{C4}', '\u{41}', Some('\u{308}')), ('\u{C5}', '\u{41}', Some('\u{30A}')), ('\u{C7}', '\u{43}', Some('\u{327}')), ('\u{C8}', '\u{45}', Some('\u{300}')),
+    ('\u{C9}', '\u{45}', Some('\u{301}')), ('\u{CA}', '\u{45}', Some('\u{302}')), ('\u{CB}', '\u{45}', Some('\u{308}')), ('\u{CC}', '\u{49}', Some('\u{300}')),
+    ('\u{CD}', '\u{49}', Some('\u{301}')), ('\u{CE}', '\u{49}', Some('\u{302}')), ('\u{CF}', '\u{49}', Some('\u{308}')), ('\u{D1}', '\u{4E}', Some('\u{303}')),
+    ('\u{D2}', '\u{4F}', Some('\u{300}')), ('\u{D3}', '\u{4F}', Some('\u{301}')), ('\u{D4}', '\u{4F}', Some('\u{302}')), ('\u{D5}', '\u{4F}', Some('\u{303}')),
+    ('\u{D6}', '\u{4F}', Some('\u{308}')), ('\u{D9}', '\u{55}', Some('\u{300}')), ('\u{DA}', '\u{55}', Some('\u{301}')), ('\u{DB}', '\u{55}', Some('\u{302}')),
+    ('\u{DC}', '\u{55}', Some('\u{308}')), ('\u{DD}', '\u{59}', Some('\u{301}')), ('\u{E0}', '\u{61}', Some('\u{300}')), ('\u{E1}', '\u{61}', Some('\u{301}')),
+    ('\u{E2}', '\u{61}', Some('\u{302}')), ('\u{E3}', '\u{61}', Some('\u{303}')), ('\u{E4}', '\u{61}', Some('\u{308}')), ('\u{E5}', '\u{61}', Some('\u{30A}')),
+    ('\u{E7}', '\u{63}', Some('\u{327}')), ('\u{E8}', '\u{65}', Some('\u{300}')), ('\u{E9}', '\u{65}', Some('\u{301}')), ('\u{EA}', '\u{65}', Some('\u{302}')),
+    ('\u{EB}', '\u{65}', Some('\u{308}')), ('\u{EC}', '\u{69}', Some('\u{300}')), ('\u{ED}', '\u{69}', Some('\u{301}')), ('\u{EE}', '\u{69}', Some('\u{302}')),
+    ('\u{EF}', '\u{69}', Some('\u{308}')), ('\u{F1}', '\u{6E}', Some('\u{303}')), ('\u{F2}', '\u{6F}', Some('\u{300}')), ('\u{F3}', '\u{6F}', Some('\u{301}')),
+    ('\u{F4}', '\u{6F}', Some('\u{302}')), ('\u{F5}', '\u{6F}', Some('\u{303}')), ('\u{F6}', '\u{6F}', Some('\u{308}')), ('\u{F9}', '\u{75}', Some('\u{300}')),
+    ('\u{FA}', '\u{75}', Some('\u{301}')), ('\u{FB}', '\u{75}', Some('\u{302}')), ('\u{FC}', '\u{75}', Some('\u{308}')), ('\u{FD}', '\u{79}', Some('\u{301}')),
+    ('\u{FF}', '\u{79}', Some('\u{308}')), ('\u{100}', '\u{41}', Some('\u{304}')), ('\u{101}', '\u{61}', Some('\u{304}')), ('\u{102}', '\u{41}', Some('\u{306}')),
+    ('\u{103}', '\u{61}', Some('\u{306}')), ('\u{104}', '\u{41}', Some('\u{328}')), ('\u{105}', '\u{61}', Some('\u{328}')), ('\u{106}', '\u{43}', Some('\u{301}')),
+    ('\u{107}', '\u{63}', Some('\u{301}')), ('\u{108}', '\u{43}', Some('\u{302}')), ('\u{109}', '\u{63}', Some('\u{302}')), ('\u{10A}', '\u{43}', Some('\u{307}')),
+    ('\u{10B}', '\u{63}', Some('\u{307}')), ('\u{10C}', '\u{43}', Some('\u{30C}')), ('\u{10D}', '\u{63}', Some('\u{30C}')), ('\u{10E}', '\u{44}', Some('\u{30C}')),
+    ('\u{10F}', '\u{64}', Some('\u{30C}')), ('\u{112}', '\u{45}', Some('\u{304}')), ('\u{113}', '\u{65}', Some('\u{304}')), ('\u{114}', '\u{45}', Some('\u{306}')),
+    ('\u{115}', '\u{65}', Some('\u{306}')), ('\u{116}', '\u{45}', Some('\u{307}')), ('\u{117}', '\u{65}', Some('\u{307}')), ('\u{118}', '\u{45}', Some('\u{328}')),
+    ('\u{119}', '\u{65}', Some('\u{328}')), ('\u{11A}', '\u{45}', Some('\u{30C}')), ('\u{11B}', '\u{65}', Some('\u{30C}')), ('\u{11C}', '\u{47}', Some('\u{302}')),
+    ('\u{11D}', '\u{67}', Some('\u{302}')), ('\u{11E}', '\u{47}', Some('\u{306}')), ('\u{11F}', '\u{67}', Some('\u{306}')), ('\u{120}', '\u{47}', Some('\u{307}')),
+    ('\u{121}', '\u{67}', Some('\u{307}')), ('\u{122}', '\u{47}', Some('\u{327}')), ('\u{123}', '\u{67}', Some('\u{327}')), ('\u{124}', '\u{48}', Some('\u{302}')),
+    ('\u{125}', '\u{68}', Some('\u{302}')), ('\u{128}', '\u{49}', Some('\u{303}')), ('\u{129}', '\u{69}', Some('\u{303}')), ('\u{12A}', '\u{49}', Some('\u{304}')),
+    ('\u{12B}', '\u{69}', Some('\u{304}')), ('\u{12C}', '\u{49}', Some('\u{306}')), ('\u{12D}', '\u{69}', Some('\u{306}')), ('\u{12E}', '\u{49}', Some('\u{328}')),
+    ('\u{12F}', '\u{69}', Some('\u{328}')), ('\u{130}', '\u{49}', Some('\u{307}')), ('\u{134}', '\u{4A}', Some('\u{302}')), ('\u{135}', '\u{6A}', Some('\u{302}')),
+    ('\u{136}', '\u{4B}', Some('\u{327}')), ('\u{137}', '\u{6B}', Some('\u{327}')), ('\u{139}', '\u{4C}', Some('\u{301}')), ('\u{13A}', '\u{6C}', Some('\u{301}')),
+    ('\u{13B}', '\u{4C}', Some('\u{327}')), ('\u{13C}', '\u{6C}', Some('\u{327}')), ('\u{13D}', '\u{4C}', Some('\u{30C}')), ('\u{13E}', '\u{6C}', Some('\u{30C}')),
+    ('\u{143}', '\u{4E}', Some('\u{301}')), ('\u{144}', '\u{6E}', Some('\u{301}')), ('\u{145}', '\u{4E}', Some('\u{327}')), ('\u{146}', '\u{6E}', Some('\u{327}')),
+    ('\u{147}', '\u{4E}', Some('\u{30C}')), ('\u{148}', '\u{6E}', Some('\u{30C}')), ('\u{14C}', '\u{4F}', Some('\u{304}')), ('\u{14D}', '\u{6F}', Some('\u{304}')),
+    ('\u{14E}', '\u{4F}', Some('\u{306}')), ('\u{14F}', '\u{6F}', Some('\u{306}')), ('\u{150}', '\u{4F}', Some('\u{30B}')), ('\u{151}', '\u{6F}', Some('\u{30B}')),
+    ('\u{154}', '\u{52}', Some('\u{301}')), ('\u{155}', '\u{72}', Some('\u{301}')), ('\u{156}', '\u{52}', Some('\u{327}')), ('\u{157}', '\u{72}', Some('\u{327}')),
+    ('\u{158}', '\u{52}', Some('\u{30C}')), ('\u{159}', '\u{72}', Some('\u{30C}')), ('\u{15A}', '\u{53}', Some('\u{301}')), ('\u{15B}', '\u{73}', Some('\u{301}')),
+    ('\u{15C}', '\u{53}', Some('\u{302}')), ('\u{15D}', '\u{73}', Some('\u{302}')), ('\u{15E}', '\u{53}', Some('\u{327}')), ('\u{15F}', '\u{73}', Some('\u{327}')),
+    ('\u{160}', '\u{53}', Some('\u{30C}')), ('\u{161}', '\u{73}', Some('\u{30C}')), ('\u{162}', '\u{54}', Some('\u{327}')), ('\u{163}', '\u{74}', Some('\u{327}')),
+    ('\u{164}', '\u{54}', Some('\u{30C}')), ('\u{165}', '\u{74}', Some('\u{30C}')), ('\u{168}', '\u{55}', Some('\u{303}')), ('\u{169}', '\u{75}', Some('\u{303}')),
+    ('\u{16A}', '\u{55}', Some('\u{304}')), ('\u{16B}', '\u{75}', Some('\u{304}')), ('\u{16C}', '\u{55}', Some('\u{306}')), ('\u{16D}', '\u{75}', Some('\u{306}')),
+    ('\u{16E}', '\u{55}', Some('\u{30A}')), ('\u{16F}', '\u{75}', Some('\u{30A}')), ('\u{170}', '\u{55}', Some('\u{30B}')), ('\u{171}', '\u{75}', Some('\u{30B}')),
+    ('\u{172}', '\u{55}', Some('\u{328}')), ('\u{173}', '\u{75}', Some('\u{328}')), ('\u{174}', '\u{57}', Some('\u{302}')), ('\u{175}', '\u{77}', Some('\u{302}')),
+    ('\u{176}', '\u{59}', Some('\u{302}')), ('\u{177}', '\u{79}', Some('\u{302}')), ('\u{178}', '\u{59}', Some('\u{308}')), ('\u{179}', '\u{5A}', Some('\u{301}')),
+    ('\u{17A}', '\u{7A}', Some('\u{301}')), ('\u{17B}', '\u{5A}', Some('\u{307}')), ('\u{17C}', '\u{7A}', Some('\u{307}')), ('\u{17D}', '\u{5A}', Some('\u{30C}')),
+    ('\u{17E}', '\u{7A}', Some('\u{30C}')), ('\u{1A0}', '\u{4F}', Some('\u{31B}')), ('\u{1A1}', '\u{6F}', Some('\u{31B}')), ('\u{1AF}', '\u{55}', Some('\u{31B}')),
+    ('\u{1B0}', '\u{75}', Some('\u{31B}')), ('\u{1CD}', '\u{41}', Some('\u{30C}')), ('\u{1CE}', '\u{61}', Some('\u{30C}')), ('\u{1CF}', '\u{49}', Some('\u{30C}')),
+    ('\u{1D0}', '\u{69}', Some('\u{30C}')), ('\u{1D1}', '\u{4F}', Some('\u{30C}')), ('\u{1D2}', '\u{6F}', Some('\u{30C}')), ('\u{1D3}', '\u{55}', Some('\u{30C}')),
+    ('\u{1D4}', '\u{75}', Some('\u{30C}')), ('\u{1D5}', '\u{DC}', Some('\u{304}')), ('\u{1D6}', '\u{FC}', Some('\u{304}')), ('\u{1D7}', '\u{DC}', Some('\u{301}')),
+    ('\u{1D8}', '\u{FC}', Some('\u{301}')), ('\u{1D9}', '\u{DC}', Some('\u{30C}')), ('\u{1DA}', '\u{FC}', Some('\u{30C}')), ('\u{1DB}', '\u{DC}', Some('\u{300}')),
+    ('\u{1DC}', '\u{FC}', Some('\u{300}')), ('\u{1DE}', '\u{C4}', Some('\u{304}')), ('\u{1DF}', '\u{E4}', Some('\u{304}')), ('\u{1E0}', '\u{226}', Some('\u{304}')),
+    ('\u{1E1}', '\u{227}', Some('\u{304}')), ('\u{1E2}', '\u{C6}', Some('\u{304}')), ('\u{1E3}', '\u{E6}', Some('\u{304}')), ('\u{1E6}', '\u{47}', Some('\u{30C}')),
+    ('\u{1E7}', '\u{67}', Some('\u{30C}')), ('\u{1E8}', '\u{4B}', Some('\u{30C}')), ('\u{1E9}', '\u{6B}', Some('\u{30C}')), ('\u{1EA}', '\u{4F}', Some('\u{328}')),
+    ('\u{1EB}', '\u{6F}', Some('\u{328}')), ('\u{1EC}', '\u{1EA}', Some('\u{304}')), ('\u{1ED}', '\u{1EB}', Some('\u{304}')), ('\u{1EE}', '\u{1B7}', Some('\u{30C}')),
+    ('\u{1EF}', '\u{292}', Some('\u{30C}')), ('\u{1F0}', '\u{6A}', Some('\u{30C}')), ('\u{1F4}', '\u{47}', Some('\u{301}')), ('\u{1F5}', '\u{67}', Some('\u{301}')),
+    ('\u{1F8}', '\u{4E}', Some('\u{300}')), ('\u{1F9}', '\u{6E}', Some('\u{300}')), ('\u{1FA}', '\u{C5}', Some('\u{301}')), ('\u{1FB}', '\u{E5}', Some('\u{301}')),
+    ('\u{1FC}', '\u{C6}', Some('\u{301}')), ('\u{1FD}', '\u{E6}', Some('\u{301}')), ('\u{1FE}', '\u{D8}', Some('\u{301}')), ('\u{1FF}', '\u{F8}', Some('\u{301}')),
+    ('\u{200}', '\u{41}', Some('\u{30F}')), ('\u{201}', '\u{61}', Some('\u{30F}')), ('\u{202}', '\u{41}', Some('\u{311}')), ('\u{203}', '\u{61}', Some('\u{311}')),
+    ('\u{204}', '\u{45}', Some('\u{30F}')), ('\u{205}', '\u{65}', Some('\u{30F}')), ('\u{206}', '\u{45}', Some('\u{311}')), ('\u{207}', '\u{65}', Some('\u{311}')),
+    ('\u{208}', '\u{49}', Some('\u{30F}')), ('\u{209}', '\u{69}', Some('\u{30F}')), ('\u{20A}', '\u{49}', Some('\u{311}')), ('\u{20B}', '\u{69}', Some('\u{311}')),
+    ('\u{20C}', '\u{4F}', Some('\u{30F}')), ('\u{20D}', '\u{6F}', Some('\u{30F}')), ('\u{20E}', '\u{4F}', Some('\u{311}')), ('\u{20F}', '\u{6F}', Some('\u{311}')),
+    ('\u{210}', '\u{52}', Some('\u{30F}')), ('\u{211}', '\u{72}', Some('\u{30F}')), ('\u{212}', '\u{52}', Some('\u{311}')), ('\u{213}', '\u{72}', Some('\u{311}')),
+    ('\u{214}', '\u{55}', Some('\u{30F}')), ('\u{215}', '\u{75}', Some('\u{30F}')), ('\u{216}', '\u{55}', Some('\u{311}')), ('\u{217}', '\u{75}', Some('\u{311}')),
+    ('\u{218}', '\u{53}', Some('\u{326}')), ('\u{219}', '\u{73}', Some('\u{326}')), ('\u{21A}', '\u{54}', Some('\u{326}')), ('\u{21B}', '\u{74}', Some('\u{326}')),
+    ('\u{21E}', '\u{48}', Some('\u{30C}')), ('\u{21F}', '\u{68}', Some('\u{30C}')), ('\u{226}', '\u{41}', Some('\u{307}')), ('\u{227}', '\u{61}', Some('\u{307}')),
+    ('\u{228}', '\u{45}', Some('\u{327}')), ('\u{229}', '\u{65}', Some('\u{327}')), ('\u{22A}', '\u{D6}', Some('\u{304}')), ('\u{22B}', '\u{F6}', Some('\u{304}')),
+    ('\u{22C}', '\u{D5}', Some('\u{304}')), ('\u{22D}', '\u{F5}', Some('\u{304}')), ('\u{22E}', '\u{4F}', Some('\u{307}')), ('\u{22F}', '\u{6F}', Some('\u{307}')),
+    ('\u{230}', '\u{22E}', Some('\u{304}')), ('\u{231}', '\u{22F}', Some('\u{304}')), ('\u{232}', '\u{59}', Some('\u{304}')), ('\u{233}', '\u{79}', Some('\u{304}')),
+    ('\u{340}', '\u{300}', None), ('\u{341}', '\u{301}', None), ('\u{343}', '\u{313}', None), ('\u{344}', '\u{308}', Some('\u{301}')),
+    ('\u{374}', '\u{2B9}', None), ('\u{37E}', '\u{3B}', None), ('\u{385}', '\u{A8}', Some('\u{301}')), ('\u{386}', '\u{391}', Some('\u{301}')),
+    ('\u{387}', '\u{B7}', None), ('\u{388}', '\u{395}', Some('\u{301}')), ('\u{389}', '\u{397}', Some('\u{301}')), ('\u{38A}', '\u{399}', Some('\u{301}')),
+    ('\u{38C}', '\u{39F}', Some('\u{301}')), ('\u{38E}', '\u{3A5}', Some('\u{301}')), ('\u{38F}', '\u{3A9}', Some('\u{301}')), ('\u{390}', '\u{3CA}', Some('\u{301}')),
+    ('\u{3AA}', '\u{399}', Some('\u{308}')), ('\u{3AB}', '\u{3A5}', Some('\u{308}')), ('\u{3AC}', '\u{3B1}', Some('\u{301}')), ('\u{3AD}', '\u{3B5}', Some('\u{301}')),
+    ('\u{3AE}', '\u{3B7}', Some('\u{301}')), ('\u{3AF}', '\u{3B9}', Some('\u{301}')), ('\u{3B0}', '\u{3CB}', Some('\u{301}')), ('\u{3CA}', '\u{3B9}', Some('\u{308}')),
+    ('\u{3CB}', '\u{3C5}', Some('\u{308}')), ('\u{3CC}', '\u{3BF}', Some('\u{301}')), ('\u{3CD}', '\u{3C5}', Some('\u{301}')), ('\u{3CE}', '\u{3C9}', Some('\u{301}')),
+    ('\u{3D3}', '\u{3D2}', Some('\u{301}')), ('\u{3D4}', '\u{3D2}', Some('\u{308}')), ('\u{400}', '\u{415}', Some('\u{300}')), ('\u{401}', '\u{415}', Some('\u{308}')),
+    ('\u{403}', '\u{413}', Some('\u{301}')), ('\u{407}', '\u{406}', Some('\u{308}')), ('\u{40C}', '\u{41A}', Some('\u{301}')), ('\u{40D}', '\u{418}', Some('\u{300}')),
+    ('\u{40E}', '\u{423}', Some('\u{306}')), ('\u{419}', '\u{418}', Some('\u{306}')), ('\u{439}', '\u{438}', Some('\u{306}')), ('\u{450}', '\u{435}', Some('\u{300}')),
+    ('\u{451}', '\u{435}', Some('\u{308}')), ('\u{453}', '\u{433}', Some('\u{301}')), ('\u{457}', '\u{456}', Some('\u{308}')), ('\u{45C}', '\u{43A}', Some('\u{301}')),
+    ('\u{45D}', '\u{438}', Some('\u{300}')), ('\u{45E}', '\u{443}', Some('\u{306}')), ('\u{476}', '\u{474}', Some('\u{30F}')), ('\u{477}', '\u{475}', Some('\u{30F}')),
+    ('\u{4C1}', '\u{416}', Some('\u{306}')), ('\u{4C2}', '\u{436}', Some('\u{306}')), ('\u{4D0}', '\u{410}', Some('\u{306}')), ('\u{4D1}', '\u{430}', Some('\u{306}')),
+    ('\u{4D2}', '\u{410}', Some('\u{308}')), ('\u{4D3}', '\u{430}', Some('\u{308}')), ('\u{4D6}', '\u{415}', Some('\u{306}')), ('\u{4D7}', '\u{435}', Some('\u{306}')),
+    ('\u{4DA}', '\u{4D8}', Some('\u{308}')), ('\u{4DB}', '\u{4D9}', Some('\u{308}')), ('\u{4DC}', '\u{416}', Some('\u{308}')), ('\u{4DD}', '\u{436}', Some('\u{308}')),
+    ('\u{4DE}', '\u{417}', Some('\u{308}')), ('\u{4DF}', '\u{437}', Some('\u{308}')), ('\u{4E2}', '\u{418}', Some('\u{304}')), ('\u{4E3}', '\u{438}', Some('\u{304}')),
+    ('\u{4E4}', '\u{418}', Some('\u{308}')), ('\u{4E5}', '\u{438}', Some('\u{308}')), ('\u{4E6}', '\u{41E}', Some('\u{308}')), ('\u{4E7}', '\u{43E}', Some('\u{308}')),
+    ('\u{4EA}', '\u{4E8}', Some('\u{308}')), ('\u{4EB}', '\u{4E9}', Some('\u{308}')), ('\u{4EC}', '\u{42D}', Some('\u{308}')), ('\u{4ED}', '\u{44D}', Some('\u{308}')),
+    ('\u{4EE}', '\u{423}', Some('\u{304}')), ('\u{4EF}', '\u{443}', Some('\u{304}')), ('\u{4F0}', '\u{423}', Some('\u{308}')), ('\u{4F1}', '\u{443}', Some('\u{308}')),
+    ('\u{4F2}', '\u{423}', Some('\u{30B}')), ('\u{4F3}', '\u{443}', Some('\u{30B}')), ('\u{4F4}', '\u{427}', Some('\u{308}')), ('\u{4F5}', '\u{447}', Some('\u{308}')),
+    ('\u{4F8}', '\u{42B}', Some('\u{308}')), ('\u{4F9}', '\u{44B}', Some('\u{308}')), ('\u{622}', '\u{627}', Some('\u{653}')), ('\u{623}', '\u{627}', Some('\u{654}')),
+    ('\u{624}', '\u{648}', Some('\u{654}')), ('\u{625}', '\u{627}', Some('\u{655}')), ('\u{626}', '\u{64A}', Some('\u{654}')), ('\u{6C0}', '\u{6D5}', Some('\u{654}')),
+    ('\u{6C2}', '\u{6C1}', Some('\u{654}')), ('\u{6D3}', '\u{6D2}', Some('\u{654}')), ('\u{929}', '\u{928}', Some('\u{93C}')), ('\u{931}', '\u{930}', Some('\u{93C}')),
+    ('\u{934}', '\u{933}', Some('\u{93C}')), ('\u{958}', '\u{915}', Some('\u{93C}')), ('\u{959}', '\u{916}', Some('\u{93C}')), ('\u{95A}', '\u{917}', Some('\u{93C}')),
+    ('\u{95B}', '\u{91C}', Some('\u{93C}')), ('\u{95C}', '\u{921}', Some('\u{93C}')), ('\u{95D}', '\u{922}', Some('\u{93C}')), ('\u{95E}', '\u{92B}', Some('\u{93C}')),
+    ('\u{95F}', '\u{92F}', Some('\u{93C}')), ('\u{9CB}', '\u{9C7}', Some('\u{9BE}')), ('\u{9CC}', '\u{9C7}', Some('\u{9D7}')), ('\u{9DC}', '\u{9A1}', Some('\u{9BC}')),
+    ('\u{9DD}', '\u{9A2}', Some('\u{9BC}')), ('\u{9DF}', '\u{9AF}', Some('\u{9BC}')), ('\u{A33}', '\u{A32}', Some('\u{A3C}')), ('\u{A36}', '\u{A38}', Some('\u{A3C}')),
+    ('\u{A59}', '\u{A16}', Some('\u{A3C}')), ('\u{A5A}', '\u{A17}', Some('\u{A3C}')), ('\u{A5B}', '\u{A1C}', Some('\u{A3C}')), ('\u{A5E}', '\u{A2B}', Some('\u{A3C}')),
+    ('\u{B48}', '\u{B47}', Some('\u{B56}')), ('\u{B4B}', '\u{B47}', Some('\u{B3E}')), ('\u{B4C}', '\u{B47}', Some('\u{B57}')), ('\u{B5C}', '\u{B21}', Some('\u{B3C}')),
+    ('\u{B5D}', '\u{B22}', Some('\u{B3C}')), ('\u{B94}', '\u{B92}', Some('\u{BD7}')), ('\u{BCA}', '\u{BC6}', Some('\u{BBE}')), ('\u{BCB}', '\u{BC7}', Some('\u{BBE}')),
+    ('\u{BCC}', '\u{BC6}', Some('\u{BD7}')), ('\u{C48}', '\u{C46}', Some('\u{C56}')), ('\u{CC0}', '\u{CBF}', Some('\u{CD5}')), ('\u{CC7}', '\u{CC6}', Some('\u{CD5}')),
+    ('\u{CC8}', '\u{CC6}', Some('\u{CD6}')), ('\u{CCA}', '\u{CC6}', Some('\u{CC2}')), ('\u{CCB}', '\u{CCA}', Some('\u{CD5}')), ('\u{D4A}', '\u{D46}', Some('\u{D3E}')),
+    ('\u{D4B}', '\u{D47}', Some('\u{D3E}')), ('\u{D4C}', '\u{D46}', Some('\u{D57}')), ('\u{DDA}', '\u{DD9}', Some('\u{DCA}')), ('\u{DDC}', '\u{DD9}', Some('\u{DCF}')),
+    ('\u{DDD}', '\u{DDC}', Some('\u{DCA}')), ('\u{DDE}', '\u{DD9}', Some('\u{DDF}')), ('\u{F43}', '\u{F42}', Some('\u{FB7}')), ('\u{F4D}', '\u{F4C}', Some('\u{FB7}')),
+    ('\u{F52}', '\u{F51}', Some('\u{FB7}')), ('\u{F57}', '\u{F56}', Some('\u{FB7}')), ('\u{F5C}', '\u{F5B}', Some('\u{FB7}')), ('\u{F69}', '\u{F40}', Some('\u{FB5}')),
+    ('\u{F73}', '\u{F71}', Some('\u{F72}')), ('\u{F75}', '\u{F71}', Some('\u{F74}')), ('\u{F76}', '\u{FB2}', Some('\u{F80}')), ('\u{F78}', '\u{FB3}', Some('\u{F80}')),
+    ('\u{F81}', '\u{F71}', Some('\u{F80}')), ('\u{F93}', '\u{F92}', Some('\u{FB7}')), ('\u{F9D}', '\u{F9C}', Some('\u{FB7}')), ('\u{FA2}', '\u{FA1}', Some('\u{FB7}')),
+    ('\u{FA7}', '\u{FA6}', Some('\u{FB7}')), ('\u{FAC}', '\u{FAB}', Some('\u{FB7}')), ('\u{FB9}', '\u{F90}', Some('\u{FB5}')), ('\u{1026}', '\u{1025}', Some('\u{102E}')),
+    ('\u{1B06}', '\u{1B05}', Some('\u{1B35}')), ('\u{1B08}', '\u{1B07}', Some('\u{1B35}')), ('\u{1B0A}', '\u{1B09}', Some('\u{1B35}')), ('\u{1B0C}', '\u{1B0B}', Some('\u{1B35}')),
+    ('\u{1B0E}', '\u{1B0D}', Some('\u{1B35}')), ('\u{1B12}', '\u{1B11}', Some('\u{1B35}')), ('\u{1B3B}', '\u{1B3A}', Some('\u{1B35}')), ('\u{1B3D}', '\u{1B3C}', Some('\u{1B35}')),
+    ('\u{1B40}', '\u{1B3E}', Some('\u{1B35}')), ('\u{1B41}', '\u{1B3F}', Some('\u{1B35}')), ('\u{1B43}', '\u{1B42}', Some('\u{1B35}')), ('\u{1E00}', '\u{41}', Some('\u{325}')),
+    ('\u{1E01}', '\u{61}', Some('\u{325}')), ('\u{1E02}', '\u{42}', Some('\u{307}')), ('\u{1E03}', '\u{62}', Some('\u{307}')), ('\u{1E04}', '\u{42}', Some('\u{323}')),
+    ('\u{1E05}', '\u{62}', Some('\u{323}')), ('\u{1E06}', '\u{42}', Some('\u{331}')), ('\u{1E07}', '\u{62}', Some('\u{331}')), ('\u{1E08}', '\u{C7}', Some('\u{301}')),
+    ('\u{1E09}', '\u{E7}', Some('\u{301}')), ('\u{1E0A}', '\u{44}', Some('\u{307}')), ('\u{1E0B}', '\u{64}', Some('\u{307}')), ('\u{1E0C}', '\u{44}', Some('\u{323}')),
+    ('\u{1E0D}', '\u{64}', Some('\u{323}')), ('\u{1E0E}', '\u{44}', Some('\u{331}')), ('\u{1E0F}', '\u{64}', Some('\u{331}')), ('\u{1E10}', '\u{44}', Some('\u{327}')),
+    ('\u{1E11}', '\u{64}', Some('\u{327}')), ('\u{1E12}', '\u{44}', Some('\u{32D}')), ('\u{1E13}', '\u{64}', Some('\u{32D}')), ('\u{1E14}', '\u{112}', Some('\u{300}')),
+    ('\u{1E15}', '\u{113}', Some('\u{300}')), ('\u{1E16}', '\u{112}', Some('\u{301}')), ('\u{1E17}', '\u{113}', Some('\u{301}')), ('\u{1E18}', '\u{45}', Some('\u{32D}')),
+    ('\u{1E19}', '\u{65}', Some('\u{32D}')), ('\u{1E1A}', '\u{45}', Some('\u{330}')), ('\u{1E1B}', '\u{65}', Some('\u{330}')), ('\u{1E1C}', '\u{228}', Some('\u{306}')),
+    ('\u{1E1D}', '\u{229}', Some('\u{306}')), ('\u{1E1E}', '\u{46}', Some('\u{307}')), ('\u{1E1F}', '\u{66}', Some('\u{307}')), ('\u{1E20}', '\u{47}', Some('\u{304}')),
+    ('\u{1E21}', '\u{67}', Some('\u{304}')), ('\u{1E22}', '\u{48}', Some('\u{307}')), ('\u{1E23}', '\u{68}', Some('\u{307}')), ('\u{1E24}', '\u{48}', Some('\u{323}')),
+    ('\u{1E25}', '\u{68}', Some('\u{323}')), ('\u{1E26}', '\u{48}', Some('\u{308}')), ('\u{1E27}', '\u{68}', Some('\u{308}')), ('\u{1E28}', '\u{48}', Some('\u{327}')),
+    ('\u{1E29}', '\u{68}', Some('\u{327}')), ('\u{1E2A}', '\u{48}', Some('\u{32E}')), ('\u{1E2B}', '\u{68}', Some('\u{32E}')), ('\u{1E2C}', '\u{49}', Some('\u{330}')),
+    ('\u{1E2D}', '\u{69}', Some('\u{330}')), ('\u{1E2E}', '\u{CF}', Some('\u{301}')), ('\u{1E2F}', '\u{EF}', Some('\u{301}')), ('\u{1E30}', '\u{4B}', Some('\u{301}')),
+    ('\u{1E31}', '\u{6B}', Some('\u{301}')), ('\u{1E32}', '\u{4B}', Some('\u{323}')), ('\u{1E33}', '\u{6B}', Some('\u{323}')), ('\u{1E34}', '\u{4B}', Some('\u{331}')),
+    ('\u{1E35}', '\u{6B}', Some('\u{331}')), ('\u{1E36}', '\u{4C}', Some('\u{323}')), ('\u{1E37}', '\u{6C}', Some('\u{323}')), ('\u{1E38}', '\u{1E36}', Some('\u{304}')),
+    ('\u{1E39}', '\u{1E37}', Some('\u{304}')), ('\u{1E3A}', '\u{4C}', Some('\u{331}')), ('\u{1E3B}', '\u{6C}', Some('\u{331}')), ('\u{1E3C}', '\u{4C}', Some('\u{32D}')),
+    ('\u{1E3D}', '\u{6C}', Some('\u{32D}')), ('\u{1E3E}', '\u{4D}', Some('\u{301}')), ('\u{1E3F}', '\u{6D}', Some('\u{301}')), ('\u{1E40}', '\u{4D}', Some('\u{307}')),
+    ('\u{1E41}', '\u{6D}', Some('\u{307}')), ('\u{1E42}', '\u{4D}', Some('\u{323}')), ('\u{1E43}', '\u{6D}', Some('\u{323}')), ('\u{1E44}', '\u{4E}', Some('\u{307}')),
+    ('\u{1E45}', '\u{6E}', Some('\u{307}')), ('\u{1E46}', '\u{4E}', Some('\u{323}')), ('\u{1E47}', '\u{6E}', Some('\u{323}')), ('\u{1E48}', '\u{4E}', Some('\u{331}')),
+    ('\u{1E49}', '\u{6E}', Some('\u{331}')), ('\u{1E4A}', '\u{4E}', Some('\u{32D}')), ('\u{1E4B}', '\u{6E}', Some('\u{32D}')), ('\u{1E4C}', '\u{D5}', Some('\u{301}')),
+    ('\u{1E4D}', '\u{F5}', Some('\u{301}')), ('\u{1E4E}', '\u{D5}', Some('\u{308}')), ('\u{1E4F}', '\u{F5}', Some('\u{308}')), ('\u{1E50}', '\u{14C}', Some('\u{300}')),
+    ('\u{1E51}', '\u{14D}', Some('\u{300}')), ('\u{1E52}', '\u{14C}', Some('\u{301}')), ('\u{1E53}', '\u{14D}', Some('\u{301}')), ('\u{1E54}', '\u{50}', Some('\u{301}')),
+    ('\u{1E55}', '\u{70}', Some('\u{301}')), ('\u{1E56}', '\u{50}', Some('\u{307}')), ('\u{1E57}', '\u{70}', Some('\u{307}')), ('\u{1E58}', '\u{52}', Some('\u{307}')),
+    ('\u{1E59}', '\u{72}', Some('\u{307}')), ('\u{1E5A}', '\u{52}', Some('\u{323}')), ('\u{1E5B}', '\u{72}', Some('\u{323}')), ('\u{1E5C}', '\u{1E5A}', Some('\u{304}')),
+    ('\u{1E5D}', '\u{1E5B}', Some('\u{304}')), ('\u{1E5E}', '\u{52}', Some('\u{331}')), ('\u{1E5F}', '\u{72}', Some('\u{331}')), ('\u{1E60}', '\u{53}', Some('\u{307}')),
+    ('\u{1E61}', '\u{73}', Some('\u{307}')), ('\u{1E62}', '\u{53}', Some('\u{323}')), ('\u{1E63}', '\u{73}', Some('\u{323}')), ('\u{1E64}', '\u{15A}', Some('\u{307}')),
+    ('\u{1E65}', '\u{15B}', Some('\u{307}')), ('\u{1E66}', '\u{160}', Some('\u{307}')), ('\u{1E67}', '\u{161}', Some('\u{307}')), ('\u{1E68}', '\u{1E62}', Some('\u{307}')),
+    ('\u{1E69}', '\u{1E63}', Some('\u{307}')), ('\u{1E6A}', '\u{54}', Some('\u{307}')), ('\u{1E6B}', '\u{74}', Some('\u{307}')), ('\u{1E6C}', '\u{54}', Some('\u{323}')),
+    ('\u{1E6D}', '\u{74}', Some('\u{323}')), ('\u{1E6E}', '\u{54}', Some('\u{331}')), ('\u{1E6F}', '\u{74}', Some('\u{331}')), ('\u{1E70}', '\u{54}', Some('\u{32D}')),
+    ('\u{1E71}', '\u{74}', Some('\u{32D}')), ('\u{1E72}', '\u{55}', Some('\u{324}')), ('\u{1E73}', '\u{75}', Some('\u{324}')), ('\u{1E74}', '\u{55}', Some('\u{330}')),
+    ('\u{1E75}', '\u{75}', Some('\u{330}')), ('\u{1E76}', '\u{55}', Some('\u{32D}')), ('\u{1E77}', '\u{75}', Some('\u{32D}')), ('\u{1E78}', '\u{168}', Some('\u{301}')),
+    ('\u{1E79}', '\u{169}', Some('\u{301}')), ('\u{1E7A}', '\u{16A}', Some('\u{308}')), ('\u{1E7B}', '\u{16B}', Some('\u{308}')), ('\u{1E7C}', '\u{56}', Some('\u{303}')),
+    ('\u{1E7D}', '\u{76}', Some('\u{303}')), ('\u{1E7E}', '\u{56}', Some('\u{323}')), ('\u{1E7F}', '\u{76}', Some('\u{323}')), ('\u{1E80}', '\u{57}', Some('\u{300}')),
+    ('\u{1E81}', '\u{77}', Some('\u{300}')), ('\u{1E82}', '\u{57}', Some('\u{301}')), ('\u{1E83}', '\u{77}', Some('\u{301}')), ('\u{1E84}', '\u{57}', Some('\u{308}')),
+    ('\u{1E85}', '\u{77}', Some('\u{308}')), ('\u{1E86}', '\u{57}', Some('\u{307}')), ('\u{1E87}', '\u{77}', Some('\u{307}')), ('\u{1E88}', '\u{57}', Some('\u{323}')),
+    ('\u{1E89}', '\u{77}', Some('\u{323}')), ('\u{1E8A}', '\u{58}', Some('\u{307}')), ('\u{1E8B}', '\u{78}', Some('\u{307}')), ('\u{1E8C}', '\u{58}', Some('\u{308}')),
+    ('\u{1E8D}', '\u{78}', Some('\u{308}')), ('\u{1E8E}', '\u{59}', Some('\u{307}')), ('\u{1E8F}', '\u{79}', Some('\u{307}')), ('\u{1E90}', '\u{5A}', Some('\u{302}')),
+    ('\u{1E91}', '\u{7A}', Some('\u{302}')), ('\u{1E92}', '\u{5A}', Some('\u{323}')), ('\u{1E93}', '\u{7A}', Some('\u{323}')), ('\u{1E94}', '\u{5A}', Some('\u{331}')),
+    ('\u{1E95}', '\u{7A}', Some('\u{331}')), ('\u{1E96}', '\u{68}', Some('\u{331}')), ('\u{1E97}', '\u{74}', Some('\u{308}')), ('\u{1E98}', '\u{77}', Some('\u{30A}')),
+    ('\u{1E99}', '\u{79}', Some('\u{30A}')), ('\u{1E9B}', '\u{17F}', Some('\u{307}')), ('\u{1EA0}', '\u{41}', Some('\u{323}')), ('\u{1EA1}', '\u{61}', Some('\u{323}')),
+    ('\u{1EA2}', '\u{41}', Some('\u{309}')), ('\u{1EA3}', '\u{61}', Some('\u{309}')), ('\u{1EA4}', '\u{C2}', Some('\u{301}')), ('\u{1EA5}', '\u{E2}', Some('\u{301}')),
+    ('\u{1EA6}', '\u{C2}', Some('\u{300}')), ('\u{1EA7}', '\u{E2}', Some('\u{300}')), ('\u{1EA8}', '\u{C2}', Some('\u{309}')), ('\u{1EA9}', '\u{E2}', Some('\u{309}')),
+    ('\u{1EAA}', '\u{C2}', Some('\u{303}')), ('\u{1EAB}', '\u{E2}', Some('\u{303}')), ('\u{1EAC}', '\u{1EA0}', Some('\u{302}')), ('\u{1EAD}', '\u{1EA1}', Some('\u{302}')),
+    ('\u{1EAE}', '\u{102}', Some('\u{301}')), ('\u{1EAF}', '\u{103}', Some('\u{301}')), ('\u{1EB0}', '\u{102}', Some('\u{300}')), ('\u{1EB1}', '\u{103}', Some('\u{300}')),
+    ('\u{1EB2}', '\u{102}', Some('\u{309}')), ('\u{1EB3}', '\u{103}', Some('\u{309}')), ('\u{1EB4}', '\u{102}', Some('\u{303}')), ('\u{1EB5}', '\u{103}', Some('\u{303}')),
+    ('\u{1EB6}', '\u{1EA0}', Some('\u{306}')), ('\u{1EB7}', '\u{1EA1}', Some('\u{306}')), ('\u{1EB8}', '\u{45}', Some('\u{323}')), ('\u{1EB9}', '\u{65}', Some('\u{323}')),
+    ('\u{1EBA}', '\u{45}', Some('\u{309}')), ('\u{1EBB}', '\u{65}', Some('\u{309}')), ('\u{1EBC}', '\u{45}', Some('\u{303}')), ('\u{1EBD}', '\u{65}', Some('\u{303}')),
+    ('\u{1EBE}', '\u{CA}', Some('\u{301}')), ('\u{1EBF}', '\u{EA}', Some('\u{301}')), ('\u{1EC0}', '\u{CA}', Some('\u{300}')), ('\u{1EC1}', '\u{EA}', Some('\u{300}')),
+    ('\u{1EC2}', '\u{CA}', Some('\u{309}')), ('\u{1EC3}', '\u{EA}', Some('\u{309}')), ('\u{1EC4}', '\u{CA}', Some('\u{303}')), ('\u{1EC5}', '\u{EA}', Some('\u{303}')),
+    ('\u{1EC6}', '\u{1EB8}', Some('\u{302}')), ('\u{1EC7}', '\u{1EB9}', Some('\u{302}')), ('\u{1EC8}', '\u{49}', Some('\u{309}')), ('\u{1EC9}', '\u{69}', Some('\u{309}')),
+    ('\u{1ECA}', '\u{49}', Some('\u{323}')), ('\u{1ECB}', '\u{69}', Some('\u{323}')), ('\u{1ECC}', '\u{4F}', Some('\u{323}')), ('\u{1ECD}', '\u{6F}', Some('\u{323}')),
+    ('\u{1ECE}', '\u{4F}', Some('\u{309}')), ('\u{1ECF}', '\u{6F}', Some('\u{309}')), ('\u{1ED0}', '\u{D4}', Some('\u{301}')), ('\u{1ED1}', '\u{F4}', Some('\u{301}')),
+    ('\u{1ED2}', '\u{D4}', Some('\u{300}')), ('\u{1ED3}', '\u{F4}', Some('\u{300}')), ('\u{1ED4}', '\u{D4}', Some('\u{309}')), ('\u{1ED5}', '\u{F4}', Some('\u{309}')),
+    ('\u{1ED6}', '\u{D4}', Some('\u{303}')), ('\u{1ED7}', '\u{F4}', Some('\u{303}')), ('\u{1ED8}', '\u{1ECC}', Some('\u{302}')), ('\u{1ED9}', '\u{1ECD}', Some('\u{302}')),
+    ('\u{1EDA}', '\u{1A0}', Some('\u{301}')), ('\u{1EDB}', '\u{1A1}', Some('\u{301}')), ('\u{1EDC}', '\u{1A0}', Some('\u{300}')), ('\u{1EDD}', '\u{1A1}', Some('\u{300}')),
+    ('\u{1EDE}', '\u{1A0}', Some('\u{309}')), ('\u{1EDF}', '\u{1A1}', Some('\u{309}')), ('\u{1EE0}', '\u{1A0}', Some('\u{303}')), ('\u{1EE1}', '\u{1A1}', Some('\u{303}')),
+    ('\u{1EE2}', '\u{1A0}', Some('\u{323}')), ('\u{1EE3}', '\u{1A1}', Some('\u{323}')), ('\u{1EE4}', '\u{55}', Some('\u{323}')), ('\u{1EE5}', '\u{75}', Some('\u{323}')),
+    ('\u{1EE6}', '\u{55}', Some('\u{309}')), ('\u{1EE7}', '\u{75}', Some('\u{309}')), ('\u{1EE8}', '\u{1AF}', Some('\u{301}')), ('\u{1EE9}', '\u{1B0}', Some('\u{301}')),
+    ('\u{1EEA}', '\u{1AF}', Some('\u{300}')), ('\u{1EEB}', '\u{1B0}', Some('\u{300}')), ('\u{1EEC}', '\u{1AF}', Some('\u{309}')), ('\u{1EED}', '\u{1B0}', Some('\u{309}')),
+    ('\u{1EEE}', '\u{1AF}', Some('\u{303}')), ('\u{1EEF}', '\u{1B0}', Some('\u{303}')), ('\u{1EF0}', '\u{1AF}', Some('\u{323}')), ('\u{1EF1}', '\u{1B0}', Some('\u{323}')),
+    ('\u{1EF2}', '\u{59}', Some('\u{300}')), ('\u{1EF3}', '\u{79}', Some('\u{300}')), ('\u{1EF4}', '\u{59}', Some('\u{323}')), ('\u{1EF5}', '\u{79}', Some('\u{323}')),
+    ('\u{1EF6}', '\u{59}', Some('\u{309}')), ('\u{1EF7}', '\u{79}', Some('\u{309}')), ('\u{1EF8}', '\u{59}', Some('\u{303}')), ('\u{1EF9}', '\u{79}', Some('\u{303}')),
+    ('\u{1F00}', '\u{3B1}', Some('\u{313}')), ('\u{1F01}', '\u{3B1}', Some('\u{314}')), ('\u{1F02}', '\u{1F00}', Some('\u{300}')), ('\u{1F03}', '\u{1F01}', Some('\u{300}')),
+    ('\u{1F04}', '\u{1F00}', Some('\u{301}')), ('\u{1F05}', '\u{1F01}', Some('\u{301}')), ('\u{1F06}', '\u{1F00}', Some('\u{342}')), ('\u{1F07}', '\u{1F01}', Some('\u{342}')),
+    ('\u{1F08}', '\u{391}', Some('\u{313}')), ('\u{1F09}', '\u{391}', Some('\u{314}')), ('\u{1F0A}', '\u{1F08}', Some('\u{300}')), ('\u{1F0B}', '\u{1F09}', Some('\u{300}')),
+    ('\u{1F0C}', '\u{1F08}', Some('\u{301}')), ('\u{1F0D}', '\u{1F09}', Some('\u{301}')), ('\u{1F0E}', '\u{1F08}', Some('\u{342}')), ('\u{1F0F}', '\u{1F09}', Some('\u{342}')),
+    ('\u{1F10}', '\u{3B5}', Some('\u{313}')), ('\u{1F11}', '\u{3B5}', Some('\u{314}')), ('\u{1F12}', '\u{1F10}', Some('\u{300}')), ('\u{1F13}', '\u{1F11}', Some('\u{300}')),
+    ('\u{1F14}', '\u{1F10}', Some('\u{301}')), ('\u{1F15}', '\u{1F11}', Some('\u{301}')), ('\u{1F18}', '\u{395}', Some('\u{313}')), ('\u{1F19}', '\u{395}', Some('\u{314}')),
+    ('\u{1F1A}', '\u{1F18}', Some('\u{300}')), ('\u{1F1B}', '\u{1F19}', Some('\u{300}')), ('\u{1F1C}', '\u{1F18}', Some('\u{301}')), ('\u{1F1D}', '\u{1F19}', Some('\u{301}')),
+    ('\u{1F20}', '\u{3B7}', Some('\u{313}')), ('\u{1F21}', '\u{3B7}', Some('\u{314}')), ('\u{1F22}', '\u{1F20}', Some('\u{300}')), ('\u{1F23}', '\u{1F21}', Some('\u{300}')),
+    ('\u{1F24}', '\u{1F20}', Some('\u{301}')), ('\u{1F25}', '\u{1F21}', Some('\u{301}')), ('\u{1F26}', '\u{1F20}', Some('\u{342}')), ('\u{1F27}', '\u{1F21}', Some('\u{342}')),
+    ('\u{1F28}', '\u{397}', Some('\u{313}')), ('\u{1F29}', '\u{397}', Some('\u{314}')), ('\u{1F2A}', '\u{1F28}', Some('\u{300}')), ('\u{1F2B}', '\u{1F29}', Some('\u{300}')),
+    ('\u{1F2C}', '\u{1F28}', Some('\u{301}')), ('\u{1F2D}', '\u{1F29}', Some('\u{301}')), ('\u{1F2E}', '\u{1F28}', Some('\u{342}')), ('\u{1F2F}', '\u{1F29}', Some('\u{342}')),
+    ('\u{1F30}', '\u{3B9}', Some('\u{313}')), ('\u{1F31}', '\u{3B9}', Some('\u{314}')), ('\u{1F32}', '\u{1F30}', Some('\u{300}')), ('\u{1F33}', '\u{1F31}', Some('\u{300}')),
+    ('\u{1F34}', '\u{1F30}', Some('\u{301}')), ('\u{1F35}', '\u{1F31}', Some('\u{301}')), ('\u{1F36}', '\u{1F30}', Some('\u{342}')), ('\u{1F37}', '\u{1F31}', Some('\u{342}')),
+    ('\u{1F38}', '\u{399}', Some('\u{313}')), ('\u{1F39}', '\u{399}', Some('\u{314}')), ('\u{1F3A}', '\u{1F38}', Some('\u{300}')), ('\u{1F3B}', '\u{1F39}', Some('\u{300}')),
+    ('\u{1F3C}', '\u{1F38}', Some('\u{301}')), ('\u{1F3D}', '\u{1F39}', Some('\u{301}')), ('\u{1F3E}', '\u{1F38}', Some('\u{342}')), ('\u{1F3F}', '\u{1F39}', Some('\u{342}')),
+    ('\u{1F40}', '\u{3BF}', Some('\u{313}')), ('\u{1F41}', '\u{3BF}', Some('\u{314}')), ('\u{1F42}', '\u{1F40}', Some('\u{300}')), ('\u{1F43}', '\u{1F41}', Some('\u{300}')),
+    ('\u{1F44}', '\u{1F40}', Some('\u{301}')), ('\u{1F45}', '\u{1F41}', Some('\u{301}')), ('\u{1F48}', '\u{39F}', Some('\u{313}')), ('\u{1F49}', '\u{39F}', Some('\u{314}')),
+    ('\u{1F4A}', '\u{1F48}', Some('\u{300}')), ('\u{1F4B}', '\u{1F49}', Some('\u{300}')), ('\u{1F4C}', '\u{1F48}', Some('\u{301}')), ('\u{1F4D}', '\u{1F49}', Some('\u{301}')),
+    ('\u{1F50}', '\u{3C5}', Some('\u{313}')), ('\u{1F51}', '\u{3C5}', Some('\u{314}')), ('\u{1F52}', '\u{1F50}', Some('\u{300}')), ('\u{1F53}', '\u{1F51}', Some('\u{300}')),
+    ('\u{1F54}', '\u{1F50}', Some('\u{301}')), ('\u{1F55}', '\u{1F51}', Some('\u{301}')), ('\u{1F56}', '\u{1F50}', Some('\u{342}')), ('\u{1F57}', '\u{1F51}', Some('\u{342}')),
+    ('\u{1F59}', '\u{3A5}', Some('\u{314}')), ('\u{1F5B}', '\u{1F59}', Some('\u{300}')), ('\u{1F5D}', '\u{1F59}', Some('\u{301}')), ('\u{1F5F}', '\u{1F59}', Some('\u{342}')),
+    ('\u{1F60}', '\u{3C9}', Some('\u{313}')), ('\u{1F61}', '\u{3C9}', Some('\u{314}')), ('\u{1F62}', '\u{1F60}', Some('\u{300}')), ('\u{1F63}', '\u{1F61}', Some('\u{300}')),
+    ('\u{1F64}', '\u{1F60}', Some('\u{301}')), ('\u{1F65}', '\u{1F61}', Some('\u{301}')), ('\u{1F66}', '\u{1F60}', Some('\u{342}')), ('\u{1F67}', '\u{1F61}', Some('\u{342}')),
+    ('\u{1F68}', '\u{3A9}', Some('\u{313}')), ('\u{1F69}', '\u{3A9}', Some('\u{314}')), ('\u{1F6A}', '\u{1F68}', Some('\u{300}')), ('\u{1F6B}', '\u{1F69}', Some('\u{300}')),
+    ('\u{1F6C}', '\u{1F68}', Some('\u{301}')), ('\u{1F6D}', '\u{1F69}', Some('\u{301}')), ('\u{1F6E}', '\u{1F68}', Some('\u{342}')), ('\u{1F6F}', '\u{1F69}', Some('\u{342}')),
+    ('\u{1F70}', '\u{3B1}', Some('\u{300}')), ('\u{1F71}', '\u{3AC}', None), ('\u{1F72}', '\u{3B5}', Some('\u{300}')), ('\u{1F73}', '\u{3AD}', None),
+    ('\u{1F74}', '\u{3B7}', Some('\u{300}')), ('\u{1F75}', '\u{3AE}', None), ('\u{1F76}', '\u{3B9}', Some('\u{300}')), ('\u{1F77}', '\u{3AF}', None),
+    ('\u{1F78}', '\u{3BF}', Some('\u{300}')), ('\u{1F79}', '\u{3CC}', None), ('\u{1F7A}', '\u{3C5}', Some('\u{300}')), ('\u{1F7B}', '\u{3CD}', None),
+    ('\u{1F7C}', '\u{3C9}', Some('\u{300}')), ('\u{1F7D}', '\u{3CE}', None), ('\u{1F80}', '\u{1F00}', Some('\u{345}')), ('\u{1F81}', '\u{1F01}', Some('\u{345}')),
+    ('\u{1F82}', '\u{1F02}', Some('\u{345}')), ('\u{1F83}', '\u{1F03}', Some('\u{345}')), ('\u{1F84}', '\u{1F04}', Some('\u{345}')), ('\u{1F85}', '\u{1F05}', Some('\u{345}')),
+    ('\u{1F86}', '\u{1F06}', Some('\u{345}')), ('\u{1F87}', '\u{1F07}', Some('\u{345}')), ('\u{1F88}', '\u{1F08}', Some('\u{345}')), ('\u{1F89}', '\u{1F09}', Some('\u{345}')),
+    ('\u{1F8A}', '\u{1F0A}', Some('\u{345}')), ('\u{1F8B}', '\u{1F0B}', Some('\u{345}')), ('\u{1F8C}', '\u{1F0C}', Some('\u{345}')), ('\u{1F8D}', '\u{1F0D}', Some('\u{345}')),
+    ('\u{1F8E}', '\u{1F0E}', Some('\u{345}')), ('\u{1F8F}', '\u{1F0F}', Some('\u{345}')), ('\u{1F90}', '\u{1F20}', Some('\u{345}')), ('\u{1F91}', '\u{1F21}', Some('\u{345}')),
+    ('\u{1F92}', '\u{1F22}', Some('\u{345}')), ('\u{1F93}', '\u{1F23}', Some('\u{345}')), ('\u{1F94}', '\u{1F24}', Some('\u{345}')), ('\u{1F95}', '\u{1F25}', Some('\u{345}')),
+    ('\u{1F96}', '\u{1F26}', Some('\u{345}')), ('\u{1F97}', '\u{1F27}', Some('\u{345}')), ('\u{1F98}', '\u{1F28}', Some('\u{345}')), ('\u{1F99}', '\u{1F29}', Some('\u{345}')),
+    ('\u{1F9A}', '\u{1F2A}', Some('\u{345}')), ('\u{1F9B}', '\u{1F2B}', Some('\u{345}')), ('\u{1F9C}', '\u{1F2C}', Some('\u{345}')), ('\u{1F9D}', '\u{1F2D}', Some('\u{345}')),
+    ('\u{1F9E}', '\u{1F2E}', Some('\u{345}')), ('\u{1F9F}', '\u{1F2F}', Some('\u{345}')), ('\u{1FA0}', '\u{1F60}', Some('\u{345}')), ('\u{1FA1}', '\u{1F61}', Some('\u{345}')),
+    ('\u{1FA2}', '\u{1F62}', Some('\u{345}')), ('\u{1FA3}', '\u{1F63}', Some('\u{345}')), ('\u{1FA4}', '\u{1F64}', Some('\u{345}')), ('\u{1FA5}', '\u{1F65}', Some('\u{345}')),
+    ('\u{1FA6}', '\u{1F66}', Some('\u{345}')), ('\u{1FA7}', '\u{1F67}', Some('\u{345}')), ('\u{1FA8}', '\u{1F68}', Some('\u{345}')), ('\u{1FA9}', '\u{1F69}', Some('\u{345}')),
+    ('\u{1FAA}', '\u{1F6A}', Some('\u{345}')), ('\u{1FAB}', '\u{1F6B}', Some('\u{345}')), ('\u{1FAC}', '\u{1F6C}', Some('\u{345}')), ('\u{1FAD}', '\u{1F6D}', Some('\u{345}')),
+    ('\u{1FAE}', '\u{1F6E}', Some('\u{345}')), ('\u{1FAF}', '\u{1F6F}', Some('\u{345}')), ('\u{1FB0}', '\u{3B1}', Some('\u{306}')), ('\u{1FB1}', '\u{3B1}', Some('\u{304}')),
+    ('\u{1FB2}', '\u{1F70}', Some('\u{345}')), ('\u{1FB3}', '\u{3B1}', Some('\u{345}')), ('\u{1FB4}', '\u{3AC}', Some('\u{345}')), ('\u{1FB6}', '\u{3B1}', Some('\u{342}')),
+    ('\u{1FB7}', '\u{1FB6}', Some('\u{345}')), ('\u{1FB8}', '\u{391}', Some('\u{306}')), ('\u{1FB9}', '\u{391}', Some('\u{304}')), ('\u{1FBA}', '\u{391}', Some('\u{300}')),
+    ('\u{1FBB}', '\u{386}', None), ('\u{1FBC}', '\u{391}', Some('\u{345}')), ('\u{1FBE}', '\u{3B9}', None), ('\u{1FC1}', '\u{A8}', Some('\u{342}')),
+    ('\u{1FC2}', '\u{1F74}', Some('\u{345}')), ('\u{1FC3}', '\u{3B7}', Some('\u{345}')), ('\u{1FC4}', '\u{3AE}', Some('\u{345}')), ('\u{1FC6}', '\u{3B7}', Some('\u{342}')),
+    ('\u{1FC7}', '\u{1FC6}', Some('\u{345}')), ('\u{1FC8}', '\u{395}', Some('\u{300}')), ('\u{1FC9}', '\u{388}', None), ('\u{1FCA}', '\u{397}', Some('\u{300}')),
+    ('\u{1FCB}', '\u{389}', None), ('\u{1FCC}', '\u{397}', Some('\u{345}')), ('\u{1FCD}', '\u{1FBF}', Some('\u{300}')), ('\u{1FCE}', '\u{1FBF}', Some('\u{301}')),
+    ('\u{1FCF}', '\u{1FBF}', Some('\u{342}')), ('\u{1FD0}', '\u{3B9}', Some('\u{306}')), ('\u{1FD1}', '\u{3B9}', Some('\u{304}')), ('\u{1FD2}', '\u{3CA}', Some('\u{300}')),
+    ('\u{1FD3}', '\u{390}', None), ('\u{1FD6}', '\u{3B9}', Some('\u{342}')), ('\u{1FD7}', '\u{3CA}', Some('\u{342}')), ('\u{1FD8}', '\u{399}', Some('\u{306}')),
+    ('\u{1FD9}', '\u{399}', Some('\u{304}')), ('\u{1FDA}', '\u{399}', Some('\u{300}')), ('\u{1FDB}', '\u{38A}', None), ('\u{1FDD}', '\u{1FFE}', Some('\u{300}')),
+    ('\u{1FDE}', '\u{1FFE}', Some('\u{301}')), ('\u{1FDF}', '\u{1FFE}', Some('\u{342}')), ('\u{1FE0}', '\u{3C5}', Some('\u{306}')), ('\u{1FE1}', '\u{3C5}', Some('\u{304}')),
+    ('\u{1FE2}', '\u{3CB}', Some('\u{300}')), ('\u{1FE3}', '\u{3B0}', None), ('\u{1FE4}', '\u{3C1}', Some('\u{313}')), ('\u{1FE5}', '\u{3C1}', Some('\u{314}')),
+    ('\u{1FE6}', '\u{3C5}', Some('\u{342}')), ('\u{1FE7}', '\u{3CB}', Some('\u{342}')), ('\u{1FE8}', '\u{3A5}', Some('\u{306}')), ('\u{1FE9}', '\u{3A5}', Some('\u{304}')),
+    ('\u{1FEA}', '\u{3A5}', Some('\u{300}')), ('\u{1FEB}', '\u{38E}', None), ('\u{1FEC}', '\u{3A1}', Some('\u{314}')), ('\u{1FED}', '\u{A8}', Some('\u{300}')),
+    ('\u{1FEE}', '\u{385}', None), ('\u{1FEF}', '\u{60}', None), ('\u{1FF2}', '\u{1F7C}', Some('\u{345}')), ('\u{1FF3}', '\u{3C9}', Some('\u{345}')),
+    ('\u{1FF4}', '\u{3CE}', Some('\u{345}')), ('\u{1FF6}', '\u{3C9}', Some('\u{342}')), ('\u{1FF7}', '\u{1FF6}', Some('\u{345}')), ('\u{1FF8}', '\u{39F}', Some('\u{300}')),
+    ('\u{1FF9}', '\u{38C}', None), ('\u{1FFA}', '\u{3A9}', Some('\u{300}')), ('\u{1FFB}', '\u{38F}', None), ('\u{1FFC}', '\u{3A9}', Some('\u{345}')),
+    ('\u{1FFD}', '\u{B4}', None), ('\u{2000}', '\u{2002}', None), ('\u{2001}', '\u{2003}', None), ('\u{2126}', '\u{3A9}', None),
+    ('\u{212A}', '\u{4B}', None), ('\u{212B}', '\u{C5}', None), ('\u{219A}', '\u{2190}', Some('\u{338}')), ('\u{219B}', '\u{2192}', Some('\u{338}')),
+    ('\u{21AE}', '\u{2194}', Some('\u{338}')), ('\u{21CD}', '\u{21D0}', Some('\u{338}')), ('\u{21CE}', '\u{21D4}', Some('\u{338}')), ('\u{21CF}', '\u{21D2}', Some('\u{338}')),
+    ('\u{2204}', '\u{2203}', Some('\u{338}')), ('\u{2209}', '\u{2208}', Some('\u{338}')), ('\u{220C}', '\u{220B}', Some('\u{338}')), ('\u{2224}', '\u{2223}', Some('\u{338}')),
+    ('\u{2226}', '\u{2225}', Some('\u{338}')), ('\u{2241}', '\u{223C}', Some('\u{338}')), ('\u{2244}', '\u{2243}', Some('\u{338}')), ('\u{2247}', '\u{2245}', Some('\u{338}')),
+    ('\u{2249}', '\u{2248}', Some('\u{338}')), ('\u{2260}', '\u{3D}', Some('\u{338}')), ('\u{2262}', '\u{2261}', Some('\u{338}')), ('\u{226D}', '\u{224D}', Some('\u{338}')),
+    ('\u{226E}', '\u{3C}', Some('\u{338}')), ('\u{226F}', '\u{3E}', Some('\u{338}')), ('\u{2270}', '\u{2264}', Some('\u{338}')), ('\u{2271}', '\u{2265}', Some('\u{338}')),
+    ('\u{2274}', '\u{2272}', Some('\u{338}')), ('\u{2275}', '\u{2273}', Some('\u{338}')), ('\u{2278}', '\u{2276}', Some('\u{338}')), ('\u{2279}', '\u{2277}', Some('\u{338}')),
+    ('\u{2280}', '\u{227A}', Some('\u{338}')), ('\u{2281}', '\u{227B}', Some('\u{338}')), ('\u{2284}', '\u{2282}', Some('\u{338}')), ('\u{2285}', '\u{2283}', Some('\u{338}')),
+    ('\u{2288}', '\u{2286}', Some('\u{338}')), ('\u{2289}', '\u{2287}', Some('\u{338}')), ('\u{22AC}', '\u{22A2}', Some('\u{338}')), ('\u{22AD}', '\u{22A8}', Some('\u{338}')),
+    ('\u{22AE}', '\u{22A9}', Some('\u{338}')), ('\u{22AF}', '\u{22AB}', Some('\u{338}')), ('\u{22E0}', '\u{227C}', Some('\u{338}')), ('\u{22E1}', '\u{227D}', Some('\u{338}')),
+    ('\u{22E2}', '\u{2291}', Some('\u{338}')), ('\u{22E3}', '\u{2292}', Some('\u{338}')), ('\u{22EA}', '\u{22B2}', Some('\u{338}')), ('\u{22EB}', '\u{22B3}', Some('\u{338}')),
+    ('\u{22EC}', '\u{22B4}', Some('\u{338}')), ('\u{22ED}', '\u{22B5}', Some('\u{338}')), ('\u{2329}', '\u{3008}', None), ('\u{232A}', '\u{3009}', None),
+    ('\u{2ADC}', '\u{2ADD}', Some('\u{338}')), ('\u{304C}', '\u{304B}', Some('\u{3099}')), ('\u{304E}', '\u{304D}', Some('\u{3099}')), ('\u{3050}', '\u{304F}', Some('\u{3099}')),
+    ('\u{3052}', '\u{3051}', Some('\u{3099}')), ('\u{3054}', '\u{3053}', Some('\u{3099}')), ('\u{3056}', '\u{3055}', Some('\u{3099}')), ('\u{3058}', '\u{3057}', Some('\u{3099}')),
+    ('\u{305A}', '\u{3059}', Some('\u{3099}')), ('\u{305C}', '\u{305B}', Some('\u{3099}')), ('\u{305E}', '\u{305D}', Some('\u{3099}')), ('\u{3060}', '\u{305F}', Some('\u{3099}')),
+    ('\u{3062}', '\u{3061}', Some('\u{3099}')), ('\u{3065}', '\u{3064}', Some('\u{3099}')), ('\u{3067}', '\u{3066}', Some('\u{3099}')), ('\u{3069}', '\u{3068}', Some('\u{3099}')),
+    ('\u{3070}', '\u{306F}', Some('\u{3099}')), ('\u{3071}', '\u{306F}', Some('\u{309A}')), ('\u{3073}', '\u{3072}', Some('\u{3099}')), ('\u{3074}', '\u{3072}', Some('\u{309A}')),
+    ('\u{3076}', '\u{3075}', Some('\u{3099}')), ('\u{3077}', '\u{3075}', Some('\u{309A}')), ('\u{3079}', '\u{3078}', Some('\u{3099}')), ('\u{307A}', '\u{3078}', Some('\u{309A}')),
+    ('\u{307C}', '\u{307B}', Some('\u{3099}')), ('\u{307D}', '\u{307B}', Some('\u{309A}')), ('\u{3094}', '\u{3046}', Some('\u{3099}')), ('\u{309E}', '\u{309D}', Some('\u{3099}')),
+    ('\u{30AC}', '\u{30AB}', Some('\u{3099}')), ('\u{30AE}', '\u{30AD}', Some('\u{3099}')), ('\u{30B0}', '\u{30AF}', Some('\u{3099}')), ('\u{30B2}', '\u{30B1}', Some('\u{3099}')),
+    ('\u{30B4}', '\u{30B3}', Some('\u{3099}')), ('\u{30B6}', '\u{30B5}', Some('\u{3099}')), ('\u{30B8}', '\u{30B7}', Some('\u{3099}')), ('\u{30BA}', '\u{30B9}', Some('\u{3099}')),
+    ('\u{30BC}', '\u{30BB}', Some('\u{3099}')), ('\u{30BE}', '\u{30BD}', Some('\u{3099}')), ('\u{30C0}', '\u{30BF}', Some('\u{3099}')), ('\u{30C2}', '\u{30C1}', Some('\u{3099}')),
+    ('\u{30C5}', '\u{30C4}', Some('\u{3099}')), ('\u{30C7}', '\u{30C6}', Some('\u{3099}')), ('\u{30C9}', '\u{30C8}', Some('\u{3099}')), ('\u{30D0}', '\u{30CF}', Some('\u{3099}')),
+    ('\u{30D1}', '\u{30CF}', Some('\u{309A}')), ('\u{30D3}', '\u{30D2}', Some('\u{3099}')), ('\u{30D4}', '\u{30D2}', Some('\u{309A}')), ('\u{30D6}', '\u{30D5}', Some('\u{3099}')),
+    ('\u{30D7}', '\u{30D5}', Some('\u{309A}')), ('\u{30D9}', '\u{30D8}', Some('\u{3099}')), ('\u{30DA}', '\u{30D8}', Some('\u{309A}')), ('\u{30DC}', '\u{30DB}', Some('\u{3099}')),
+    ('\u{30DD}', '\u{30DB}', Some('\u{309A}')), ('\u{30F4}', '\u{30A6}', Some('\u{3099}')), ('\u{30F7}', '\u{30EF}', Some('\u{3099}')), ('\u{30F8}', '\u{30F0}', Some('\u{3099}')),
+    ('\u{30F9}', '\u{30F1}', Some('\u{3099}')), ('\u{30FA}', '\u{30F2}', Some('\u{3099}')), ('\u{30FE}', '\u{30FD}', Some('\u{3099}')), ('\u{F900}', '\u{8C48}', None),
+    ('\u{F901}', '\u{66F4}', None), ('\u{F902}', '\u{8ECA}', None), ('\u{F903}', '\u{8CC8}', None), ('\u{F904}', '\u{6ED1}', None),
+    ('\u{F905}', '\u{4E32}', None), ('\u{F906}', '\u{53E5}', None), ('\u{F907}', '\u{9F9C}', None), ('\u{F908}', '\u{9F9C}', None),
+    ('\u{F909}', '\u{5951}', None), ('\u{F90A}', '\u{91D1}', None), ('\u{F90B}', '\u{5587}', None), ('\u{F90C}', '\u{5948}', None),
+    ('\u{F90D}', '\u{61F6}', None), ('\u{F90E}', '\u{7669}', None), ('\u{F90F}', '\u{7F85}', None), ('\u{F910}', '\u{863F}', None),
+    ('\u{F911}', '\u{87BA}', None), ('\u{F912}', '\u{88F8}', None), ('\u{F913}', '\u{908F}', None), ('\u{F914}', '\u{6A02}', None),
+    ('\u{F915}', '\u{6D1B}', None), ('\u{F916}', '\u{70D9}', None), ('\u{F917}', '\u{73DE}', None), ('\u{F918}', '\u{843D}', None),
+    ('\u{F919}', '\u{916A}', None), ('\u{F91A}', '\u{99F1}', None), ('\u{F91B}', '\u{4E82}', None), ('\u{F91C}', '\u{5375}', None),
+    ('\u{F91D}', '\u{6B04}', None), ('\u{F91E}', '\u{721B}', None), ('\u{F91F}', '\u{862D}', None), ('\u{F920}', '\u{9E1E}', None),
+    ('\u{F921}', '\u{5D50}', None), ('\u{F922}', '\u{6FEB}', None), ('\u{F923}', '\u{85CD}', None), ('\u{F924}', '\u{8964}', None),
+    ('\u{F925}', '\u{62C9}', None), ('\u{F926}', '\u{81D8}', None), ('\u{F927}', '\u{881F}', None), ('\u{F928}', '\u{5ECA}', None),
+    ('\u{F929}', '\u{6717}', None), ('\u{F92A}', '\u{6D6A}', None), ('\u{F92B}', '\u{72FC}', None), ('\u{F92C}', '\u{90CE}', None),
+    ('\u{F92D}', '\u{4F86}', None), ('\u{F92E}', '\u{51B7}', None), ('\u{F92F}', '\u{52DE}', None), ('\u{F930}', '\u{64C4}', None),
+    ('\u{F931}', '\u{6AD3}', None), ('\u{F932}', '\u{7210}', None), ('\u{F933}', '\u{76E7}', None), ('\u{F934}', '\u{8001}', None),
+    ('\u{F935}', '\u{8606}', None), ('\u{F936}', '\u{865C}', None), ('\u{F937}', '\u{8DEF}', None), ('\u{F938}', '\u{9732}', None),
+    ('\u{F939}', '\u{9B6F}', None), ('\u{F93A}', '\u{9DFA}', None), ('\u{F93B}', '\u{788C}', None), ('\u{F93C}', '\u{797F}', None),
+    ('\u{F93D}', '\u{7DA0}', None), ('\u{F93E}', '\u{83C9}', None), ('\u{F93F}', '\u{9304}', None), ('\u{F940}', '\u{9E7F}', None),
+    ('\u{F941}', '\u{8AD6}', None), ('\u{F942}', '\u{58DF}', None), ('\u{F943}', '\u{5F04}', None), ('\u{F944}', '\u{7C60}', None),
+    ('\u{F945}', '\u{807E}', None), ('\u{F946}', '\u{7262}', None), ('\u{F947}', '\u{78CA}', None), ('\u{F948}', '\u{8CC2}', None),
+    ('\u{F949}', '\u{96F7}', None), ('\u{F94A}', '\u{58D8}', None), ('\u{F94B}', '\u{5C62}', None), ('\u{F94C}', '\u{6A13}', None),
+    ('\u{F94D}', '\u{6DDA}', None), ('\u{F94E}', '\u{6F0F}', None), ('\u{F94F}', '\u{7D2F}', None), ('\u{F950}', '\u{7E37}', None),
+    ('\u{F951}', '\u{964B}', None), ('\u{F952}', '\u{52D2}', None), ('\u{F953}', '\u{808B}', None), ('\u{F954}', '\u{51DC}', None),
+    ('\u{F955}', '\u{51CC}', None), ('\u{F956}', '\u{7A1C}', None), ('\u{F957}', '\u{7DBE}', None), ('\u{F958}', '\u{83F1}', None),
+    ('\u{F959}', '\u{9675}', None), ('\u{F95A}', '\u{8B80}', None), ('\u{F95B}', '\u{62CF}', None), ('\u{F95C}', '\u{6A02}', None),
+    ('\u{F95D}', '\u{8AFE}', None), ('\u{F95E}', '\u{4E39}', None), ('\u{F95F}', '\u{5BE7}', None), ('\u{F960}', '\u{6012}', None),
+    ('\u{F961}', '\u{7387}', None), ('\u{F962}', '\u{7570}', None), ('\u{F963}', '\u{5317}', None), ('\u{F964}', '\u{78FB}', None),
+    ('\u{F965}', '\u{4FBF}', None), ('\u{F966}', '\u{5FA9}', None), ('\u{F967}', '\u{4E0D}', None), ('\u{F968}', '\u{6CCC}', None),
+    ('\u{F969}', '\u{6578}', None), ('\u{F96A}', '\u{7D22}', None), ('\u{F96B}', '\u{53C3}', None), ('\u{F96C}', '\u{585E}', None),
+    ('\u{F96D}', '\u{7701}', None), ('\u{F96E}', '\u{8449}', None), ('\u{F96F}', '\u{8AAA}', None), ('\u{F970}', '\u{6BBA}', None),
+    ('\u{F971}', '\u{8FB0}', None), ('\u{F972}', '\u{6C88}', None), ('\u{F973}', '\u{62FE}', None), ('\u{F974}', '\u{82E5}', None),
+    ('\u{F975}', '\u{63A0}', None), ('\u{F976}', '\u{7565}', None), ('\u{F977}', '\u{4EAE}', None), ('\u{F978}', '\u{5169}', None),
+    ('\u{F979}', '\u{51C9}', None), ('\u{F97A}', '\u{6881}', None), ('\u{F97B}', '\u{7CE7}', None), ('\u{F97C}', '\u{826F}', None),
+    ('\u{F97D}', '\u{8AD2}', None), ('\u{F97E}', '\u{91CF}', None), ('\u{F97F}', '\u{52F5}', None), ('\u{F980}', '\u{5442}', None),
+    ('\u{F981}', '\u{5973}', None), ('\u{F982}', '\u{5EEC}', None), ('\u{F983}', '\u{65C5}', None), ('\u{F984}', '\u{6FFE}', None),
+    ('\u{F985}', '\u{792A}', None), ('\u{F986}', '\u{95AD}', None), ('\u{F987}', '\u{9A6A}', None), ('\u{F988}', '\u{9E97}', None),
+    ('\u{F989}', '\u{9ECE}', None), ('\u{F98A}', '\u{529B}', None), ('\u{F98B}', '\u{66C6}', None), ('\u{F98C}', '\u{6B77}', None),
+    ('\u{F98D}', '\u{8F62}', None), ('\u{F98E}', '\u{5E74}', None), ('\u{F98F}', '\u{6190}', None), ('\u{F990}', '\u{6200}', None),
+    ('\u{F991}', '\u{649A}', None), ('\u{F992}', '\u{6F23}', None), ('\u{F993}', '\u{7149}', None), ('\u{F994}', '\u{7489}', None),
+    ('\u{F995}', '\u{79CA}', None), ('\u{F996}', '\u{7DF4}', None), ('\u{F997}', '\u{806F}', None), ('\u{F998}', '\u{8F26}', None),
+    ('\u{F999}', '\u{84EE}', None), ('\u{F99A}', '\u{9023}', None), ('\u{F99B}', '\u{934A}', None), ('\u{F99C}', '\u{5217}', None),
+    ('\u{F99D}', '\u{52A3}', None), ('\u{F99E}', '\u{54BD}', None), ('\u{F99F}', '\u{70C8}', None), ('\u{F9A0}', '\u{88C2}', None),
+    ('\u{F9A1}', '\u{8AAA}', None), ('\u{F9A2}', '\u{5EC9}', None), ('\u{F9A3}', '\u{5FF5}', None), ('\u{F9A4}', '\u{637B}', None),
+    ('\u{F9A5}', '\u{6BAE}', None), ('\u{F9A6}', '\u{7C3E}', None), ('\u{F9A7}', '\u{7375}', None), ('\u{F9A8}', '\u{4EE4}', None),
+    ('\u{F9A9}', '\u{56F9}', None), ('\u{F9AA}', '\u{5BE7}', None), ('\u{F9AB}', '\u{5DBA}', None), ('\u{F9AC}', '\u{601C}', None),
+    ('\u{F9AD}', '\u{73B2}', None), ('\u{F9AE}', '\u{7469}', None), ('\u{F9AF}', '\u{7F9A}', None), ('\u{F9B0}', '\u{8046}', None),
+    ('\u{F9B1}', '\u{9234}', None), ('\u{F9B2}', '\u{96F6}', None), ('\u{F9B3}', '\u{9748}', None), ('\u{F9B4}', '\u{9818}', None),
+    ('\u{F9B5}', '\u{4F8B}', None), ('\u{F9B6}', '\u{79AE}', None), ('\u{F9B7}', '\u{91B4}', None), ('\u{F9B8}', '\u{96B8}', None),
+    ('\u{F9B9}', '\u{60E1}', None), ('\u{F9BA}', '\u{4E86}', None), ('\u{F9BB}', '\u{50DA}', None), ('\u{F9BC}', '\u{5BEE}', None),
+    ('\u{F9BD}', '\u{5C3F}', None), ('\u{F9BE}', '\u{6599}', None), ('\u{F9BF}', '\u{6A02}', None), ('\u{F9C0}', '\u{71CE}', None),
+    ('\u{F9C1}', '\u{7642}', None), ('\u{F9C2}', '\u{84FC}', None), ('\u{F9C3}', '\u{907C}', None), ('\u{F9C4}', '\u{9F8D}', None),
+    ('\u{F9C5}', '\u{6688}', None), ('\u{F9C6}', '\u{962E}', None), ('\u{F9C7}', '\u{5289}', None), ('\u{F9C8}', '\u{677B}', None),
+    ('\u{F9C9}', '\u{67F3}', None), ('\u{F9CA}', '\u{6D41}', None), ('\u{F9CB}', '\u{6E9C}', None), ('\u{F9CC}', '\u{7409}', None),
+    ('\u{F9CD}', '\u{7559}', None), ('\u{F9CE}', '\u{786B}', None), ('\u{F9CF}', '\u{7D10}', None), ('\u{F9D0}', '\u{985E}', None),
+    ('\u{F9D1}', '\u{516D}', None), ('\u{F9D2}', '\u{622E}', None), ('\u{F9D3}', '\u{9678}', None), ('\u{F9D4}', '\u{502B}', None),
+    ('\u{F9D5}', '\u{5D19}', None), ('\u{F9D6}', '\u{6DEA}', None), ('\u{F9D7}', '\u{8F2A}', None), ('\u{F9D8}', '\u{5F8B}', None),
+    ('\u{F9D9}', '\u{6144}', None), ('\u{F9DA}', '\u{6817}', None), ('\u{F9DB}', '\u{7387}', None), ('\u{F9DC}', '\u{9686}', None),
+    ('\u{F9DD}', '\u{5229}', None), ('\u{F9DE}', '\u{540F}', None), ('\u{F9DF}', '\u{5C65}', None), ('\u{F9E0}', '\u{6613}', None),
+    ('\u{F9E1}', '\u{674E}', None), ('\u{F9E2}', '\u{68A8}', None), ('\u{F9E3}', '\u{6CE5}', None), ('\u{F9E4}', '\u{7406}', None),
+    ('\u{F9E5}', '\u{75E2}', None), ('\u{F9E6}', '\u{7F79}', None), ('\u{F9E7}', '\u{88CF}', None), ('\u{F9E8}', '\u{88E1}', None),
+    ('\u{F9E9}', '\u{91CC}', None), ('\u{F9EA}', '\u{96E2}', None), ('\u{F9EB}', '\u{533F}', None), ('\u{F9EC}', '\u{6EBA}', None),
+    ('\u{F9ED}', '\u{541D}', None), ('\u{F9EE}', '\u{71D0}', None), ('\u{F9EF}', '\u{7498}', None), ('\u{F9F0}', '\u{85FA}', None),
+    ('\u{F9F1}', '\u{96A3}', None), ('\u{F9F2}', '\u{9C57}', None), ('\u{F9F3}', '\u{9E9F}', None), ('\u{F9F4}', '\u{6797}', None),
+    ('\u{F9F5}', '\u{6DCB}', None), ('\u{F9F6}', '\u{81E8}', None), ('\u{F9F7}', '\u{7ACB}', None), ('\u{F9F8}', '\u{7B20}', None),
+    ('\u{F9F9}', '\u{7C92}', None), ('\u{F9FA}', '\u{72C0}', None), ('\u{F9FB}', '\u{7099}', None), ('\u{F9FC}', '\u{8B58}', None),
+    ('\u{F9FD}', '\u{4EC0}', None), ('\u{F9FE}', '\u{8336}', None), ('\u{F9FF}', '\u{523A}', None), ('\u{FA00}', '\u{5207}', None),
+    ('\u{FA01}', '\u{5EA6}', None), ('\u{FA02}', '\u{62D3}', None), ('\u{FA03}', '\u{7CD6}', None), ('\u{FA04}', '\u{5B85}', None),
+    ('\u{FA05}', '\u{6D1E}', None), ('\u{FA06}', '\u{66B4}', None), ('\u{FA07}', '\u{8F3B}', None), ('\u{FA08}', '\u{884C}', None),
+    ('\u{FA09}', '\u{964D}', None), ('\u{FA0A}', '\u{898B}', None), ('\u{FA0B}', '\u{5ED3}', None), ('\u{FA0C}', '\u{5140}', None),
+    ('\u{FA0D}', '\u{55C0}', None), ('\u{FA10}', '\u{585A}', None), ('\u{FA12}', '\u{6674}', None), ('\u{FA15}', '\u{51DE}', None),
+    ('\u{FA16}', '\u{732A}', None), ('\u{FA17}', '\u{76CA}', None), ('\u{FA18}', '\u{793C}', None), ('\u{FA19}', '\u{795E}', None),
+    ('\u{FA1A}', '\u{7965}', None), ('\u{FA1B}', '\u{798F}', None), ('\u{FA1C}', '\u{9756}', None), ('\u{FA1D}', '\u{7CBE}', None),
+    ('\u{FA1E}', '\u{7FBD}', None), ('\u{FA20}', '\u{8612}', None), ('\u{FA22}', '\u{8AF8}', None), ('\u{FA25}', '\u{9038}', None),
+    ('\u{FA26}', '\u{90FD}', None), ('\u{FA2A}', '\u{98EF}', None), ('\u{FA2B}', '\u{98FC}', None), ('\u{FA2C}', '\u{9928}', None),
+    ('\u{FA2D}', '\u{9DB4}', None), ('\u{FA2E}', '\u{90DE}', None), ('\u{FA2F}', '\u{96B7}', None), ('\u{FA30}', '\u{4FAE}', None),
+    ('\u{FA31}', '\u{50E7}', None), ('\u{FA32}', '\u{514D}', None), ('\u{FA33}', '\u{52C9}', None), ('\u{FA34}', '\u{52E4}', None),
+    ('\u{FA35}', '\u{5351}', None), ('\u{FA36}', '\u{559D}', None), ('\u{FA37}', '\u{5606}', None), ('\u{FA38}', '\u{5668}', None),
+    ('\u{FA39}', '\u{5840}', None), ('\u{FA3A}', '\u{58A8}', None), ('\u{FA3B}', '\u{5C64}', None), ('\u{FA3C}', '\u{5C6E}', None),
+    ('\u{FA3D}', '\u{6094}', None), ('\u{FA3E}', '\u{6168}', None), ('\u{FA3F}', '\u{618E}', None), ('\u{FA40}', '\u{61F2}', None),
+    ('\u{FA41}', '\u{654F}', None), ('\u{FA42}', '\u{65E2}', None), ('\u{FA43}', '\u{6691}', None), ('\u{FA44}', '\u{6885}', None),
+    ('\u{FA45}', '\u{6D77}', None), ('\u{FA46}', '\u{6E1A}', None), ('\u{FA47}', '\u{6F22}', None), ('\u{FA48}', '\u{716E}', None),
+    ('\u{FA49}', '\u{722B}', None), ('\u{FA4A}', '\u{7422}', None), ('\u{FA4B}', '\u{7891}', None), ('\u{FA4C}', '\u{793E}', None),
+    ('\u{FA4D}', '\u{7949}', None), ('\u{FA4E}', '\u{7948}', None), ('\u{FA4F}', '\u{7950}', None), ('\u{FA50}', '\u{7956}', None),
+    ('\u{FA51}', '\u{795D}', None), ('\u{FA52}', '\u{798D}', None), ('\u{FA53}', '\u{798E}', None), ('\u{FA54}', '\u{7A40}', None),
+    ('\u{FA55}', '\u{7A81}', None), ('\u{FA56}', '\u{7BC0}', None), ('\u{FA57}', '\u{7DF4}', None), ('\u{FA58}', '\u{7E09}', None),
+    ('\u{FA59}', '\u{7E41}', None), ('\u{FA5A}', '\u{7F72}', None), ('\u{FA5B}', '\u{8005}', None), ('\u{FA5C}', '\u{81ED}', None),
+    ('\u{FA5D}', '\u{8279}', None), ('\u{FA5E}', '\u{8279}', None), ('\u{FA5F}', '\u{8457}', None), ('\u{FA60}', '\u{8910}', None),
+    ('\u{FA61}', '\u{8996}', None), ('\u{FA62}', '\u{8B01}', None), ('\u{FA63}', '\u{8B39}', None), ('\u{FA64}', '\u{8CD3}', None),
+    ('\u{FA65}', '\u{8D08}', None), ('\u{FA66}', '\u{8FB6}', None), ('\u{FA67}', '\u{9038}', None), ('\u{FA68}', '\u{96E3}', None),
+    ('\u{FA69}', '\u{97FF}', None), ('\u{FA6A}', '\u{983B}', None), ('\u{FA6B}', '\u{6075}', None), ('\u{FA6C}', '\u{242EE}', None),
+    ('\u{FA6D}', '\u{8218}', None), ('\u{FA70}', '\u{4E26}', None), ('\u{FA71}', '\u{51B5}', None), ('\u{FA72}', '\u{5168}', None),
+    ('\u{FA73}', '\u{4F80}', None), ('\u{FA74}', '\u{5145}', None), ('\u{FA75}', '\u{5180}', None), ('\u{FA76}', '\u{52C7}', None),
+    ('\u{FA77}', '\u{52FA}', None), ('\u{FA78}', '\u{559D}', None), ('\u{FA79}', '\u{5555}', None), ('\u{FA7A}', '\u{5599}', None),
+    ('\u{FA7B}', '\u{55E2}', None), ('\u{FA7C}', '\u{585A}', None), ('\u{FA7D}', '\u{58B3}', None), ('\u{FA7E}', '\u{5944}', None),
+    ('\u{FA7F}', '\u{5954}', None), ('\u{FA80}', '\u{5A62}', None), ('\u{FA81}', '\u{5B28}', None), ('\u{FA82}', '\u{5ED2}', None),
+    ('\u{FA83}', '\u{5ED9}', None), ('\u{FA84}', '\u{5F69}', None), ('\u{FA85}', '\u{5FAD}', None), ('\u{FA86}', '\u{60D8}', None),
+    ('\u{FA87}', '\u{614E}', None), ('\u{FA88}', '\u{6108}', None), ('\u{FA89}', '\u{618E}', None), ('\u{FA8A}', '\u{6160}', None),
+    ('\u{FA8B}', '\u{61F2}', None), ('\u{FA8C}', '\u{6234}', None), ('\u{FA8D}', '\u{63C4}', None), ('\u{FA8E}', '\u{641C}', None),
+    ('\u{FA8F}', '\u{6452}', None), ('\u{FA90}', '\u{6556}', None), ('\u{FA91}', '\u{6674}', None), ('\u{FA92}', '\u{6717}', None),
+    ('\u{FA93}', '\u{671B}', None), ('\u{FA94}', '\u{6756}', None), ('\u{FA95}', '\u{6B79}', None), ('\u{FA96}', '\u{6BBA}', None),
+    ('\u{FA97}', '\u{6D41}', None), ('\u{FA98}', '\u{6EDB}', None), ('\u{FA99}', '\u{6ECB}', None), ('\u{FA9A}', '\u{6F22}', None),
+    ('\u{FA9B}', '\u{701E}', None), ('\u{FA9C}', '\u{716E}', None), ('\u{FA9D}', '\u{77A7}', None), ('\u{FA9E}', '\u{7235}', None),
+    ('\u{FA9F}', '\u{72AF}', None), ('\u{FAA0}', '\u{732A}', None), ('\u{FAA1}', '\u{7471}', None), ('\u{FAA2}', '\u{7506}', None),
+    ('\u{FAA3}', '\u{753B}', None), ('\u{FAA4}', '\u{761D}', None), ('\u{FAA5}', '\u{761F}', None), ('\u{FAA6}', '\u{76CA}', None),
+    ('\u{FAA7}', '\u{76DB}', None), ('\u{FAA8}', '\u{76F4}', None), ('\u{FAA9}', '\u{774A}', None), ('\u{FAAA}', '\u{7740}', None),
+    ('\u{FAAB}', '\u{78CC}', None), ('\u{FAAC}', '\u{7AB1}', None), ('\u{FAAD}', '\u{7BC0}', None), ('\u{FAAE}', '\u{7C7B}', None),
+    ('\u{FAAF}', '\u{7D5B}', None), ('\u{FAB0}', '\u{7DF4}', None), ('\u{FAB1}', '\u{7F3E}', None), ('\u{FAB2}', '\u{8005}', None),
+    ('\u{FAB3}', '\u{8352}', None), ('\u{FAB4}', '\u{83EF}', None), ('\u{FAB5}', '\u{8779}', None), ('\u{FAB6}', '\u{8941}', None),
+    ('\u{FAB7}', '\u{8986}', None), ('\u{FAB8}', '\u{8996}', None), ('\u{FAB9}', '\u{8ABF}', None), ('\u{FABA}', '\u{8AF8}', None),
+    ('\u{FABB}', '\u{8ACB}', None), ('\u{FABC}', '\u{8B01}', None), ('\u{FABD}', '\u{8AFE}', None), ('\u{FABE}', '\u{8AED}', None),
+    ('\u{FABF}', '\u{8B39}', None), ('\u{FAC0}', '\u{8B8A}', None), ('\u{FAC1}', '\u{8D08}', None), ('\u{FAC2}', '\u{8F38}', None),
+    ('\u{FAC3}', '\u{9072}', None), ('\u{FAC4}', '\u{9199}', None), ('\u{FAC5}', '\u{9276}', None), ('\u{FAC6}', '\u{967C}', None),
+    ('\u{FAC7}', '\u{96E3}', None), ('\u{FAC8}', '\u{9756}', None), ('\u{FAC9}', '\u{97DB}', None), ('\u{FACA}', '\u{97FF}', None),
+    ('\u{FACB}', '\u{980B}', None), ('\u{FACC}', '\u{983B}', None), ('\u{FACD}', '\u{9B12}', None), ('\u{FACE}', '\u{9F9C}', None),
+    ('\u{FACF}', '\u{2284A}', None), ('\u{FAD0}', '\u{22844}', None), ('\u{FAD1}', '\u{233D5}', None), ('\u{FAD2}', '\u{3B9D}', None),
+    ('\u{FAD3}', '\u{4018}', None), ('\u{FAD4}', '\u{4039}', None), ('\u{FAD5}', '\u{25249}', None), ('\u{FAD6}', '\u{25CD0}', None),
+    ('\u{FAD7}', '\u{27ED3}', None), ('\u{FAD8}', '\u{9F43}', None), ('\u{FAD9}', '\u{9F8E}', None), ('\u{FB1D}', '\u{5D9}', Some('\u{5B4}')),
+    ('\u{FB1F}', '\u{5F2}', Some('\u{5B7}')), ('\u{FB2A}', '\u{5E9}', Some('\u{5C1}')), ('\u{FB2B}', '\u{5E9}', Some('\u{5C2}')), ('\u{FB2C}', '\u{FB49}', Some('\u{5C1}')),
+    ('\u{FB2D}', '\u{FB49}', Some('\u{5C2}')), ('\u{FB2E}', '\u{5D0}', Some('\u{5B7}')), ('\u{FB2F}', '\u{5D0}', Some('\u{5B8}')), ('\u{FB30}', '\u{5D0}', Some('\u{5BC}')),
+    ('\u{FB31}', '\u{5D1}', Some('\u{5BC}')), ('\u{FB32}', '\u{5D2}', Some('\u{5BC}')), ('\u{FB33}', '\u{5D3}', Some('\u{5BC}')), ('\u{FB34}', '\u{5D4}', Some('\u{5BC}')),
+    ('\u{FB35}', '\u{5D5}', Some('\u{5BC}')), ('\u{FB36}', '\u{5D6}', Some('\u{5BC}')), ('\u{FB38}', '\u{5D8}', Some('\u{5BC}')), ('\u{FB39}', '\u{5D9}', Some('\u{5BC}')),
+    ('\u{FB3A}', '\u{5DA}', Some('\u{5BC}')), ('\u{FB3B}', '\u{5DB}', Some('\u{5BC}')), ('\u{FB3C}', '\u{5DC}', Some('\u{5BC}')), ('\u{FB3E}', '\u{5DE}', Some('\u{5BC}')),
+    ('\u{FB40}', '\u{5E0}', Some('\u{5BC}')), ('\u{FB41}', '\u{5E1}', Some('\u{5BC}')), ('\u{FB43}', '\u{5E3}', Some('\u{5BC}')), ('\u{FB44}', '\u{5E4}', Some('\u{5BC}')),
+    ('\u{FB46}', '\u{5E6}', Some('\u{5BC}')), ('\u{FB47}', '\u{5E7}', Some('\u{5BC}')), ('\u{FB48}', '\u{5E8}', Some('\u{5BC}')), ('\u{FB49}', '\u{5E9}', Some('\u{5BC}')),
+    ('\u{FB4A}', '\u{5EA}', Some('\u{5BC}')), ('\u{FB4B}', '\u{5D5}', Some('\u{5B9}')), ('\u{FB4C}', '\u{5D1}', Some('\u{5BF}')), ('\u{FB4D}', '\u{5DB}', Some('\u{5BF}')),
+    ('\u{FB4E}', '\u{5E4}', Some('\u{5BF}')), ('\u{1109A}', '\u{11099}', Some('\u{110BA}')), ('\u{1109C}', '\u{1109B}', Some('\u{110BA}')), ('\u{110AB}', '\u{110A5}', Some('\u{110BA}')),
+    ('\u{1112E}', '\u{11131}', Some('\u{11127}')), ('\u{1112F}', '\u{11132}', Some('\u{11127}')), ('\u{1134B}', '\u{11347}', Some('\u{1133E}')), ('\u{1134C}', '\u{11347}', Some('\u{11357}')),
+    ('\u{114BB}', '\u{114B9}', Some('\u{114BA}')), ('\u{114BC}', '\u{114B9}', Some('\u{114B0}')), ('\u{114BE}', '\u{114B9}', Some('\u{114BD}')), ('\u{115BA}', '\u{115B8}', Some('\u{115AF}')),
+    ('\u{115BB}', '\u{115B9}', Some('\u{115AF}')), ('\u{11938}', '\u{11935}', Some('\u{11930}')), ('\u{1D15E}', '\u{1D157}', Some('\u{1D165}')), ('\u{1D15F}', '\u{1D158}', Some('\u{1D165}')),
+    ('\u{1D160}', '\u{1D15F}', Some('\u{1D16E}')), ('\u{1D161}', '\u{1D15F}', Some('\u{1D16F}')), ('\u{1D162}', '\u{1D15F}', Some('\u{1D170}')), ('\u{1D163}', '\u{1D15F}', Some('\u{1D171}')),
+    ('\u{1D164}', '\u{1D15F}', Some('\u{1D172}')), ('\u{1D1BB}', '\u{1D1B9}', Some('\u{1D165}')), ('\u{1D1BC}', '\u{1D1BA}', Some('\u{1D165}')), ('\u{1D1BD}', '\u{1D1BB}', Some('\u{1D16E}')),
+    ('\u{1D1BE}', '\u{1D1BC}', Some('\u{1D16E}')), ('\u{1D1BF}', '\u{1D1BB}', Some('\u{1D16F}')), ('\u{1D1C0}', '\u{1D1BC}', Some('\u{1D16F}')), ('\u{2F800}', '\u{4E3D}', None),
+    ('\u{2F801}', '\u{4E38}', None), ('\u{2F802}', '\u{4E41}', None), ('\u{2F803}', '\u{20122}', None), ('\u{2F804}', '\u{4F60}', None),
+    ('\u{2F805}', '\u{4FAE}', None), ('\u{2F806}', '\u{4FBB}', None), ('\u{2F807}', '\u{5002}', None), ('\u{2F808}', '\u{507A}', None),
+    ('\u{2F809}', '\u{5099}', None), ('\u{2F80A}', '\u{50E7}', None), ('\u{2F80B}', '\u{50CF}', None), ('\u{2F80C}', '\u{349E}', None),
+    ('\u{2F80D}', '\u{2063A}', None), ('\u{2F80E}', '\u{514D}', None), ('\u{2F80F}', '\u{5154}', None), ('\u{2F810}', '\u{5164}', None),
+    ('\u{2F811}', '\u{5177}', None), ('\u{2F812}', '\u{2051C}', None), ('\u{2F813}', '\u{34B9}', None), ('\u{2F814}', '\u{5167}', None),
+    ('\u{2F815}', '\u{518D}', None), ('\u{2F816}', '\u{2054B}', None), ('\u{2F817}', '\u{5197}', None), ('\u{2F818}', '\u{51A4}', None),
+    ('\u{2F819}', '\u{4ECC}', None), ('\u{2F81A}', '\u{51AC}', None), ('\u{2F81B}', '\u{51B5}', None), ('\u{2F81C}', '\u{291DF}', None),
+    ('\u{2F81D}', '\u{51F5}', None), ('\u{2F81E}', '\u{5203}', None), ('\u{2F81F}', '\u{34DF}', None), ('\u{2F820}', '\u{523B}', None),
+    ('\u{2F821}', '\u{5246}', None), ('\u{2F822}', '\u{5272}', None), ('\u{2F823}', '\u{5277}', None), ('\u{2F824}', '\u{3515}', None),
+    ('\u{2F825}', '\u{52C7}', None), ('\u{2F826}', '\u{52C9}', None), ('\u{2F827}', '\u{52E4}', None), ('\u{2F828}', '\u{52FA}', None),
+    ('\u{2F829}', '\u{5305}', None), ('\u{2F82A}', '\u{5306}', None), ('\u{2F82B}', '\u{5317}', None), ('\u{2F82C}', '\u{5349}', None),
+    ('\u{2F82D}', '\u{5351}', None), ('\u{2F82E}', '\u{535A}', None), ('\u{2F82F}', '\u{5373}', None), ('\u{2F830}', '\u{537D}', None),
+    ('\u{2F831}', '\u{537F}', None), ('\u{2F832}', '\u{537F}', None), ('\u{2F833}', '\u{537F}', None), ('\u{2F834}', '\u{20A2C}', None),
+    ('\u{2F835}', '\u{7070}', None), ('\u{2F836}', '\u{53CA}', None), ('\u{2F837}', '\u{53DF}', None), ('\u{2F838}', '\u{20B63}', None),
+    ('\u{2F839}', '\u{53EB}', None), ('\u{2F83A}', '\u{53F1}', None), ('\u{2F83B}', '\u{5406}', None), ('\u{2F83C}', '\u{549E}', None),
+    ('\u{2F83D}', '\u{5438}', None), ('\u{2F83E}', '\u{5448}', None), ('\u{2F83F}', '\u{5468}', None), ('\u{2F840}', '\u{54A2}', None),
+    ('\u{2F841}', '\u{54F6}', None), ('\u{2F842}', '\u{5510}', None), ('\u{2F843}', '\u{5553}', None), ('\u{2F844}', '\u{5563}', None),
+    ('\u{2F845}', '\u{5584}', None), ('\u{2F846}', '\u{5584}', None), ('\u{2F847}', '\u{5599}', None), ('\u{2F848}', '\u{55AB}', None),
+    ('\u{2F849}', '\u{55B3}', None), ('\u{2F84A}', '\u{55C2}', None), ('\u{2F84B}', '\u{5716}', None), ('\u{2F84C}', '\u{5606}', None),
+    ('\u{2F84D}', '\u{5717}', None), ('\u{2F84E}', '\u{5651}', None), ('\u{2F84F}', '\u{5674}', None), ('\u{2F850}', '\u{5207}', None),
+    ('\u{2F851}', '\u{58EE}', None), ('\u{2F852}', '\u{57CE}', None), ('\u{2F853}', '\u{57F4}', None), ('\u{2F854}', '\u{580D}', None),
+    ('\u{2F855}', '\u{578B}', None), ('\u{2F856}', '\u{5832}', None), ('\u{2F857}', '\u{5831}', None), ('\u{2F858}', '\u{58AC}', None),
+    ('\u{2F859}', '\u{214E4}', None), ('\u{2F85A}', '\u{58F2}', None), ('\u{2F85B}', '\u{58F7}', None), ('\u{2F85C}', '\u{5906}', None),
+    ('\u{2F85D}', '\u{591A}', None), ('\u{2F85E}', '\u{5922}', None), ('\u{2F85F}', '\u{5962}', None), ('\u{2F860}', '\u{216A8}', None),
+    ('\u{2F861}', '\u{216EA}', None), ('\u{2F862}', '\u{59EC}', None), ('\u{2F863}', '\u{5A1B}', None), ('\u{2F864}', '\u{5A27}', None),
+    ('\u{2F865}', '\u{59D8}', None), ('\u{2F866}', '\u{5A66}', None), ('\u{2F867}', '\u{36EE}', None), ('\u{2F868}', '\u{36FC}', None),
+    ('\u{2F869}', '\u{5B08}', None), ('\u{2F86A}', '\u{5B3E}', None), ('\u{2F86B}', '\u{5B3E}', None), ('\u{2F86C}', '\u{219C8}', None),
+    ('\u{2F86D}', '\u{5BC3}', None), ('\u{2F86E}', '\u{5BD8}', None), ('\u{2F86F}', '\u{5BE7}', None), ('\u{2F870}', '\u{5BF3}', None),
+    ('\u{2F871}', '\u{21B18}', None), ('\u{2F872}', '\u{5BFF}', None), ('\u{2F873}', '\u{5C06}', None), ('\u{2F874}', '\u{5F53}', None),
+    ('\u{2F875}', '\u{5C22}', None), ('\u{2F876}', '\u{3781}', None), ('\u{2F877}', '\u{5C60}', None), ('\u{2F878}', '\u{5C6E}', None),
+    ('\u{2F879}', '\u{5CC0}', None), ('\u{2F87A}', '\u{5C8D}', None), ('\u{2F87B}', '\u{21DE4}', None), ('\u{2F87C}', '\u{5D43}', None),
+    ('\u{2F87D}', '\u{21DE6}', None), ('\u{2F87E}', '\u{5D6E}', None), ('\u{2F87F}', '\u{5D6B}', None), ('\u{2F880}', '\u{5D7C}', None),
+    ('\u{2F881}', '\u{5DE1}', None), ('\u{2F882}', '\u{5DE2}', None), ('\u{2F883}', '\u{382F}', None), ('\u{2F884}', '\u{5DFD}', None),
+    ('\u{2F885}', '\u{5E28}', None), ('\u{2F886}', '\u{5E3D}', None), ('\u{2F887}', '\u{5E69}', None), ('\u{2F888}', '\u{3862}', None),
+    ('\u{2F889}', '\u{22183}', None), ('\u{2F88A}', '\u{387C}', None), ('\u{2F88B}', '\u{5EB0}', None), ('\u{2F88C}', '\u{5EB3}', None),
+    ('\u{2F88D}', '\u{5EB6}', None), ('\u{2F88E}', '\u{5ECA}', None), ('\u{2F88F}', '\u{2A392}', None), ('\u{2F890}', '\u{5EFE}', None),
+    ('\u{2F891}', '\u{22331}', None), ('\u{2F892}', '\u{22331}', None), ('\u{2F893}', '\u{8201}', None), ('\u{2F894}', '\u{5F22}', None),
+    ('\u{2F895}', '\u{5F22}', None), ('\u{2F896}', '\u{38C7}', None), ('\u{2F897}', '\u{232B8}', None), ('\u{2F898}', '\u{261DA}', None),
+    ('\u{2F899}', '\u{5F62}', None), ('\u{2F89A}', '\u{5F6B}', None), ('\u{2F89B}', '\u{38E3}', None), ('\u{2F89C}', '\u{5F9A}', None),
+    ('\u{2F89D}', '\u{5FCD}', None), ('\u{2F89E}', '\u{5FD7}', None), ('\u{2F89F}', '\u{5FF9}', None), ('\u{2F8A0}', '\u{6081}', None),
+    ('\u{2F8A1}', '\u{393A}', None), ('\u{2F8A2}', '\u{391C}', None), ('\u{2F8A3}', '\u{6094}', None), ('\u{2F8A4}', '\u{226D4}', None),
+    ('\u{2F8A5}', '\u{60C7}', None), ('\u{2F8A6}', '\u{6148}', None), ('\u{2F8A7}', '\u{614C}', None), ('\u{2F8A8}', '\u{614E}', None),
+    ('\u{2F8A9}', '\u{614C}', None), ('\u{2F8AA}', '\u{617A}', None), ('\u{2F8AB}', '\u{618E}', None), ('\u{2F8AC}', '\u{61B2}', None),
+    ('\u{2F8AD}', '\u{61A4}', None), ('\u{2F8AE}', '\u{61AF}', None), ('\u{2F8AF}', '\u{61DE}', None), ('\u{2F8B0}', '\u{61F2}', None),
+    ('\u{2F8B1}', '\u{61F6}', None), ('\u{2F8B2}', '\u{6210}', None), ('\u{2F8B3}', '\u{621B}', None), ('\u{2F8B4}', '\u{625D}', None),
+    ('\u{2F8B5}', '\u{62B1}', None), ('\u{2F8B6}', '\u{62D4}', None), ('\u{2F8B7}', '\u{6350}', None), ('\u{2F8B8}', '\u{22B0C}', None),
+    ('\u{2F8B9}', '\u{633D}', None), ('\u{2F8BA}', '\u{62FC}', None), ('\u{2F8BB}', '\u{6368}', None), ('\u{2F8BC}', '\u{6383}', None),
+    ('\u{2F8BD}', '\u{63E4}', None), ('\u{2F8BE}', '\u{22BF1}', None), ('\u{2F8BF}', '\u{6422}', None), ('\u{2F8C0}', '\u{63C5}', None),
+    ('\u{2F8C1}', '\u{63A9}', None), ('\u{2F8C2}', '\u{3A2E}', None), ('\u{2F8C3}', '\u{6469}', None), ('\u{2F8C4}', '\u{647E}', None),
+    ('\u{2F8C5}', '\u{649D}', None), ('\u{2F8C6}', '\u{6477}', None), ('\u{2F8C7}', '\u{3A6C}', None), ('\u{2F8C8}', '\u{654F}', None),
+    ('\u{2F8C9}', '\u{656C}', None), ('\u{2F8CA}', '\u{2300A}', None), ('\u{2F8CB}', '\u{65E3}', None), ('\u{2F8CC}', '\u{66F8}', None),
+    ('\u{2F8CD}', '\u{6649}', None), ('\u{2F8CE}', '\u{3B19}', None), ('\u{2F8CF}', '\u{6691}', None), ('\u{2F8D0}', '\u{3B08}', None),
+    ('\u{2F8D1}', '\u{3AE4}', None), ('\u{2F8D2}', '\u{5192}', None), ('\u{2F8D3}', '\u{5195}', None), ('\u{2F8D4}', '\u{6700}', None),
+    ('\u{2F8D5}', '\u{669C}', None), ('\u{2F8D6}', '\u{80AD}', None), ('\u{2F8D7}', '\u{43D9}', None), ('\u{2F8D8}', '\u{6717}', None),
+    ('\u{2F8D9}', '\u{671B}', None), ('\u{2F8DA}', '\u{6721}', None), ('\u{2F8DB}', '\u{675E}', None), ('\u{2F8DC}', '\u{6753}', None),
+    ('\u{2F8DD}', '\u{233C3}', None), ('\u{2F8DE}', '\u{3B49}', None), ('\u{2F8DF}', '\u{67FA}', None), ('\u{2F8E0}', '\u{6785}', None),
+    ('\u{2F8E1}', '\u{6852}', None), ('\u{2F8E2}', '\u{6885}', None), ('\u{2F8E3}', '\u{2346D}', None), ('\u{2F8E4}', '\u{688E}', None),
+    ('\u{2F8E5}', '\u{681F}', None), ('\u{2F8E6}', '\u{6914}', None), ('\u{2F8E7}', '\u{3B9D}', None), ('\u{2F8E8}', '\u{6942}', None),
+    ('\u{2F8E9}', '\u{69A3}', None), ('\u{2F8EA}', '\u{69EA}', None), ('\u{2F8EB}', '\u{6AA8}', None), ('\u{2F8EC}', '\u{236A3}', None),
+    ('\u{2F8ED}', '\u{6ADB}', None), ('\u{2F8EE}', '\u{3C18}', None), ('\u{2F8EF}', '\u{6B21}', None), ('\u{2F8F0}', '\u{238A7}', None),
+    ('\u{2F8F1}', '\u{6B54}', None), ('\u{2F8F2}', '\u{3C4E}', None), ('\u{2F8F3}', '\u{6B72}', None), ('\u{2F8F4}', '\u{6B9F}', None),
+    ('\u{2F8F5}', '\u{6BBA}', None), ('\u{2F8F6}', '\u{6BBB}', None), ('\u{2F8F7}', '\u{23A8D}', None), ('\u{2F8F8}', '\u{21D0B}', None),
+    ('\u{2F8F9}', '\u{23AFA}', None), ('\u{2F8FA}', '\u{6C4E}', None), ('\u{2F8FB}', '\u{23CBC}', None), ('\u{2F8FC}', '\u{6CBF}', None),
+    ('\u{2F8FD}', '\u{6CCD}', None), ('\u{2F8FE}', '\u{6C67}', None), ('\u{2F8FF}', '\u{6D16}', None), ('\u{2F900}', '\u{6D3E}', None),
+    ('\u{2F901}', '\u{6D77}', None), ('\u{2F902}', '\u{6D41}', None), ('\u{2F903}', '\u{6D69}', None), ('\u{2F904}', '\u{6D78}', None),
+    ('\u{2F905}', '\u{6D85}', None), ('\u{2F906}', '\u{23D1E}', None), ('\u{2F907}', '\u{6D34}', None), ('\u{2F908}', '\u{6E2F}', None),
+    ('\u{2F909}', '\u{6E6E}', None), ('\u{2F90A}', '\u{3D33}', None), ('\u{2F90B}', '\u{6ECB}', None), ('\u{2F90C}', '\u{6EC7}', None),
+    ('\u{2F90D}', '\u{23ED1}', None), ('\u{2F90E}', '\u{6DF9}', None), ('\u{2F90F}', '\u{6F6E}', None), ('\u{2F910}', '\u{23F5E}', None),
+    ('\u{2F911}', '\u{23F8E}', None), ('\u{2F912}', '\u{6FC6}', None), ('\u{2F913}', '\u{7039}', None), ('\u{2F914}', '\u{701E}', None),
+    ('\u{2F915}', '\u{701B}', None), ('\u{2F916}', '\u{3D96}', None), ('\u{2F917}', '\u{704A}', None), ('\u{2F918}', '\u{707D}', None),
+    ('\u{2F919}', '\u{7077}', None), ('\u{2F91A}', '\u{70AD}', None), ('\u{2F91B}', '\u{20525}', None), ('\u{2F91C}', '\u{7145}', None),
+    ('\u{2F91D}', '\u{24263}', None), ('\u{2F91E}', '\u{719C}', None), ('\u{2F91F}', '\u{243AB}', None), ('\u{2F920}', '\u{7228}', None),
+    ('\u{2F921}', '\u{7235}', None), ('\u{2F922}', '\u{7250}', None), ('\u{2F923}', '\u{24608}', None), ('\u{2F924}', '\u{7280}', None),
+    ('\u{2F925}', '\u{7295}', None), ('\u{2F926}', '\u{24735}', None), ('\u{2F927}', '\u{24814}', None), ('\u{2F928}', '\u{737A}', None),
+    ('\u{2F929}', '\u{738B}', None), ('\u{2F92A}', '\u{3EAC}', None), ('\u{2F92B}', '\u{73A5}', None), ('\u{2F92C}', '\u{3EB8}', None),
+    ('\u{2F92D}', '\u{3EB8}', None), ('\u{2F92E}', '\u{7447}', None), ('\u{2F92F}', '\u{745C}', None), ('\u{2F930}', '\u{7471}', None),
+    ('\u{2F931}', '\u{7485}', None), ('\u{2F932}', '\u{74CA}', None), ('\u{2F933}', '\u{3F1B}', None), ('\u{2F934}', '\u{7524}', None),
+    ('\u{2F935}', '\u{24C36}', None), ('\u{2F936}', '\u{753E}', None), ('\u{2F937}', '\u{24C92}', None), ('\u{2F938}', '\u{7570}', None),
+    ('\u{2F939}', '\u{2219F}', None), ('\u{2F93A}', '\u{7610}', None), ('\u{2F93B}', '\u{24FA1}', None), ('\u{2F93C}', '\u{24FB8}', None),
+    ('\u{2F93D}', '\u{25044}', None), ('\u{2F93E}', '\u{3FFC}', None), ('\u{2F93F}', '\u{4008}', None), ('\u{2F940}', '\u{76F4}', None),
+    ('\u{2F941}', '\u{250F3}', None), ('\u{2F942}', '\u{250F2}', None), ('\u{2F943}', '\u{25119}', None), ('\u{2F944}', '\u{25133}', None),
+    ('\u{2F945}', '\u{771E}', None), ('\u{2F946}', '\u{771F}', None), ('\u{2F947}', '\u{771F}', None), ('\u{2F948}', '\u{774A}', None),
+    ('\u{2F949}', '\u{4039}', None), ('\u{2F94A}', '\u{778B}', None), ('\u{2F94B}', '\u{4046}', None), ('\u{2F94C}', '\u{4096}', None),
+    ('\u{2F94D}', '\u{2541D}', None), ('\u{2F94E}', '\u{784E}', None), ('\u{2F94F}', '\u{788C}', None), ('\u{2F950}', '\u{78CC}', None),
+    ('\u{2F951}', '\u{40E3}', None), ('\u{2F952}', '\u{25626}', None), ('\u{2F953}', '\u{7956}', None), ('\u{2F954}', '\u{2569A}', None),
+    ('\u{2F955}', '\u{256C5}', None), ('\u{2F956}', '\u{798F}', None), ('\u{2F957}', '\u{79EB}', None), ('\u{2F958}', '\u{412F}', None),
+    ('\u{2F959}', '\u{7A40}', None), ('\u{2F95A}', '\u{7A4A}', None), ('\u{2F95B}', '\u{7A4F}', None), ('\u{2F95C}', '\u{2597C}', None),
+    ('\u{2F95D}', '\u{25AA7}', None), ('\u{2F95E}', '\u{25AA7}', None), ('\u{2F95F}', '\u{7AEE}', None), ('\u{2F960}', '\u{4202}', None),
+    ('\u{2F961}', '\u{25BAB}', None), ('\u{2F962}', '\u{7BC6}', None), ('\u{2F963}', '\u{7BC9}', None), ('\u{2F964}', '\u{4227}', None),
+    ('\u{2F965}', '\u{25C80}', None), ('\u{2F966}', '\u{7CD2}', None), ('\u{2F967}', '\u{42A0}', None), ('\u{2F968}', '\u{7CE8}', None),
+    ('\u{2F969}', '\u{7CE3}', None), ('\u{2F96A}', '\u{7D00}', None), ('\u{2F96B}', '\u{25F86}', None), ('\u{2F96C}', '\u{7D63}', None),
+    ('\u{2F96D}', '\u{4301}', None), ('\u{2F96E}', '\u{7DC7}', None), ('\u{2F96F}', '\u{7E02}', None), ('\u{2F970}', '\u{7E45}', None),
+    ('\u{2F971}', '\u{4334}', None), ('\u{2F972}', '\u{26228}', None), ('\u{2F973}', '\u{26247}', None), ('\u{2F974}', '\u{4359}', None),
+    ('\u{2F975}', '\u{262D9}', None), ('\u{2F976}', '\u{7F7A}', None), ('\u{2F977}', '\u{2633E}', None), ('\u{2F978}', '\u{7F95}', None),
+    ('\u{2F979}', '\u{7FFA}', None), ('\u{2F97A}', '\u{8005}', None), ('\u{2F97B}', '\u{264DA}', None), ('\u{2F97C}', '\u{26523}', None),
+    ('\u{2F97D}', '\u{8060}', None), ('\u{2F97E}', '\u{265A8}', None), ('\u{2F97F}', '\u{8070}', None), ('\u{2F980}', '\u{2335F}', None),
+    ('\u{2F981}', '\u{43D5}', None), ('\u{2F982}', '\u{80B2}', None), ('\u{2F983}', '\u{8103}', None), ('\u{2F984}', '\u{440B}', None),
+    ('\u{2F985}', '\u{813E}', None), ('\u{2F986}', '\u{5AB5}', None), ('\u{2F987}', '\u{267A7}', None), ('\u{2F988}', '\u{267B5}', None),
+    ('\u{2F989}', '\u{23393}', None), ('\u{2F98A}', '\u{2339C}', None), ('\u{2F98B}', '\u{8201}', None), ('\u{2F98C}', '\u{8204}', None),
+    ('\u{2F98D}', '\u{8F9E}', None), ('\u{2F98E}', '\u{446B}', None), ('\u{2F98F}', '\u{8291}', None), ('\u{2F990}', '\u{828B}', None),
+    ('\u{2F991}', '\u{829D}', None), ('\u{2F992}', '\u{52B3}', None), ('\u{2F993}', '\u{82B1}', None), ('\u{2F994}', '\u{82B3}', None),
+    ('\u{2F995}', '\u{82BD}', None), ('\u{2F996}', '\u{82E6}', None), ('\u{2F997}', '\u{26B3C}', None), ('\u{2F998}', '\u{82E5}', None),
+    ('\u{2F999}', '\u{831D}', None), ('\u{2F99A}', '\u{8363}', None), ('\u{2F99B}', '\u{83AD}', None), ('\u{2F99C}', '\u{8323}', None),
+    ('\u{2F99D}', '\u{83BD}', None), ('\u{2F99E}', '\u{83E7}', None), ('\u{2F99F}', '\u{8457}', None), ('\u{2F9A0}', '\u{8353}', None),
+    ('\u{2F9A1}', '\u{83CA}', None), ('\u{2F9A2}', '\u{83CC}', None), ('\u{2F9A3}', '\u{83DC}', None), ('\u{2F9A4}', '\u{26C36}', None),
+    ('\u{2F9A5}', '\u{26D6B}', None), ('\u{2F9A6}', '\u{26CD5}', None), ('\u{2F9A7}', '\u{452B}', None), ('\u{2F9A8}', '\u{84F1}', None),
+    ('\u{2F9A9}', '\u{84F3}', None), ('\u{2F9AA}', '\u{8516}', None), ('\u{2F9AB}', '\u{273CA}', None), ('\u{2F9AC}', '\u{8564}', None),
+    ('\u{2F9AD}', '\u{26F2C}', None), ('\u{2F9AE}', '\u{455D}', None), ('\u{2F9AF}', '\u{4561}', None), ('\u{2F9B0}', '\u{26FB1}', None),
+    ('\u{2F9B1}', '\u{270D2}', None), ('\u{2F9B2}', '\u{456B}', None), ('\u{2F9B3}', '\u{8650}', None), ('\u{2F9B4}', '\u{865C}', None),
+    ('\u{2F9B5}', '\u{8667}', None), ('\u{2F9B6}', '\u{8669}', None), ('\u{2F9B7}', '\u{86A9}', None), ('\u{2F9B8}', '\u{8688}', None),
+    ('\u{2F9B9}', '\u{870E}', None), ('\u{2F9BA}', '\u{86E2}', None), ('\u{2F9BB}', '\u{8779}', None), ('\u{2F9BC}', '\u{8728}', None),
+    ('\u{2F9BD}', '\u{876B}', None), ('\u{2F9BE}', '\u{8786}', None), ('\u{2F9BF}', '\u{45D7}', None), ('\u{2F9C0}', '\u{87E1}', None),
+    ('\u{2F9C1}', '\u{8801}', None), ('\u{2F9C2}', '\u{45F9}', None), ('\u{2F9C3}', '\u{8860}', None), ('\u{2F9C4}', '\u{8863}', None),
+    ('\u{2F9C5}', '\u{27667}', None), ('\u{2F9C6}', '\u{88D7}', None), ('\u{2F9C7}', '\u{88DE}', None), ('\u{2F9C8}', '\u{4635}', None),
+    ('\u{2F9C9}', '\u{88FA}', None), ('\u{2F9CA}', '\u{34BB}', None), ('\u{2F9CB}', '\u{278AE}', None), ('\u{2F9CC}', '\u{27966}', None),
+    ('\u{2F9CD}', '\u{46BE}', None), ('\u{2F9CE}', '\u{46C7}', None), ('\u{2F9CF}', '\u{8AA0}', None), ('\u{2F9D0}', '\u{8AED}', None),
+    ('\u{2F9D1}', '\u{8B8A}', None), ('\u{2F9D2}', '\u{8C55}', None), ('\u{2F9D3}', '\u{27CA8}', None), ('\u{2F9D4}', '\u{8CAB}', None),
+    ('\u{2F9D5}', '\u{8CC1}', None), ('\u{2F9D6}', '\u{8D1B}', None), ('\u{2F9D7}', '\u{8D77}', None), ('\u{2F9D8}', '\u{27F2F}', None),
+    ('\u{2F9D9}', '\u{20804}', None), ('\u{2F9DA}', '\u{8DCB}', None), ('\u{2F9DB}', '\u{8DBC}', None), ('\u{2F9DC}', '\u{8DF0}', None),
+    ('\u{2F9DD}', '\u{208DE}', None), ('\u{2F9DE}', '\u{8ED4}', None), ('\u{2F9DF}', '\u{8F38}', None), ('\u{2F9E0}', '\u{285D2}', None),
+    ('\u{2F9E1}', '\u{285ED}', None), ('\u{2F9E2}', '\u{9094}', None), ('\u{2F9E3}', '\u{90F1}', None), ('\u{2F9E4}', '\u{9111}', None),
+    ('\u{2F9E5}', '\u{2872E}', None), ('\u{2F9E6}', '\u{911B}', None), ('\u{2F9E7}', '\u{9238}', None), ('\u{2F9E8}', '\u{92D7}', None),
+    ('\u{2F9E9}', '\u{92D8}', None), ('\u{2F9EA}', '\u{927C}', None), ('\u{2F9EB}', '\u{93F9}', None), ('\u{2F9EC}', '\u{9415}', None),
+    ('\u{2F9ED}', '\u{28BFA}', None), ('\u{2F9EE}', '\u{958B}', None), ('\u{2F9EF}', '\u{4995}', None), ('\u{2F9F0}', '\u{95B7}', None),
+    ('\u{2F9F1}', '\u{28D77}', None), ('\u{2F9F2}', '\u{49E6}', None), ('\u{2F9F3}', '\u{96C3}', None), ('\u{2F9F4}', '\u{5DB2}', None),
+    ('\u{2F9F5}', '\u{9723}', None), ('\u{2F9F6}', '\u{29145}', None), ('\u{2F9F7}', '\u{2921A}', None), ('\u{2F9F8}', '\u{4A6E}', None),
+    ('\u{2F9F9}', '\u{4A76}', None), ('\u{2F9FA}', '\u{97E0}', None), ('\u{2F9FB}', '\u{2940A}', None), ('\u{2F9FC}', '\u{4AB2}', None),
+    ('\u{2F9FD}', '\u{29496}', None), ('\u{2F9FE}', '\u{980B}', None), ('\u{2F9FF}', '\u{980B}', None), ('\u{2FA00}', '\u{9829}', None),
+    ('\u{2FA01}', '\u{295B6}', None), ('\u{2FA02}', '\u{98E2}', None), ('\u{2FA03}', '\u{4B33}', None), ('\u{2FA04}', '\u{9929}', None),
+    ('\u{2FA05}', '\u{99A7}', None), ('\u{2FA06}', '\u{99C2}', None), ('\u{2FA07}', '\u{99FE}', None), ('\u{2FA08}', '\u{4BCE}', None),
+    ('\u{2FA09}', '\u{29B30}', None), ('\u{2FA0A}', '\u{9B12}', None), ('\u{2FA0B}', '\u{9C40}', None), ('\u{2FA0C}', '\u{9CFD}', None),
+    ('\u{2FA0D}', '\u{4CCE}', None), ('\u{2FA0E}', '\u{4CED}', None), ('\u{2FA0F}', '\u{9D67}', None), ('\u{2FA10}', '\u{2A0CE}', None),
+    ('\u{2FA11}', '\u{4CF8}', None), ('\u{2FA12}', '\u{2A105}', None), ('\u{2FA13}', '\u{2A20E}', None), ('\u{2FA14}', '\u{2A291}', None),
+    ('\u{2FA15}', '\u{9EBB}', None), ('\u{2FA16}', '\u{4D56}', None), ('\u{2FA17}', '\u{9EF9}', None), ('\u{2FA18}', '\u{9EFE}', None),
+    ('\u{2FA19}', '\u{9F05}', None), ('\u{2FA1A}', '\u{9F0F}', None), ('\u{2FA1B}', '\u{9F16}', None), ('\u{2FA1C}', '\u{9F3B}', None),
+    ('\u{2FA1D}', '\u{2A600}', None),
+];
+
+#[rustfmt::skip]
+pub(super) const CANONICAL_COMPOSITION: &[(char, char, char)] = &[
+    ('\u{3C}', '\u{338}', '\u{226E}'), ('\u{3D}', '\u{338}', '\u{2260}'), ('\u{3E}', '\u{338}', '\u{226F}'), ('\u{41}', '\u{300}', '\u{C0}'),
+    ('\u{41}', '\u{301}', '\u{C1}'), ('\u{41}', '\u{302}', '\u{C2}'), ('\u{41}', '\u{303}', '\u{C3}'), ('\u{41}', '\u{304}', '\u{100}'),
+    ('\u{41}', '\u{306}', '\u{102}'), ('\u{41}', '\u{307}', '\u{226}'), ('\u{41}', '\u{308}', '\u{C4}'), ('\u{41}', '\u{309}', '\u{1EA2}'),
+    ('\u{41}', '\u{30A}', '\u{C5}'), ('\u{41}', '\u{30C}', '\u{1CD}'), ('\u{41}', '\u{30F}', '\u{200}'), ('\u{41}', '\u{311}', '\u{202}'),
+    ('\u{41}', '\u{323}', '\u{1EA0}'), ('\u{41}', '\u{325}', '\u{1E00}'), ('\u{41}', '\u{328}', '\u{104}'), ('\u{42}', '\u{307}', '\u{1E02}'),
+    ('\u{42}', '\u{323}', '\u{1E04}'), ('\u{42}', '\u{331}', '\u{1E06}'), ('\u{43}', '\u{301}', '\u{106}'), ('\u{43}', '\u{302}', '\u{108}'),
+    ('\u{43}', '\u{307}', '\u{10A}'), ('\u{43}', '\u{30C}', '\u{10C}'), ('\u{43}', '\u{327}', '\u{C7}'), ('\u{44}', '\u{307}', '\u{1E0A}'),
+    ('\u{44}', '\u{30C}', '\u{10E}'), ('\u{44}', '\u{323}', '\u{1E0C}'), ('\u{44}', '\u{327}', '\u{1E10}'), ('\u{44}', '\u{32D}', '\u{1E12}'),
+    ('\u{44}', '\u{331}', '\u{1E0E}'), ('\u{45}', '\u{300}', '\u{C8}'), ('\u{45}', '\u{301}', '\u{C9}'), ('\u{45}', '\u{302}', '\u{CA}'),
+    ('\u{45}', '\u{303}', '\u{1EBC}'), ('\u{45}', '\u{304}', '\u{112}'), ('\u{45}', '\u{306}', '\u{114}'), ('\u{45}', '\u{307}', '\u{116}'),
+    ('\u{45}', '\u{308}', '\u{CB}'), ('\u{45}', '\u{309}', '\u{1EBA}'), ('\u{45}', '\u{30C}', '\u{11A}'), ('\u{45}', '\u{30F}', '\u{204}'),
+    ('\u{45}', '\u{311}', '\u{206}'), ('\u{45}', '\u{323}', '\u{1EB8}'), ('\u{45}', '\u{327}', '\u{228}'), ('\u{45}', '\u{328}', '\u{118}'),
+    ('\u{45}', '\u{32D}', '\u{1E18}'), ('\u{45}', '\u{330}', '\u{1E1A}'), ('\u{46}', '\u{307}', '\u{1E1E}'), ('\u{47}', '\u{301}', '\u{1F4}'),
+    ('\u{47}', '\u{302}', '\u{11C}'), ('\u{47}', '\u{304}', '\u{1E20}'), ('\u{47}', '\u{306}', '\u{11E}'), ('\u{47}', '\u{307}', '\u{120}'),
+    ('\u{47}', '\u{30C}', '\u{1E6}'), ('\u{47}', '\u{327}', '\u{122}'), ('\u{48}', '\u{302}', '\u{124}'), ('\u{48}', '\u{307}', '\u{1E22}'),
+    ('\u{48}', '\u{308}', '\u{1E26}'), ('\u{48}', '\u{30C}', '\u{21E}'), ('\u{48}', '\u{323}', '\u{1E24}'), ('\u{48}', '\u{327}', '\u{1E28}'),
+    ('\u{48}', '\u{32E}', '\u{1E2A}'), ('\u{49}', '\u{300}', '\u{CC}'), ('\u{49}', '\u{301}', '\u{CD}'), ('\u{49}', '\u{302}', '\u{CE}'),
+    ('\u{49}', '\u{303}', '\u{128}'), ('\u{49}', '\u{304}', '\u{12A}'), ('\u{49}', '\u{306}', '\u{12C}'), ('\u{49}', '\u{307}', '\u{130}'),
+    ('\u{49}', '\u{308}', '\u{CF}'), ('\u{49}', '\u{309}', '\u{1EC8}'), ('\u{49}', '\u{30C}', '\u{1CF}'), ('\u{49}', '\u{30F}', '\u{208}'),
+    ('\u{49}', '\u{311}', '\u{20A}'), ('\u{49}', '\u{323}', '\u{1ECA}'), ('\u{49}', '\u{328}', '\u{12E}'), ('\u{49}', '\u{330}', '\u{1E2C}'),
+    ('\u{4A}', '\u{302}', '\u{134}'), ('\u{4B}', '\u{301}', '\u{1E30}'), ('\u{4B}', '\u{30C}', '\u{1E8}'), ('\u{4B}', '\u{323}', '\u{1E32}'),
+    ('\u{4B}', '\u{327}', '\u{136}'), ('\u{4B}', '\u{331}', '\u{1E34}'), ('\u{4C}', '\u{301}', '\u{139}'), ('\u{4C}', '\u{30C}', '\u{13D}'),
+    ('\u{4C}', '\u{323}', '\u{1E36}'), ('\u{4C}', '\u{327}', '\u{13B}'), ('\u{4C}', '\u{32D}', '\u{1E3C}'), ('\u{4C}', '\u{331}', '\u{1E3A}'),
+    ('\u{4D}', '\u{301}', '\u{1E3E}'), ('\u{4D}', '\u{307}', '\u{1E40}'), ('\u{4D}', '\u{323}', '\u{1E42}'), ('\u{4E}', '\u{300}', '\u{1F8}'),
+    ('\u{4E}', '\u{301}', '\u{143}'), ('\u{4E}', '\u{303}', '\u{D1}'), ('\u{4E}', '\u{307}', '\u{1E44}'), ('\u{4E}', '\u{30C}', '\u{147}'),
+    ('\u{4E}', '\u{323}', '\u{1E46}'), ('\u{4E}', '\u{327}', '\u{145}'), ('\u{4E}', '\u{32D}', '\u{1E4A}'), ('\u{4E}', '\u{331}', '\u{1E48}'),
+    ('\u{4F}', '\u{300}', '\u{D2}'), ('\u{4F}', '\u{301}', '\u{D3}'), ('\u{4F}', '\u{302}', '\u{D4}'), ('\u{4F}', '\u{303}', '\u{D5}'),
+    ('\u{4F}', '\u{304}', '\u{14C}'), ('\u{4F}', '\u{306}', '\u{14E}'), ('\u{4F}', '\u{307}', '\u{22E}'), ('\u{4F}', '\u{308}', '\u{D6}'),
+    ('\u{4F}', '\u{309}', '\u{1ECE}'), ('\u{4F}', '\u{30B}', '\u{150}'), ('\u{4F}', '\u{30C}', '\u{1D1}'), ('\u{4F}', '\u{30F}', '\u{20C}'),
+    ('\u{4F}', '\u{311}', '\u{20E}'), ('\u{4F}', '\u{31B}', '\u{1A0}'), ('\u{4F}', '\u{323}', '\u{1ECC}'), ('\u{4F}', '\u{328}', '\u{1EA}'),
+    ('\u{50}', '\u{301}', '\u{1E54}'), ('\u{50}', '\u{307}', '\u{1E56}'), ('\u{52}', '\u{301}', '\u{154}'), ('\u{52}', '\u{307}', '\u{1E58}'),
+    ('\u{52}', '\u{30C}', '\u{158}'), ('\u{52}', '\u{30F}', '\u{210}'), ('\u{52}', '\u{311}', '\u{212}'), ('\u{52}', '\u{323}', '\u{1E5A}'),
+    ('\u{52}', '\u{327}', '\u{156}'), ('\u{52}', '\u{331}', '\u{1E5E}'), ('\u{53}', '\u{301}', '\u{15A}'), ('\u{53}', '\u{302}', '\u{15C}'),
+    ('\u{53}', '\u{307}', '\u{1E60}'), ('\u{53}', '\u{30C}', '\u{160}'), ('\u{53}', '\u{323}', '\u{1E62}'), ('\u{53}', '\u{326}', '\u{218}'),
+    ('\u{53}', '\u{327}', '\u{15E}'), ('\u{54}', '\u{307}', '\u{1E6A}'), ('\u{54}', '\u{30C}', '\u{164}'), ('\u{54}', '\u{323}', '\u{1E6C}'),
+    ('\u{54}', '\u{326}', '\u{21A}'), ('\u{54}', '\u{327}', '\u{162}'), ('\u{54}', '\u{32D}', '\u{1E70}'), ('\u{54}', '\u{331}', '\u{1E6E}'),
+    ('\u{55}', '\u{300}', '\u{D9}'), ('\u{55}', '\u{301}', '\u{DA}'), ('\u{55}', '\u{302}', '\u{DB}'), ('\u{55}', '\u{303}', '\u{168}'),
+    ('\u{55}', '\u{304}', '\u{16A}'), ('\u{55}', '\u{306}', '\u{16C}'), ('\u{55}', '\u{308}', '\u{DC}'), ('\u{55}', '\u{309}', '\u{1EE6}'),
+    ('\u{55}', '\u{30A}', '\u{16E}'), ('\u{55}', '\u{30B}', '\u{170}'), ('\u{55}', '\u{30C}', '\u{1D3}'), ('\u{55}', '\u{30F}', '\u{214}'),
+    ('\u{55}', '\u{311}', '\u{216}'), ('\u{55}', '\u{31B}', '\u{1AF}'), ('\u{55}', '\u{323}', '\u{1EE4}'), ('\u{55}', '\u{324}', '\u{1E72}'),
+    ('\u{55}', '\u{328}', '\u{172}'), ('\u{55}', '\u{32D}', '\u{1E76}'), ('\u{55}', '\u{330}', '\u{1E74}'), ('\u{56}', '\u{303}', '\u{1E7C}'),
+    ('\u{56}', '\u{323}', '\u{1E7E}'), ('\u{57}', '\u{300}', '\u{1E80}'), ('\u{57}', '\u{301}', '\u{1E82}'), ('\u{57}', '\u{302}', '\u{174}'),
+    ('\u{57}', '\u{307}', '\u{1E86}'), ('\u{57}', '\u{308}', '\u{1E84}'), ('\u{57}', '\u{323}', '\u{1E88}'), ('\u{58}', '\u{307}', '\u{1E8A}'),
+    ('\u{58}', '\u{308}', '\u{1E8C}'), ('\u{59}', '\u{300}', '\u{1EF2}'), ('\u{59}', '\u{301}', '\u{DD}'), ('\u{59}', '\u{302}', '\u{176}'),
+    ('\u{59}', '\u{303}', '\u{1EF8}'), ('\u{59}', '\u{304}', '\u{232}'), ('\u{59}', '\u{307}', '\u{1E8E}'), ('\u{59}', '\u{308}', '\u{178}'),
+    ('\u{59}', '\u{309}', '\u{1EF6}'), ('\u{59}', '\u{323}', '\u{1EF4}'), ('\u{5A}', '\u{301}', '\u{179}'), ('\u{5A}', '\u{302}', '\u{1E90}'),
+    ('\u{5A}', '\u{307}', '\u{17B}'), ('\u{5A}', '\u{30C}', '\u{17D}'), ('\u{5A}', '\u{323}', '\u{1E92}'), ('\u{5A}', '\u{331}', '\u{1E94}'),
+    ('\u{61}', '\u{300}', '\u{E0}'), ('\u{61}', '\u{301}', '\u{E1}'), ('\u{61}', '\u{302}', '\u{E2}'), ('\u{61}', '\u{303}', '\u{E3}'),
+    ('\u{61}', '\u{304}', '\u{101}'), ('\u{61}', '\u{306}', '\u{103}'), ('\u{61}', '\u{307}', '\u{227}'), ('\u{61}', '\u{308}', '\u{E4}'),
+    ('\u{61}', '\u{309}', '\u{1EA3}'), ('\u{61}', '\u{30A}', '\u{E5}'), ('\u{61}', '\u{30C}', '\u{1CE}'), ('\u{61}', '\u{30F}', '\u{201}'),
+    ('\u{61}', '\u{311}', '\u{203}'), ('\u{61}', '\u{323}', '\u{1EA1}'), ('\u{61}', '\u{325}', '\u{1E01}'), ('\u{61}', '\u{328}', '\u{105}'),
+    ('\u{62}', '\u{307}', '\u{1E03}'), ('\u{62}', '\u{323}', '\u{1E05}'), ('\u{62}', '\u{331}', '\u{1E07}'), ('\u{63}', '\u{301}', '\u{107}'),
+    ('\u{63}', '\u{302}', '\u{109}'), ('\u{63}', '\u{307}', '\u{10B}'), ('\u{63}', '\u{30C}', '\u{10D}'), ('\u{63}', '\u{327}', '\u{E7}'),
+    ('\u{64}', '\u{307}', '\u{1E0B}'), ('\u{64}', '\u{30C}', '\u{10F}'), ('\u{64}', '\u{323}', '\u{1E0D}'), ('\u{64}', '\u{327}', '\u{1E11}'),
+    ('\u{64}', '\u{32D}', '\u{1E13}'), ('\u{64}', '\u{331}', '\u{1E0F}'), ('\u{65}', '\u{300}', '\u{E8}'), ('\u{65}', '\u{301}', '\u{E9}'),
+    ('\u{65}', '\u{302}', '\u{EA}'), ('\u{65}', '\u{303}', '\u{1EBD}'), ('\u{65}', '\u{304}', '\u{113}'), ('\u{65}', '\u{306}', '\u{115}'),
+    ('\u{65}', '\u{307}', '\u{117}'), ('\u{65}', '\u{308}', '\u{EB}'), ('\u{65}', '\u{309}', '\u{1EBB}'), ('\u{65}', '\u{30C}', '\u{11B}'),
+    ('\u{65}', '\u{30F}', '\u{205}'), ('\u{65}', '\u{311}', '\u{207}'), ('\u{65}', '\u{323}', '\u{1EB9}'), ('\u{65}', '\u{327}', '\u{229}'),
+    ('\u{65}', '\u{328}', '\u{119}'), ('\u{65}', '\u{32D}', '\u{1E19}'), ('\u{65}', '\u{330}', '\u{1E1B}'), ('\u{66}', '\u{307}', '\u{1E1F}'),
+    ('\u{67}', '\u{301}', '\u{1F5}'), ('\u{67}', '\u{302}', '\u{11D}'), ('\u{67}', '\u{304}', '\u{1E21}'), ('\u{67}', '\u{306}', '\u{11F}'),
+    ('\u{67}', '\u{307}', '\u{121}'), ('\u{67}', '\u{30C}', '\u{1E7}'), ('\u{67}', '\u{327}', '\u{123}'), ('\u{68}', '\u{302}', '\u{125}'),
+    ('\u{68}', '\u{307}', '\u{1E23}'), ('\u{68}', '\u{308}', '\u{1E27}'), ('\u{68}', '\u{30C}', '\u{21F}'), ('\u{68}', '\u{323}', '\u{1E25}'),
+    ('\u{68}', '\u{327}', '\u{1E29}'), ('\u{68}', '\u{32E}', '\u{1E2B}'), ('\u{68}', '\u{331}', '\u{1E96}'), ('\u{69}', '\u{300}', '\u{EC}'),
+    ('\u{69}', '\u{301}', '\u{ED}'), ('\u{69}', '\u{302}', '\u{EE}'), ('\u{69}', '\u{303}', '\u{129}'), ('\u{69}', '\u{304}', '\u{12B}'),
+    ('\u{69}', '\u{306}', '\u{12D}'), ('\u{69}', '\u{308}', '\u{EF}'), ('\u{69}', '\u{309}', '\u{1EC9}'), ('\u{69}', '\u{30C}', '\u{1D0}'),
+    ('\u{69}', '\u{30F}', '\u{209}'), ('\u{69}', '\u{311}', '\u{20B}'), ('\u{69}', '\u{323}', '\u{1ECB}'), ('\u{69}', '\u{328}', '\u{12F}'),
+    ('\u{69}', '\u{330}', '\u{1E2D}'), ('\u{6A}', '\u{302}', '\u{135}'), ('\u{6A}', '\u{30C}', '\u{1F0}'), ('\u{6B}', '\u{301}', '\u{1E31}'),
+    ('\u{6B}', '\u{30C}', '\u{1E9}'), ('\u{6B}', '\u{323}', '\u{1E33}'), ('\u{6B}', '\u{327}', '\u{137}'), ('\u{6B}', '\u{331}', '\u{1E35}'),
+    ('\u{6C}', '\u{301}', '\u{13A}'), ('\u{6C}', '\u{30C}', '\u{13E}'), ('\u{6C}', '\u{323}', '\u{1E37}'), ('\u{6C}', '\u{327}', '\u{13C}'),
+    ('\u{6C}', '\u{32D}', '\u{1E3D}'), ('\u{6C}', '\u{331}', '\u{1E3B}'), ('\u{6D}', '\u{301}', '\u{1E3F}'), ('\u{6D}', '\u{307}', '\u{1E41}'),
+    ('\u{6D}', '\u{323}', '\u{1E43}'), ('\u{6E}', '\u{300}', '\u{1F9}'), ('\u{6E}', '\u{301}', '\u{144}'), ('\u{6E}', '\u{303}', '\u{F1}'),
+    ('\u{6E}', '\u{307}', '\u{1E45}'), ('\u{6E}', '\u{30C}', '\u{148}'), ('\u{6E}', '\u{323}', '\u{1E47}'), ('\u{6E}', '\u{327}', '\u{146}'),
+    ('\u{6E}', '\u{32D}', '\u{1E4B}'), ('\u{6E}', '\u{331}', '\u{1E49}'), ('\u{6F}', '\u{300}', '\u{F2}'), ('\u{6F}', '\u{301}', '\u{F3}'),
+    ('\u{6F}', '\u{302}', '\u{F4}'), ('\u{6F}', '\u{303}', '\u{F5}'), ('\u{6F}', '\u{304}', '\u{14D}'), ('\u{6F}', '\u{306}', '\u{14F}'),
+    ('\u{6F}', '\u{307}', '\u{22F}'), ('\u{6F}', '\u{308}', '\u{F6}'), ('\u{6F}', '\u{309}', '\u{1ECF}'), ('\u{6F}', '\u{30B}', '\u{151}'),
+    ('\u{6F}', '\u{30C}', '\u{1D2}'), ('\u{6F}', '\u{30F}', '\u{20D}'), ('\u{6F}', '\u{311}', '\u{20F}'), ('\u{6F}', '\u{31B}', '\u{1A1}'),
+    ('\u{6F}', '\u{323}', '\u{1ECD}'), ('\u{6F}', '\u{328}', '\u{1EB}'), ('\u{70}', '\u{301}', '\u{1E55}'), ('\u{70}', '\u{307}', '\u{1E57}'),
+    ('\u{72}', '\u{301}', '\u{155}'), ('\u{72}', '\u{307}', '\u{1E59}'), ('\u{72}', '\u{30C}', '\u{159}'), ('\u{72}', '\u{30F}', '\u{211}'),
+    ('\u{72}', '\u{311}', '\u{213}'), ('\u{72}', '\u{323}', '\u{1E5B}'), ('\u{72}', '\u{327}', '\u{157}'), ('\u{72}', '\u{331}', '\u{1E5F}'),
+    ('\u{73}', '\u{301}', '\u{15B}'), ('\u{73}', '\u{302}', '\u{15D}'), ('\u{73}', '\u{307}', '\u{1E61}'), ('\u{73}', '\u{30C}', '\u{161}'),
+    ('\u{73}', '\u{323}', '\u{1E63}'), ('\u{73}', '\u{326}', '\u{219}'), ('\u{73}', '\u{327}', '\u{15F}'), ('\u{74}', '\u{307}', '\u{1E6B}'),
+    ('\u{74}', '\u{308}', '\u{1E97}'), ('\u{74}', '\u{30C}', '\u{165}'), ('\u{74}', '\u{323}', '\u{1E6D}'), ('\u{74}', '\u{326}', '\u{21B}'),
+    ('\u{74}', '\u{327}', '\u{163}'), ('\u{74}', '\u{32D}', '\u{1E71}'), ('\u{74}', '\u{331}', '\u{1E6F}'), ('\u{75}', '\u{300}', '\u{F9}'),
+    ('\u{75}', '\u{301}', '\u{FA}'), ('\u{75}', '\u{302}', '\u{FB}'), ('\u{75}', '\u{303}', '\u{169}'), ('\u{75}', '\u{304}', '\u{16B}'),
+    ('\u{75}', '\u{306}', '\u{16D}'), ('\u{75}', '\u{308}', '\u{FC}'), ('\u{75}', '\u{309}', '\u{1EE7}'), ('\u{75}', '\u{30A}', '\u{16F}'),
+    ('\u{75}', '\u{30B}', '\u{171}'), ('\u{75}', '\u{30C}', '\u{1D4}'), ('\u{75}', '\u{30F}', '\u{215}'), ('\u{75}', '\u{311}', '\u{217}'),
+    ('\u{75}', '\u{31B}', '\u{1B0}'), ('\u{75}', '\u{323}', '\u{1EE5}'), ('\u{75}', '\u{324}', '\u{1E73}'), ('\u{75}', '\u{328}', '\u{173}'),
+    ('\u{75}', '\u{32D}', '\u{1E77}'), ('\u{75}', '\u{330}', '\u{1E75}'), ('\u{76}', '\u{303}', '\u{1E7D}'), ('\u{76}', '\u{323}', '\u{1E7F}'),
+    ('\u{77}', '\u{300}', '\u{1E81}'), ('\u{77}', '\u{301}', '\u{1E83}'), ('\u{77}', '\u{302}', '\u{175}'), ('\u{77}', '\u{307}', '\u{1E87}'),
+    ('\u{77}', '\u{308}', '\u{1E85}'), ('\u{77}', '\u{30A}', '\u{1E98}'), ('\u{77}', '\u{323}', '\u{1E89}'), ('\u{78}', '\u{307}', '\u{1E8B}'),
+    ('\u{78}', '\u{308}', '\u{1E8D}'), ('\u{79}', '\u{300}', '\u{1EF3}'), ('\u{79}', '\u{301}', '\u{FD}'), ('\u{79}', '\u{302}', '\u{177}'),
+    ('\u{79}', '\u{303}', '\u{1EF9}'), ('\u{79}', '\u{304}', '\u{233}'), ('\u{79}', '\u{307}', '\u{1E8F}'), ('\u{79}', '\u{308}', '\u{FF}'),
+    ('\u{79}', '\u{309}', '\u{1EF7}'), ('\u{79}', '\u{30A}', '\u{1E99}'), ('\u{79}', '\u{323}', '\u{1EF5}'), ('\u{7A}', '\u{301}', '\u{17A}'),
+    ('\u{7A}', '\u{302}', '\u{1E91}'), ('\u{7A}', '\u{307}', '\u{17C}'), ('\u{7A}', '\u{30C}', '\u{17E}'), ('\u{7A}', '\u{323}', '\u{1E93}'),
+    ('\u{7A}', '\u{331}', '\u{1E95}'), ('\u{A8}', '\u{300}', '\u{1FED}'), ('\u{A8}', '\u{301}', '\u{385}'), ('\u{A8}', '\u{342}', '\u{1FC1}'),
+    ('\u{C2}', '\u{300}', '\u{1EA6}'), ('\u{C2}', '\u{301}', '\u{1EA4}'), ('\u{C2}', '\u{303}', '\u{1EAA}'), ('\u{C2}', '\u{309}', '\u{1EA8}'),
+    ('\u{C4}', '\u{304}', '\u{1DE}'), ('\u{C5}', '\u{301}', '\u{1FA}'), ('\u{C6}', '\u{301}', '\u{1FC}'), ('\u{C6}', '\u{304}', '\u{1E2}'),
+    ('\u{C7}', '\u{301}', '\u{1E08}'), ('\u{CA}', '\u{300}', '\u{1EC0}'), ('\u{CA}', '\u{301}', '\u{1EBE}'), ('\u{CA}', '\u{303}', '\u{1EC4}'),
+    ('\u{CA}', '\u{309}', '\u{1EC2}'), ('\u{CF}', '\u{301}', '\u{1E2E}'), ('\u{D4}', '\u{300}', '\u{1ED2}'), ('\u{D4}', '\u{301}', '\u{1ED0}'),
+    ('\u{D4}', '\u{303}', '\u{1ED6}'), ('\u{D4}', '\u{309}', '\u{1ED4}'), ('\u{D5}', '\u{301}', '\u{1E4C}'), ('\u{D5}', '\u{304}', '\u{22C}'),
+    ('\u{D5}', '\u{308}', '\u{1E4E}'), ('\u{D6}', '\u{304}', '\u{22A}'), ('\u{D8}', '\u{301}', '\u{1FE}'), ('\u{DC}', '\u{300}', '\u{1DB}'),
+    ('\u{DC}', '\u{301}', '\u{1D7}'), ('\u{DC}', '\u{304}', '\u{1D5}'), ('\u{DC}', '\u{30C}', '\u{1D9}'), ('\u{E2}', '\u{300}', '\u{1EA7}'),
+    ('\u{E2}', '\u{301}', '\u{1EA5}'), ('\u{E2}', '\u{303}', '\u{1EAB}'), ('\u{E2}', '\u{309}', '\u{1EA9}'), ('\u{E4}', '\u{304}', '\u{1DF}'),
+    ('\u{E5}', '\u{301}', '\u{1FB}'), ('\u{E6}', '\u{301}', '\u{1FD}'), ('\u{E6}', '\u{304}', '\u{1E3}'), ('\u{E7}', '\u{301}', '\u{1E09}'),
+    ('\u{EA}', '\u{300}', '\u{1EC1}'), ('\u{EA}', '\u{301}', '\u{1EBF}'), ('\u{EA}', '\u{303}', '\u{1EC5}'), ('\u{EA}', '\u{309}', '\u{1EC3}'),
+    ('\u{EF}', '\u{301}', '\u{1E2F}'), ('\u{F4}', '\u{300}', '\u{1ED3}'), ('\u{F4}', '\u{301}', '\u{1ED1}'), ('\u{F4}', '\u{303}', '\u{1ED7}'),
+    ('\u{F4}', '\u{309}', '\u{1ED5}'), ('\u{F5}', '\u{301}', '\u{1E4D}'), ('\u{F5}', '\u{304}', '\u{22D}'), ('\u{F5}', '\u{308}', '\u{1E4F}'),
+    ('\u{F6}', '\u{304}', '\u{22B}'), ('\u{F8}', '\u{301}', '\u{1FF}'), ('\u{FC}', '\u{300}', '\u{1DC}'), ('\u{FC}', '\u{301}', '\u{1D8}'),
+    ('\u{FC}', '\u{304}', '\u{1D6}'), ('\u{FC}', '\u{30C}', '\u{1DA}'), ('\u{102}', '\u{300}', '\u{1EB0}'), ('\u{102}', '\u{301}', '\u{1EAE}'),
+    ('\u{102}', '\u{303}', '\u{1EB4}'), ('\u{102}', '\u{309}', '\u{1EB2}'), ('\u{103}', '\u{300}', '\u{1EB1}'), ('\u{103}', '\u{301}', '\u{1EAF}'),
+    ('\u{103}', '\u{303}', '\u{1EB5}'), ('\u{103}', '\u{309}', '\u{1EB3}'), ('\u{112}', '\u{300}', '\u{1E14}'), ('\u{112}', '\u{301}', '\u{1E16}'),
+    ('\u{113}', '\u{300}', '\u{1E15}'), ('\u{113}', '\u{301}', '\u{1E17}'), ('\u{14C}', '\u{300}', '\u{1E50}'), ('\u{14C}', '\u{301}', '\u{1E52}'),
+    ('\u{14D}', '\u{300}', '\u{1E51}'), ('\u{14D}', '\u{301}', '\u{1E53}'), ('\u{15A}', '\u{307}', '\u{1E64}'), ('\u{15B}', '\u{307}', '\u{1E65}'),
+    ('\u{160}', '\u{307}', '\u{1E66}'), ('\u{161}', '\u{307}', '\u{1E67}'), ('\u{168}', '\u{301}', '\u{1E78}'), ('\u{169}', '\u{301}', '\u{1E79}'),
+    ('\u{16A}', '\u{308}', '\u{1E7A}'), ('\u{16B}', '\u{308}', '\u{1E7B}'), ('\u{17F}', '\u{307}', '\u{1E9B}'), ('\u{1A0}', '\u{300}', '\u{1EDC}'),
+    ('\u{1A0}', '\u{301}', '\u{1EDA}'), ('\u{1A0}', '\u{303}', '\u{1EE0}'), ('\u{1A0}', '\u{309}', '\u{1EDE}'), ('\u{1A0}', '\u{323}', '\u{1EE2}'),
+    ('\u{1A1}', '\u{300}', '\u{1EDD}'), ('\u{1A1}', '\u{301}', '\u{1EDB}'), ('\u{1A1}', '\u{303}', '\u{1EE1}'), ('\u{1A1}', '\u{309}', '\u{1EDF}'),
+    ('\u{1A1}', '\u{323}', '\u{1EE3}'), ('\u{1AF}', '\u{300}', '\u{1EEA}'), ('\u{1AF}', '\u{301}', '\u{1EE8}'), ('\u{1AF}', '\u{303}', '\u{1EEE}'),
+    ('\u{1AF}', '\u{309}', '\u{1EEC}'), ('\u{1AF}', '\u{323}', '\u{1EF0}'), ('\u{1B0}', '\u{300}', '\u{1EEB}'), ('\u{1B0}', '\u{301}', '\u{1EE9}'),
+    ('\u{1B0}', '\u{303}', '\u{1EEF}'), ('\u{1B0}', '\u{309}', '\u{1EED}'), ('\u{1B0}', '\u{323}', '\u{1EF1}'), ('\u{1B7}', '\u{30C}', '\u{1EE}'),
+    ('\u{1EA}', '\u{304}', '\u{1EC}'), ('\u{1EB}', '\u{304}', '\u{1ED}'), ('\u{226}', '\u{304}', '\u{1E0}'), ('\u{227}', '\u{304}', '\u{1E1}'),
+    ('\u{228}', '\u{306}', '\u{1E1C}'), ('\u{229}', '\u{306}', '\u{1E1D}'), ('\u{22E}', '\u{304}', '\u{230}'), ('\u{22F}', '\u{304}', '\u{231}'),
+    ('\u{292}', '\u{30C}', '\u{1EF}'), ('\u{391}', '\u{300}', '\u{1FBA}'), ('\u{391}', '\u{301}', '\u{386}'), ('\u{391}', '\u{304}', '\u{1FB9}'),
+    ('\u{391}', '\u{306}', '\u{1FB8}'), ('\u{391}', '\u{313}', '\u{1F08}'), ('\u{391}', '\u{314}', '\u{1F09}'), ('\u{391}', '\u{345}', '\u{1FBC}'),
+    ('\u{395}', '\u{300}', '\u{1FC8}'), ('\u{395}', '\u{301}', '\u{388}'), ('\u{395}', '\u{313}', '\u{1F18}'), ('\u{395}', '\u{314}', '\u{1F19}'),
+    ('\u{397}', '\u{300}', '\u{1FCA}'), ('\u{397}', '\u{301}', '\u{389}'), ('\u{397}', '\u{313}', '\u{1F28}'), ('\u{397}', '\u{314}', '\u{1F29}'),
+    ('\u{397}', '\u{345}', '\u{1FCC}'), ('\u{399}', '\u{300}', '\u{1FDA}'), ('\u{399}', '\u{301}', '\u{38A}'), ('\u{399}', '\u{304}', '\u{1FD9}'),
+    ('\u{399}', '\u{306}', '\u{1FD8}'), ('\u{399}', '\u{308}', '\u{3AA}'), ('\u{399}', '\u{313}', '\u{1F38}'), ('\u{399}', '\u{314}', '\u{1F39}'),
+    ('\u{39F}', '\u{300}', '\u{1FF8}'), ('\u{39F}', '\u{301}', '\u{38C}'), ('\u{39F}', '\u{313}', '\u{1F48}'), ('\u{39F}', '\u{314}', '\u{1F49}'),
+    ('\u{3A1}', '\u{314}', '\u{1FEC}'), ('\u{3A5}', '\u{300}', '\u{1FEA}'), ('\u{3A5}', '\u{301}', '\u{38E}'), ('\u{3A5}', '\u{304}', '\u{1FE9}'),
+    ('\u{3A5}', '\u{306}', '\u{1FE8}'), ('\u{3A5}', '\u{308}', '\u{3AB}'), ('\u{3A5}', '\u{314}', '\u{1F59}'), ('\u{3A9}', '\u{300}', '\u{1FFA}'),
+    ('\u{3A9}', '\u{301}', '\u{38F}'), ('\u{3A9}', '\u{313}', '\u{1F68}'), ('\u{3A9}', '\u{314}', '\u{1F69}'), ('\u{3A9}', '\u{345}', '\u{1FFC}'),
+    ('\u{3AC}', '\u{345}', '\u{1FB4}'), ('\u{3AE}', '\u{345}', '\u{1FC4}'), ('\u{3B1}', '\u{300}', '\u{1F70}'), ('\u{3B1}', '\u{301}', '\u{3AC}'),
+    ('\u{3B1}', '\u{304}', '\u{1FB1}'), ('\u{3B1}', '\u{306}', '\u{1FB0}'), ('\u{3B1}', '\u{313}', '\u{1F00}'), ('\u{3B1}', '\u{314}', '\u{1F01}'),
+    ('\u{3B1}', '\u{342}', '\u{1FB6}'), ('\u{3B1}', '\u{345}', '\u{1FB3}'), ('\u{3B5}', '\u{300}', '\u{1F72}'), ('\u{3B5}', '\u{301}', '\u{3AD}'),
+    ('\u{3B5}', '\u{313}', '\u{1F10}'), ('\u{3B5}', '\u{314}', '\u{1F11}'), ('\u{3B7}', '\u{300}', '\u{1F74}'), ('\u{3B7}', '\u{301}', '\u{3AE}'),
+    ('\u{3B7}', '\u{313}', '\u{1F20}'), ('\u{3B7}', '\u{314}', '\u{1F21}'), ('\u{3B7}', '\u{342}', '\u{1FC6}'), ('\u{3B7}', '\u{345}', '\u{1FC3}'),
+    ('\u{3B9}', '\u{300}', '\u{1F76}'), ('\u{3B9}', '\u{301}', '\u{3AF}'), ('\u{3B9}', '\u{304}', '\u{1FD1}'), ('\u{3B9}', '\u{306}', '\u{1FD0}'),
+    ('\u{3B9}', '\u{308}', '\u{3CA}'), ('\u{3B9}', '\u{313}', '\u{1F30}'), ('\u{3B9}', '\u{314}', '\u{1F31}'), ('\u{3B9}', '\u{342}', '\u{1FD6}'),
+    ('\u{3BF}', '\u{300}', '\u{1F78}'), ('\u{3BF}', '\u{301}', '\u{3CC}'), ('\u{3BF}', '\u{313}', '\u{1F40}'), ('\u{3BF}', '\u{314}', '\u{1F41}'),
+    ('\u{3C1}', '\u{313}', '\u{1FE4}'), ('\u{3C1}', '\u{314}', '\u{1FE5}'), ('\u{3C5}', '\u{300}', '\u{1F7A}'), ('\u{3C5}', '\u{301}', '\u{3CD}'),
+    ('\u{3C5}', '\u{304}', '\u{1FE1}'), ('\u{3C5}', '\u{306}', '\u{1FE0}'), ('\u{3C5}', '\u{308}', '\u{3CB}'), ('\u{3C5}', '\u{313}', '\u{1F50}'),
+    ('\u{3C5}', '\u{314}', '\u{1F51}'), ('\u{3C5}', '\u{342}', '\u{1FE6}'), ('\u{3C9}', '\u{300}', '\u{1F7C}'), ('\u{3C9}', '\u{301}', '\u{3CE}'),
+    ('\u{3C9}', '\u{313}', '\u{1F60}'), ('\u{3C9}', '\u{314}', '\u{1F61}'), ('\u{3C9}', '\u{342}', '\u{1FF6}'), ('\u{3C9}', '\u{345}', '\u{1FF3}'),
+    ('\u{3CA}', '\u{300}', '\u{1FD2}'), ('\u{3CA}', '\u{301}', '\u{390}'), ('\u{3CA}', '\u{342}', '\u{1FD7}'), ('\u{3CB}', '\u{300}', '\u{1FE2}'),
+    ('\u{3CB}', '\u{301}', '\u{3B0}'), ('\u{3CB}', '\u{342}', '\u{1FE7}'), ('\u{3CE}', '\u{345}', '\u{1FF4}'), ('\u{3D2}', '\u{301}', '\u{3D3}'),
+    ('\u{3D2}', '\u{308}', '\u{3D4}'), ('\u{406}', '\u{308}', '\u{407}'), ('\u{410}', '\u{306}', '\u{4D0}'), ('\u{410}', '\u{308}', '\u{4D2}'),
+    ('\u{413}', '\u{301}', '\u{403}'), ('\u{415}', '\u{300}', '\u{400}'), ('\u{415}', '\u{306}', '\u{4D6}'), ('\u{415}', '\u{308}', '\u{401}'),
+    ('\u{416}', '\u{306}', '\u{4C1}'), ('\u{416}', '\u{308}', '\u{4DC}'), ('\u{417}', '\u{308}', '\u{4DE}'), ('\u{418}', '\u{300}', '\u{40D}'),
+    ('\u{418}', '\u{304}', '\u{4E2}'), ('\u{418}', '\u{306}', '\u{419}'), ('\u{418}', '\u{308}', '\u{4E4}'), ('\u{41A}', '\u{301}', '\u{40C}'),
+    ('\u{41E}', '\u{308}', '\u{4E6}'), ('\u{423}', '\u{304}', '\u{4EE}'), ('\u{423}', '\u{306}', '\u{40E}'), ('\u{423}', '\u{308}', '\u{4F0}'),
+    ('\u{423}', '\u{30B}', '\u{4F2}'), ('\u{427}', '\u{308}', '\u{4F4}'), ('\u{42B}', '\u{308}', '\u{4F8}'), ('\u{42D}', '\u{308}', '\u{4EC}'),
+    ('\u{430}', '\u{306}', '\u{4D1}'), ('\u{430}', '\u{308}', '\u{4D3}'), ('\u{433}', '\u{301}', '\u{453}'), ('\u{435}', '\u{300}', '\u{450}'),
+    ('\u{435}', '\u{306}', '\u{4D7}'), ('\u{435}', '\u{308}', '\u{451}'), ('\u{436}', '\u{306}', '\u{4C2}'), ('\u{436}', '\u{308}', '\u{4DD}'),
+    ('\u{437}', '\u{308}', '\u{4DF}'), ('\u{438}', '\u{300}', '\u{45D}'), ('\u{438}', '\u{304}', '\u{4E3}'), ('\u{438}', '\u{306}', '\u{439}'),
+    ('\u{438}', '\u{308}', '\u{4E5}'), ('\u{43A}', '\u{301}', '\u{45C}'), ('\u{43E}', '\u{308}', '\u{4E7}'), ('\u{443}', '\u{304}', '\u{4EF}'),
+    ('\u{443}', '\u{306}', '\u{45E}'), ('\u{443}', '\u{308}', '\u{4F1}'), ('\u{443}', '\u{30B}', '\u{4F3}'), ('\u{447}', '\u{308}', '\u{4F5}'),
+    ('\u{44B}', '\u{308}', '\u{4F9}'), ('\u{44D}', '\u{308}', '\u{4ED}'), ('\u{456}', '\u{308}', '\u{457}'), ('\u{474}', '\u{30F}', '\u{476}'),
+    ('\u{475}', '\u{30F}', '\u{477}'), ('\u{4D8}', '\u{308}', '\u{4DA}'), ('\u{4D9}', '\u{308}', '\u{4DB}'), ('\u{4E8}', '\u{308}', '\u{4EA}'),
+    ('\u{4E9}', '\u{308}', '\u{4EB}'), ('\u{627}', '\u{653}', '\u{622}'), ('\u{627}', '\u{654}', '\u{623}'), ('\u{627}', '\u{655}', '\u{625}'),
+    ('\u{648}', '\u{654}', '\u{624}'), ('\u{64A}', '\u{654}', '\u{626}'), ('\u{6C1}', '\u{654}', '\u{6C2}'), ('\u{6D2}', '\u{654}', '\u{6D3}'),
+    ('\u{6D5}', '\u{654}', '\u{6C0}'), ('\u{928}', '\u{93C}', '\u{929}'), ('\u{930}', '\u{93C}', '\u{931}'), ('\u{933}', '\u{93C}', '\u{934}'),
+    ('\u{9C7}', '\u{9BE}', '\u{9CB}'), ('\u{9C7}', '\u{9D7}', '\u{9CC}'), ('\u{B47}', '\u{B3E}', '\u{B4B}'), ('\u{B47}', '\u{B56}', '\u{B48}'),
+    ('\u{B47}', '\u{B57}', '\u{B4C}'), ('\u{B92}', '\u{BD7}', '\u{B94}'), ('\u{BC6}', '\u{BBE}', '\u{BCA}'), ('\u{BC6}', '\u{BD7}', '\u{BCC}'),
+    ('\u{BC7}', '\u{BBE}', '\u{BCB}'), ('\u{C46}', '\u{C56}', '\u{C48}'), ('\u{CBF}', '\u{CD5}', '\u{CC0}'), ('\u{CC6}', '\u{CC2}', '\u{CCA}'),
+    ('\u{CC6}', '\u{CD5}', '\u{CC7}'), ('\u{CC6}', '\u{CD6}', '\u{CC8}'), ('\u{CCA}', '\u{CD5}', '\u{CCB}'), ('\u{D46}', '\u{D3E}', '\u{D4A}'),
+    ('\u{D46}', '\u{D57}', '\u{D4C}'), ('\u{D47}', '\u{D3E}', '\u{D4B}'), ('\u{DD9}', '\u{DCA}', '\u{DDA}'), ('\u{DD9}', '\u{DCF}', '\u{DDC}'),
+    ('\u{DD9}', '\u{DDF}', '\u{DDE}'), ('\u{DDC}', '\u{DCA}', '\u{DDD}'), ('\u{1025}', '\u{102E}', '\u{1026}'), ('\u{1B05}', '\u{1B35}', '\u{1B06}'),
+    ('\u{1B07}', '\u{1B35}', '\u{1B08}'), ('\u{1B09}', '\u{1B35}', '\u{1B0A}'), ('\u{1B0B}', '\u{1B35}', '\u{1B0C}'), ('\u{1B0D}', '\u{1B35}', '\u{1B0E}'),
+    ('\u{1B11}', '\u{1B35}', '\u{1B12}'), ('\u{1B3A}', '\u{1B35}', '\u{1B3B}'), ('\u{1B3C}', '\u{1B35}', '\u{1B3D}'), ('\u{1B3E}', '\u{1B35}', '\u{1B40}'),
+    ('\u{1B3F}', '\u{1B35}', '\u{1B41}'), ('\u{1B42}', '\u{1B35}', '\u{1B43}'), ('\u{1E36}', '\u{304}', '\u{1E38}'), ('\u{1E37}', '\u{304}', '\u{1E39}'),
+    ('\u{1E5A}', '\u{304}', '\u{1E5C}'), ('\u{1E5B}', '\u{304}', '\u{1E5D}'), ('\u{1E62}', '\u{307}', '\u{1E68}'), ('\u{1E63}', '\u{307}', '\u{1E69}'),
+    ('\u{1EA0}', '\u{302}', '\u{1EAC}'), ('\u{1EA0}', '\u{306}', '\u{1EB6}'), ('\u{1EA1}', '\u{302}', '\u{1EAD}'), ('\u{1EA1}', '\u{306}', '\u{1EB7}'),
+    ('\u{1EB8}', '\u{302}', '\u{1EC6}'), ('\u{1EB9}', '\u{302}', '\u{1EC7}'), ('\u{1ECC}', '\u{302}', '\u{1ED8}'), ('\u{1ECD}', '\u{302}', '\u{1ED9}'),
+    ('\u{1F00}', '\u{300}', '\u{1F02}'), ('\u{1F00}', '\u{301}', '\u{1F04}'), ('\u{1F00}', '\u{342}', '\u{1F06}'), ('\u{1F00}', '\u{345}', '\u{1F80}'),
+    ('\u{1F01}', '\u{300}', '\u{1F03}'), ('\u{1F01}', '\u{301}', '\u{1F05}'), ('\u{1F01}', '\u{342}', '\u{1F07}'), ('\u{1F01}', '\u{345}', '\u{1F81}'),
+    ('\u{1F02}', '\u{345}', '\u{1F82}'), ('\u{1F03}', '\u{345}', '\u{1F83}'), ('\u{1F04}', '\u{345}', '\u{1F84}'), ('\u{1F05}', '\u{345}', '\u{1F85}'),
+    ('\u{1F06}', '\u{345}', '\u{1F86}'), ('\u{1F07}', '\u{345}', '\u{1F87}'), ('\u{1F08}', '\u{300}', '\u{1F0A}'), ('\u{1F08}', '\u{301}', '\u{1F0C}'),
+    ('\u{1F08}', '\u{342}', '\u{1F0E}'), ('\u{1F08}', '\u{345}', '\u{1F88}'), ('\u{1F09}', '\u{300}', '\u{1F0B}'), ('\u{1F09}', '\u{301}', '\u{1F0D}'),
+    ('\u{1F09}', '\u{342}', '\u{1F0F}'), ('\u{1F09}', '\u{345}', '\u{1F89}'), ('\u{1F0A}', '\u{345}', '\u{1F8A}'), ('\u{1F0B}', '\u{345}', '\u{1F8B}'),
+    ('\u{1F0C}', '\u{345}', '\u{1F8C}'), ('\u{1F0D}', '\u{345}', '\u{1F8D}'), ('\u{1F0E}', '\u{345}', '\u{1F8E}'), ('\u{1F0F}', '\u{345}', '\u{1F8F}'),
+    ('\u{1F10}', '\u{300}', '\u{1F12}'), ('\u{1F10}', '\u{301}', '\u{1F14}'), ('\u{1F11}', '\u{300}', '\u{1F13}'), ('\u{1F11}', '\u{301}', '\u{1F15}'),
+    ('\u{1F18}', '\u{300}', '\u{1F1A}'), ('\u{1F18}', '\u{301}', '\u{1F1C}'), ('\u{1F19}', '\u{300}', '\u{1F1B}'), ('\u{1F19}', '\u{301}', '\u{1F1D}'),
+    ('\u{1F20}', '\u{300}', '\u{1F22}'), ('\u{1F20}', '\u{301}', '\u{1F24}'), ('\u{1F20}', '\u{342}', '\u{1F26}'), ('\u{1F20}', '\u{345}', '\u{1F90}'),
+    ('\u{1F21}', '\u{300}', '\u{1F23}'), ('\u{1F21}', '\u{301}', '\u{1F25}'), ('\u{1F21}', '\u{342}', '\u{1F27}'), ('\u{1F21}', '\u{345}', '\u{1F91}'),
+    ('\u{1F22}', '\u{345}', '\u{1F92}'), ('\u{1F23}', '\u{345}', '\u{1F93}'), ('\u{1F24}', '\u{345}', '\u{1F94}'), ('\u{1F25}', '\u{345}', '\u{1F95}'),
+    ('\u{1F26}', '\u{345}', '\u{1F96}'), ('\u{1F27}', '\u{345}', '\u{1F97}'), ('\u{1F28}', '\u{300}', '\u{1F2A}'), ('\u{1F28}', '\u{301}', '\u{1F2C}'),
+    ('\u{1F28}', '\u{342}', '\u{1F2E}'), ('\u{1F28}', '\u{345}', '\u{1F98}'), ('\u{1F29}', '\u{300}', '\u{1F2B}'), ('\u{1F29}', '\u{301}', '\u{1F2D}'),
+    ('\u{1F29}', '\u{342}', '\u{1F2F}'), ('\u{1F29}', '\u{345}', '\u{1F99}'), ('\u{1F2A}', '\u{345}', '\u{1F9A}'), ('\u{1F2B}', '\u{345}', '\u{1F9B}'),
+    ('\u{1F2C}', '\u{345}', '\u{1F9C}'), ('\u{1F2D}', '\u{345}', '\u{1F9D}'), ('\u{1F2E}', '\u{345}', '\u{1F9E}'), ('\u{1F2F}', '\u{345}', '\u{1F9F}'),
+    ('\u{1F30}', '\u{300}', '\u{1F32}'), ('\u{1F30}', '\u{301}', '\u{1F34}'), ('\u{1F30}', '\u{342}', '\u{1F36}'), ('\u{1F31}', '\u{300}', '\u{1F33}'),
+    ('\u{1F31}', '\u{301}', '\u{1F35}'), ('\u{1F31}', '\u{342}', '\u{1F37}'), ('\u{1F38}', '\u{300}', '\u{1F3A}'), ('\u{1F38}', '\u{301}', '\u{1F3C}'),
+    ('\u{1F38}', '\u{342}', '\u{1F3E}'), ('\u{1F39}', '\u{300}', '\u{1F3B}'), ('\u{1F39}', '\u{301}', '\u{1F3D}'), ('\u{1F39}', '\u{342}', '\u{1F3F}'),
+    ('\u{1F40}', '\u{300}', '\u{1F42}'), ('\u{1F40}', '\u{301}', '\u{1F44}'), ('\u{1F41}', '\u{300}', '\u{1F43}'), ('\u{1F41}', '\u{301}', '\u{1F45}'),
+    ('\u{1F48}', '\u{300}', '\u{1F4A}'), ('\u{1F48}', '\u{301}', '\u{1F4C}'), ('\u{1F49}', '\u{300}', '\u{1F4B}'), ('\u{1F49}', '\u{301}', '\u{1F4D}'),
+    ('\u{1F50}', '\u{300}', '\u{1F52}'), ('\u{1F50}', '\u{301}', '\u{1F54}'), ('\u{1F50}', '\u{342}', '\u{1F56}'), ('\u{1F51}', '\u{300}', '\u{1F53}'),
+    ('\u{1F51}', '\u{301}', '\u{1F55}'), ('\u{1F51}', '\u{342}', '\u{1F57}'), ('\u{1F59}', '\u{300}', '\u{1F5B}'), ('\u{1F59}', '\u{301}', '\u{1F5D}'),
+    ('\u{1F59}', '\u{342}', '\u{1F5F}'), ('\u{1F60}', '\u{300}', '\u{1F62}'), ('\u{1F60}', '\u{301}', '\u{1F64}'), ('\u{1F60}', '\u{342}', '\u{1F66}'),
+    ('\u{1F60}', '\u{345}', '\u{1FA0}'), ('\u{1F61}', '\u{300}', '\u{1F63}'), ('\u{1F61}', '\u{301}', '\u{1F65}'), ('\u{1F61}', '\u{342}', '\u{1F67}'),
+    ('\u{1F61}', '\u{345}', '\u{1FA1}'), ('\u{1F62}', '\u{345}', '\u{1FA2}'), ('\u{1F63}', '\u{345}', '\u{1FA3}'), ('\u{1F64}', '\u{345}', '\u{1FA4}'),
+    ('\u{1F65}', '\u{345}', '\u{1FA5}'), ('\u{1F66}', '\u{345}', '\u{1FA6}'), ('\u{1F67}', '\u{345}', '\u{1FA7}'), ('\u{1F68}', '\u{300}', '\u{1F6A}'),
+    ('\u{1F68}', '\u{301}', '\u{1F6C}'), ('\u{1F68}', '\u{342}', '\u{1F6E}'), ('\u{1F68}', '\u{345}', '\u{1FA8}'), ('\u{1F69}', '\u{300}', '\u{1F6B}'),
+    ('\u{1F69}', '\u{301}', '\u{1F6D}'), ('\u{1F69}', '\u{342}', '\u{1F6F}'), ('\u{1F69}', '\u{345}', '\u{1FA9}'), ('\u{1F6A}', '\u{345}', '\u{1FAA}'),
+    ('\u{1F6B}', '\u{345}', '\u{1FAB}'), ('\u{1F6C}', '\u{345}', '\u{1FAC}'), ('\u{1F6D}', '\u{345}', '\u{1FAD}'), ('\u{1F6E}', '\u{345}', '\u{1FAE}'),
+    ('\u{1F6F}', '\u{345}', '\u{1FAF}'), ('\u{1F70}', '\u{345}', '\u{1FB2}'), ('\u{1F74}', '\u{345}', '\u{1FC2}'), ('\u{1F7C}', '\u{345}', '\u{1FF2}'),
+    ('\u{1FB6}', '\u{345}', '\u{1FB7}'), ('\u{1FBF}', '\u{300}', '\u{1FCD}'), ('\u{1FBF}', '\u{301}', '\u{1FCE}'), ('\u{1FBF}', '\u{342}', '\u{1FCF}'),
+    ('\u{1FC6}', '\u{345}', '\u{1FC7}'), ('\u{1FF6}', '\u{345}', '\u{1FF7}'), ('\u{1FFE}', '\u{300}', '\u{1FDD}'), ('\u{1FFE}', '\u{301}', '\u{1FDE}'),
+    ('\u{1FFE}', '\u{342}', '\u{1FDF}'), ('\u{2190}', '\u{338}', '\u{219A}'), ('\u{2192}', '\u{338}', '\u{219B}'), ('\u{2194}', '\u{338}', '\u{21AE}'),
+    ('\u{21D0}', '\u{338}', '\u{21CD}'), ('\u{21D2}', '\u{338}', '\u{21CF}'), ('\u{21D4}', '\u{338}', '\u{21CE}'), ('\u{2203}', '\u{338}', '\u{2204}'),
+    ('\u{2208}', '\u{338}', '\u{2209}'), ('\u{220B}', '\u{338}', '\u{220C}'), ('\u{2223}', '\u{338}', '\u{2224}'), ('\u{2225}', '\u{338}', '\u{2226}'),
+    ('\u{223C}', '\u{338}', '\u{2241}'), ('\u{2243}', '\u{338}', '\u{2244}'), ('\u{2245}', '\u{338}', '\u{2247}'), ('\u{2248}', '\u{338}', '\u{2249}'),
+    ('\u{224D}', '\u{338}', '\u{226D}'), ('\u{2261}', '\u{338}', '\u{2262}'), ('\u{2264}', '\u{338}', '\u{2270}'), ('\u{2265}', '\u{338}', '\u{2271}'),
+    ('\u{2272}', '\u{338}', '\u{2274}'), ('\u{2273}', '\u{338}', '\u{2275}'), ('\u{2276}', '\u{338}', '\u{2278}'), ('\u{2277}', '\u{338}', '\u{2279}'),
+    ('\u{227A}', '\u{338}', '\u{2280}'), ('\u{227B}', '\u{338}', '\u{2281}'), ('\u{227C}', '\u{338}', '\u{22E0}'), ('\u{227D}', '\u{338}', '\u{22E1}'),
+    ('\u{2282}', '\u{338}', '\u{2284}'), ('\u{2283}', '\u{338}', '\u{2285}'), ('\u{2286}', '\u{338}', '\u{2288}'), ('\u{2287}', '\u{338}', '\u{2289}'),
+    ('\u{2291}', '\u{338}', '\u{22E2}'), ('\u{2292}', '\u{338}', '\u{22E3}'), ('\u{22A2}', '\u{338}', '\u{22AC}'), ('\u{22A8}', '\u{338}', '\u{22AD}'),
+    ('\u{22A9}', '\u{338}', '\u{22AE}'), ('\u{22AB}', '\u{338}', '\u{22AF}'), ('\u{22B2}', '\u{338}', '\u{22EA}'), ('\u{22B3}', '\u{338}', '\u{22EB}'),
+    ('\u{22B4}', '\u{338}', '\u{22EC}'), ('\u{22B5}', '\u{338}', '\u{22ED}'), ('\u{3046}', '\u{3099}', '\u{3094}'), ('\u{304B}', '\u{3099}', '\u{304C}'),
+    ('\u{304D}', '\u{3099}', '\u{304E}'), ('\u{304F}', '\u{3099}', '\u{3050}'), ('\u{3051}', '\u{3099}', '\u{3052}'), ('\u{3053}', '\u{3099}', '\u{3054}'),
+    ('\u{3055}', '\u{3099}', '\u{3056}'), ('\u{3057}', '\u{3099}', '\u{3058}'), ('\u{3059}', '\u{3099}', '\u{305A}'), ('\u{305B}', '\u{3099}', '\u{305C}'),
+    ('\u{305D}', '\u{3099}', '\u{305E}'), ('\u{305F}', '\u{3099}', '\u{3060}'), ('\u{3061}', '\u{3099}', '\u{3062}'), ('\u{3064}', '\u{3099}', '\u{3065}'),
+    ('\u{3066}', '\u{3099}', '\u{3067}'), ('\u{3068}', '\u{3099}', '\u{3069}'), ('\u{306F}', '\u{3099}', '\u{3070}'), ('\u{306F}', '\u{309A}', '\u{3071}'),
+    ('\u{3072}', '\u{3099}', '\u{3073}'), ('\u{3072}', '\u{309A}', '\u{3074}'), ('\u{3075}', '\u{3099}', '\u{3076}'), ('\u{3075}', '\u{309A}', '\u{3077}'),
+    ('\u{3078}', '\u{3099}', '\u{3079}'), ('\u{3078}', '\u{309A}', '\u{307A}'), ('\u{307B}', '\u{3099}', '\u{307C}'), ('\u{307B}', '\u{309A}', '\u{307D}'),
+    ('\u{309D}', '\u{3099}', '\u{309E}'), ('\u{30A6}', '\u{3099}', '\u{30F4}'), ('\u{30AB}', '\u{3099}', '\u{30AC}'), ('\u{30AD}', '\u{3099}', '\u{30AE}'),
+    ('\u{30AF}', '\u{3099}', '\u{30B0}'), ('\u{30B1}', '\u{3099}', '\u{30B2}'), ('\u{30B3}', '\u{3099}', '\u{30B4}'), ('\u{30B5}', '\u{3099}', '\u{30B6}'),
+    ('\u{30B7}', '\u{3099}', '\u{30B8}'), ('\u{30B9}', '\u{3099}', '\u{30BA}'), ('\u{30BB}', '\u{3099}', '\u{30BC}'), ('\u{30BD}', '\u{3099}', '\u{30BE}'),
+    ('\u{30BF}', '\u{3099}', '\u{30C0}'), ('\u{30C1}', '\u{3099}', '\u{30C2}'), ('\u{30C4}', '\u{3099}', '\u{30C5}'), ('\u{30C6}', '\u{3099}', '\u{30C7}'),
+    ('\u{30C8}', '\u{3099}', '\u{30C9}'), ('\u{30CF}', '\u{3099}', '\u{30D0}'), ('\u{30CF}', '\u{309A}', '\u{30D1}'), ('\u{30D2}', '\u{3099}', '\u{30D3}'),
+    ('\u{30D2}', '\u{309A}', '\u{30D4}'), ('\u{30D5}', '\u{3099}', '\u{30D6}'), ('\u{30D5}', '\u{309A}', '\u{30D7}'), ('\u{30D8}', '\u{3099}', '\u{30D9}'),
+    ('\u{30D8}', '\u{309A}', '\u{30DA}'), ('\u{30DB}', '\u{3099}', '\u{30DC}'), ('\u{30DB}', '\u{309A}', '\u{30DD}'), ('\u{30EF}', '\u{3099}', '\u{30F7}'),
+    ('\u{30F0}', '\u{3099}', '\u{30F8}'), ('\u{30F1}', '\u{3099}', '\u{30F9}'), ('\u{30F2}', '\u{3099}', '\u{30FA}'), ('\u{30FD}', '\u{3099}', '\u{30FE}'),
+    ('\u{11099}', '\u{110BA}', '\u{1109A}'), ('\u{1109B}', '\u{110BA}', '\u{1109C}'), ('\u{110A5}', '\u{110BA}', '\u{110AB}'), ('\u{11131}', '\u{11127}', '\u{1112E}'),
+    ('\u{11132}', '\u{11127}', '\u{1112F}'), ('\u{11347}', '\u{1133E}', '\u{1134B}'), ('\u{11347}', '\u{11357}', '\u{1134C}'), ('\u{114B9}', '\u{114B0}', '\u{114BC}'),
+    ('\u{114B9}', '\u{114BA}', '\u{114BB}'), ('\u{114B9}', '\u{114BD}', '\u{114BE}'), ('\u{115B8}', '\u{115AF}', '\u{115BA}'), ('\u{115B9}', '\u{115AF}', '\u{115BB}'),
+    ('\u{11935}', '\u{11930}', '\u{11938}'),
+];
+
+pub(super) const UNKNOWN_SCRIPT: u8 = 214;
+
+#[rustfmt::skip]
+pub(super) const SCRIPT_SETS: &[[u64; 3]] = &[
+    [0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF, 0xFFFFFFFFFFFFFFFF],
+    [0x0000000000000000, 0x0000000000000008, 0x0000000000000000],
+    [0x0000000000001000, 0x0000000000000000, 0x0000000200000000],
+    [0x0000040000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000001000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000010000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000010000000, 0x0000020000000000, 0x0000000000000000],
+    [0x0000008010000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000010000000, 0x0000000000000008, 0x0000000000000000],
+    [0x0000000000000010, 0x0000000000000000, 0x0000000000000000],
+    [0x0008000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000008, 0x0000000000000000, 0x0000000000000000],
+    [0x0001000000000008, 0x0000000200000000, 0x0000000010020040],
+    [0x0000000000000008, 0x0000000000000000, 0x0000000000020040],
+    [0x0001000000000009, 0x0000000200000000, 0x0000000010020040],
+    [0x0001000000000009, 0x008040000000C000, 0x0000000000000042],
+    [0x0000000000000008, 0x0000000000000000, 0x0000000000000040],
+    [0x0000000000000008, 0x0000000000000000, 0x0000000010020000],
+    [0x0001000000000008, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000040],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000020000],
+    [0x0000000000000000, 0x0000000200000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0400000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000004000, 0x0000000000000000],
+    [0x0000000040000000, 0x0000000000000000, 0x0000000000000000],
+    [0x04002A0040000400, 0x1000800000002008, 0x0000000000212000],
+    [0x04002A0040000400, 0x0000800000002008, 0x0000000000212000],
+    [0x04003A0140000400, 0x0000800040022802, 0x0000000000213021],
+    [0x04003A0140000400, 0x0000800040022822, 0x0000000000213021],
+    [0x0200000140000000, 0x0000000000000800, 0x0000000000000000],
+    [0x0000000000000400, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000100400, 0x0000000000000000, 0x0000000000000020],
+    [0x0000200000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000200000000000, 0x0000000008000000, 0x0000000000000000],
+    [0x0000080000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000080000000000, 0x0000000000000001, 0x0000000000000000],
+    [0x0000000000000000, 0x0000800000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000002000],
+    [0x0000020000000000, 0x0000000000000000, 0x0000000000002000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000010000],
+    [0x0400000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0400000000000000, 0x0000000040000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000002000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000001],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000040000],
+    [0x0000000000000000, 0x0000000000000004, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000080000],
+    [0x0000000000000000, 0x0000000010000000, 0x0000000000000000],
+    [0x0000000000100000, 0x0000000010000000, 0x0000000000000200],
+    [0x0000004000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000004000000000, 0x0000000000000008, 0x0000000000000000],
+    [0x0000800000000000, 0x0000000000000000, 0x0000000100000000],
+    [0x0000002000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000400000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000020000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000001000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0200000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000080],
+    [0x0002000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0002000000010000, 0x0000000000000000, 0x0000000000000180],
+    [0x0000000000010000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000100],
+    [0x8000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000002000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0020000002000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000020, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000200],
+    [0x0000000000000000, 0x0000000080000000, 0x0000000000000000],
+    [0x0000000000008000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000400],
+    [0x0000000000000040, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000010],
+    [0x0000000000000200, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000010, 0x0000000000000000],
+    [0x0000000000000000, 0x0000002000000000, 0x0000000000000000],
+    [0x0400020040000400, 0x0000000000000000, 0x0000000000000000],
+    [0x0000020040000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000040000400, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000040000000, 0x1000000000000000, 0x0000000000000000],
+    [0x0400000040000000, 0x0000800000002000, 0x0000000000012000],
+    [0x0000000040000000, 0x0000000040000000, 0x0000000000000000],
+    [0x0400020040000400, 0x0000800040000000, 0x0000000000210000],
+    [0x0400020040000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000040000000, 0x0000000000000000],
+    [0x0000000010000000, 0x0000000000000000, 0x0000000000000040],
+    [0x0000000000000000, 0x0000000002000008, 0x0000000000000000],
+    [0x0000020040000000, 0x0000000000000008, 0x0000000000000000],
+    [0x0000000000004000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000008000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000100000],
+    [0x0000400000000000, 0x0000000000000000, 0x0000000380000000],
+    [0x0810C00000001000, 0x0000000000000000, 0x00000003A0000000],
+    [0x0810C00000001000, 0x0000000000000000, 0x0000000380000000],
+    [0x0000400000001000, 0x0000000000000000, 0x0000000380000000],
+    [0x0810000000000000, 0x0000000000000000, 0x0000000080000000],
+    [0x0810400000000000, 0x0000000000000000, 0x0000000380000000],
+    [0x0010000000000000, 0x0000000000000000, 0x0000000080000000],
+    [0x0800000000000000, 0x0000000000000000, 0x0000000080000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000020000000],
+    [0x0000000000000000, 0x0000000000000100, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000001000000],
+    [0x0000000000000080, 0x0000000000000000, 0x0000000000000000],
+    [0x0000400000000000, 0x0000000000000008, 0x0000000380000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000020],
+    [0x0600280140000000, 0x0000000041002803, 0x0000000000201000],
+    [0x0600280140000000, 0x0000000041000803, 0x0000000000201000],
+    [0x0200280140000000, 0x0000000001000803, 0x0000000000201000],
+    [0x0000000000000000, 0x0020000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0800000000000000, 0x0000000000000000],
+    [0x0000000040000000, 0x0000000000000000, 0x0000000000002000],
+    [0x1000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x1000000000000000, 0x0000000010000008, 0x0000000000000000],
+    [0x0000000000000000, 0x0100000000000000, 0x0000000000000000],
+    [0x0100000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0100000000008000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000200000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000800],
+    [0x0000000000000000, 0x0000000000080000, 0x0000000000000000],
+    [0x0000000000000008, 0x0000000200000000, 0x0000000000000000],
+    [0x0000000000000008, 0x0000000000000000, 0x0000000000020000],
+    [0x0000000000000000, 0x0000000000000080, 0x0000000000000000],
+    [0x000000000C000000, 0x0000000000000080, 0x0000000000000000],
+    [0x0000000004000000, 0x0000000000000080, 0x0000000000000000],
+    [0x0000000004000000, 0x00000000000000C0, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000200, 0x0000000000000000],
+    [0x0000000000040000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000001000008, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000008000000000, 0x0000000000000000],
+    [0x0000010000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000020000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000800000],
+    [0x0000000000000000, 0x0000040000000000, 0x0000000000000000],
+    [0x0000000020000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x2000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0002000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0001000000000000, 0x0000000000000000],
+    [0x0000000800000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000080000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000002000000],
+    [0x0000000000000000, 0x0000000000000040, 0x0000000000000000],
+    [0x0000000004000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0020000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0008000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000020000000, 0x0000000000000000],
+    [0x0004000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0040000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000400, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000400000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000200000, 0x0000000000000000],
+    [0x2000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000100000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000010000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000008000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000400000008000, 0x0000000000000000],
+    [0x0000000000000020, 0x0000000000000000, 0x0000000000000000],
+    [0x0080000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0040000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0080000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000200000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000004000000000, 0x0000000000000000],
+    [0x0001000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000010000000],
+    [0x0000000000000000, 0x0000080000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000002],
+    [0x0000000000000000, 0x0000400000000000, 0x0000000000000000],
+    [0x0000000000800000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000001000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000002000, 0x0000000000000000, 0x0000000000000000],
+    [0x0200000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000004],
+    [0x0000000000100000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000800, 0x0000000000000000],
+    [0x0000000000000000, 0x1000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000001, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000008000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000002, 0x0000000000000000],
+    [0x0000020000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000100000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000200000],
+    [0x0000000000000000, 0x4000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000001000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000001000],
+    [0x0000000000000002, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000100000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000008000000],
+    [0x0000000080000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000040000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000000008],
+    [0x0000000000000000, 0x0010000000000000, 0x0000000000000000],
+    [0x0000000000000800, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000010000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000020000, 0x0000000000000000],
+    [0x0000100000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000001000, 0x0000000000000000],
+    [0x0000000002000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000008000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000400000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000004, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000004000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000004000],
+    [0x0000000000000100, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0004000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000040000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000800000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000008000],
+    [0x0000000000000000, 0x0000000400000000, 0x0000000000000000],
+    [0x4000000000000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000200000000, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x8000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000800000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000000400000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000004000000],
+    [0x0000000000000000, 0x0000000000100000, 0x0000000000000000],
+    [0x0000000000000001, 0x0000000000000000, 0x0000000000000000],
+    [0x0000000000000000, 0x0000000000000000, 0x0000000400000000],
+];
+
+#[rustfmt::skip]
+pub(super) const SCRIPT_EXTENSIONS: &[(u32, u32, u8)] = &[
+    (0x0, 0x40, 0), (0x41, 0x5A, 1), (0x5B, 0x60, 0), (0x61, 0x7A, 1),
+    (0x7B, 0xA9, 0), (0xAA, 0xAA, 1), (0xAB, 0xB9, 0), (0xBA, 0xBA, 1),
+    (0xBB, 0xBF, 0), (0xC0, 0xD6, 1), (0xD7, 0xD7, 0), (0xD8, 0xF6, 1),
+    (0xF7, 0xF7, 0), (0xF8, 0x2B8, 1), (0x2B9, 0x2DF, 0), (0x2E0, 0x2E4, 1),
+    (0x2E5, 0x2E9, 0), (0x2EA, 0x2EB, 2), (0x2EC, 0x341, 0), (0x342, 0x342, 3),
+    (0x343, 0x344, 0), (0x345, 0x345, 3), (0x346, 0x362, 0), (0x363, 0x36F, 1),
+    (0x370, 0x373, 3), (0x374, 0x374, 0), (0x375, 0x377, 3), (0x37A, 0x37D, 3),
+    (0x37E, 0x37E, 0), (0x37F, 0x37F, 3), (0x384, 0x384, 3), (0x385, 0x385, 0),
+    (0x386, 0x386, 3), (0x387, 0x387, 0), (0x388, 0x38A, 3), (0x38C, 0x38C, 3),
+    (0x38E, 0x3A1, 3), (0x3A3, 0x3E1, 3), (0x3E2, 0x3EF, 4), (0x3F0, 0x3FF, 3),
+    (0x400, 0x482, 5), (0x483, 0x483, 6), (0x484, 0x484, 7), (0x485, 0x486, 8),
+    (0x487, 0x487, 7), (0x488, 0x52F, 5), (0x531, 0x556, 9), (0x559, 0x58A, 9),
+    (0x58D, 0x58F, 9), (0x591, 0x5C7, 10), (0x5D0, 0x5EA, 10), (0x5EF, 0x5F4, 10),
+    (0x600, 0x604, 11), (0x605, 0x605, 0), (0x606, 0x60B, 11), (0x60C, 0x60C, 12),
+    (0x60D, 0x61A, 11), (0x61B, 0x61B, 12), (0x61C, 0x61C, 13), (0x61D, 0x61E, 11),
+    (0x61F, 0x61F, 14), (0x620, 0x63F, 11), (0x640, 0x640, 15), (0x641, 0x64A, 11),
+    (0x64B, 0x655, 16), (0x656, 0x65F, 11), (0x660, 0x669, 17), (0x66A, 0x66F, 11),
+    (0x670, 0x670, 16), (0x671, 0x6D3, 11), (0x6D4, 0x6D4, 18), (0x6D5, 0x6DC, 11),
+    (0x6DD, 0x6DD, 0), (0x6DE, 0x6FF, 11), (0x700, 0x70D, 19), (0x70F, 0x74A, 19),
+    (0x74D, 0x74F, 19), (0x750, 0x77F, 11), (0x780, 0x7B1, 20), (0x7C0, 0x7FA, 21),
+    (0x7FD, 0x7FF, 21), (0x800, 0x82D, 22), (0x830, 0x83E, 22), (0x840, 0x85B, 23),
+    (0x85E, 0x85E, 23), (0x860, 0x86A, 19), (0x870, 0x88E, 11), (0x890, 0x891, 11),
+    (0x898, 0x8E1, 11), (0x8E2, 0x8E2, 0), (0x8E3, 0x8FF, 11), (0x900, 0x950, 24),
+    (0x951, 0x951, 25), (0x952, 0x952, 26), (0x953, 0x954, 0), (0x955, 0x963, 24),
+    (0x964, 0x964, 27), (0x965, 0x965, 28), (0x966, 0x96F, 29), (0x970, 0x97F, 24),
+    (0x980, 0x983, 30), (0x985, 0x98C, 30), (0x98F, 0x990, 30), (0x993, 0x9A8, 30),
+    (0x9AA, 0x9B0, 30), (0x9B2, 0x9B2, 30), (0x9B6, 0x9B9, 30), (0x9BC, 0x9C4, 30),
+    (0x9C7, 0x9C8, 30), (0x9CB, 0x9CE, 30), (0x9D7, 0x9D7, 30), (0x9DC, 0x9DD, 30),
+    (0x9DF, 0x9E3, 30), (0x9E6, 0x9EF, 31), (0x9F0, 0x9FE, 30), (0xA01, 0xA03, 32),
+    (0xA05, 0xA0A, 32), (0xA0F, 0xA10, 32), (0xA13, 0xA28, 32), (0xA2A, 0xA30, 32),
+    (0xA32, 0xA33, 32), (0xA35, 0xA36, 32), (0xA38, 0xA39, 32), (0xA3C, 0xA3C, 32),
+    (0xA3E, 0xA42, 32), (0xA47, 0xA48, 32), (0xA4B, 0xA4D, 32), (0xA51, 0xA51, 32),
+    (0xA59, 0xA5C, 32), (0xA5E, 0xA5E, 32), (0xA66, 0xA6F, 33), (0xA70, 0xA76, 32),
+    (0xA81, 0xA83, 34), (0xA85, 0xA8D, 34), (0xA8F, 0xA91, 34), (0xA93, 0xAA8, 34),
+    (0xAAA, 0xAB0, 34), (0xAB2, 0xAB3, 34), (0xAB5, 0xAB9, 34), (0xABC, 0xAC5, 34),
+    (0xAC7, 0xAC9, 34), (0xACB, 0xACD, 34), (0xAD0, 0xAD0, 34), (0xAE0, 0xAE3, 34),
+    (0xAE6, 0xAEF, 35), (0xAF0, 0xAF1, 34), (0xAF9, 0xAFF, 34), (0xB01, 0xB03, 36),
+    (0xB05, 0xB0C, 36), (0xB0F, 0xB10, 36), (0xB13, 0xB28, 36), (0xB2A, 0xB30, 36),
+    (0xB32, 0xB33, 36), (0xB35, 0xB39, 36), (0xB3C, 0xB44, 36), (0xB47, 0xB48, 36),
+    (0xB4B, 0xB4D, 36), (0xB55, 0xB57, 36), (0xB5C, 0xB5D, 36), (0xB5F, 0xB63, 36),
+    (0xB66, 0xB77, 36), (0xB82, 0xB83, 37), (0xB85, 0xB8A, 37), (0xB8E, 0xB90, 37),
+    (0xB92, 0xB95, 37), (0xB99, 0xB9A, 37), (0xB9C, 0xB9C, 37), (0xB9E, 0xB9F, 37),
+    (0xBA3, 0xBA4, 37), (0xBA8, 0xBAA, 37), (0xBAE, 0xBB9, 37), (0xBBE, 0xBC2, 37),
+    (0xBC6, 0xBC8, 37), (0xBCA, 0xBCD, 37), (0xBD0, 0xBD0, 37), (0xBD7, 0xBD7, 37),
+    (0xBE6, 0xBF3, 38), (0xBF4, 0xBFA, 37), (0xC00, 0xC0C, 39), (0xC0E, 0xC10, 39),
+    (0xC12, 0xC28, 39), (0xC2A, 0xC39, 39), (0xC3C, 0xC44, 39), (0xC46, 0xC48, 39),
+    (0xC4A, 0xC4D, 39), (0xC55, 0xC56, 39), (0xC58, 0xC5A, 39), (0xC5D, 0xC5D, 39),
+    (0xC60, 0xC63, 39), (0xC66, 0xC6F, 39), (0xC77, 0xC7F, 39), (0xC80, 0xC8C, 40),
+    (0xC8E, 0xC90, 40), (0xC92, 0xCA8, 40), (0xCAA, 0xCB3, 40), (0xCB5, 0xCB9, 40),
+    (0xCBC, 0xCC4, 40), (0xCC6, 0xCC8, 40), (0xCCA, 0xCCD, 40), (0xCD5, 0xCD6, 40),
+    (0xCDD, 0xCDE, 40), (0xCE0, 0xCE3, 40), (0xCE6, 0xCEF, 41), (0xCF1, 0xCF2, 40),
+    (0xD00, 0xD0C, 42), (0xD0E, 0xD10, 42), (0xD12, 0xD44, 42), (0xD46, 0xD48, 42),
+    (0xD4A, 0xD4F, 42), (0xD54, 0xD63, 42), (0xD66, 0xD7F, 42), (0xD81, 0xD83, 43),
+    (0xD85, 0xD96, 43), (0xD9A, 0xDB1, 43), (0xDB3, 0xDBB, 43), (0xDBD, 0xDBD, 43),
+    (0xDC0, 0xDC6, 43), (0xDCA, 0xDCA, 43), (0xDCF, 0xDD4, 43), (0xDD6, 0xDD6, 43),
+    (0xDD8, 0xDDF, 43), (0xDE6, 0xDEF, 43), (0xDF2, 0xDF4, 43), (0xE01, 0xE3A, 44),
+    (0xE3F, 0xE3F, 0), (0xE40, 0xE5B, 44), (0xE81, 0xE82, 45), (0xE84, 0xE84, 45),
+    (0xE86, 0xE8A, 45), (0xE8C, 0xEA3, 45), (0xEA5, 0xEA5, 45), (0xEA7, 0xEBD, 45),
+    (0xEC0, 0xEC4, 45), (0xEC6, 0xEC6, 45), (0xEC8, 0xECD, 45), (0xED0, 0xED9, 45),
+    (0xEDC, 0xEDF, 45), (0xF00, 0xF47, 46), (0xF49, 0xF6C, 46), (0xF71, 0xF97, 46),
+    (0xF99, 0xFBC, 46), (0xFBE, 0xFCC, 46), (0xFCE, 0xFD4, 46), (0xFD5, 0xFD8, 0),
+    (0xFD9, 0xFDA, 46), (0x1000, 0x103F, 47), (0x1040, 0x1049, 48), (0x104A, 0x109F, 47),
+    (0x10A0, 0x10C5, 49), (0x10C7, 0x10C7, 49), (0x10CD, 0x10CD, 49), (0x10D0, 0x10FA, 49),
+    (0x10FB, 0x10FB, 50), (0x10FC, 0x10FF, 49), (0x1100, 0x11FF, 51), (0x1200, 0x1248, 52),
+    (0x124A, 0x124D, 52), (0x1250, 0x1256, 52), (0x1258, 0x1258, 52), (0x125A, 0x125D, 52),
+    (0x1260, 0x1288, 52), (0x128A, 0x128D, 52), (0x1290, 0x12B0, 52), (0x12B2, 0x12B5, 52),
+    (0x12B8, 0x12BE, 52), (0x12C0, 0x12C0, 52), (0x12C2, 0x12C5, 52), (0x12C8, 0x12D6, 52),
+    (0x12D8, 0x1310, 52), (0x1312, 0x1315, 52), (0x1318, 0x135A, 52), (0x135D, 0x137C, 52),
+    (0x1380, 0x1399, 52), (0x13A0, 0x13F5, 53), (0x13F8, 0x13FD, 53), (0x1400, 0x167F, 54),
+    (0x1680, 0x169C, 55), (0x16A0, 0x16EA, 56), (0x16EB, 0x16ED, 0), (0x16EE, 0x16F8, 56),
+    (0x1700, 0x1715, 57), (0x171F, 0x171F, 57), (0x1720, 0x1734, 58), (0x1735, 0x1736, 59),
+    (0x1740, 0x1753, 60), (0x1760, 0x176C, 61), (0x176E, 0x1770, 61), (0x1772, 0x1773, 61),
+    (0x1780, 0x17DD, 62), (0x17E0, 0x17E9, 62), (0x17F0, 0x17F9, 62), (0x1800, 0x1801, 63),
+    (0x1802, 0x1803, 64), (0x1804, 0x1804, 63), (0x1805, 0x1805, 64), (0x1806, 0x1819, 63),
+    (0x1820, 0x1878, 63), (0x1880, 0x18AA, 63), (0x18B0, 0x18F5, 54), (0x1900, 0x191E, 65),
+    (0x1920, 0x192B, 65), (0x1930, 0x193B, 65), (0x1940, 0x1940, 65), (0x1944, 0x194F, 65),
+    (0x1950, 0x196D, 66), (0x1970, 0x1974, 66), (0x1980, 0x19AB, 67), (0x19B0, 0x19C9, 67),
+    (0x19D0, 0x19DA, 67), (0x19DE, 0x19DF, 67), (0x19E0, 0x19FF, 62), (0x1A00, 0x1A1B, 68),
+    (0x1A1E, 0x1A1F, 68), (0x1A20, 0x1A5E, 69), (0x1A60, 0x1A7C, 69), (0x1A7F, 0x1A89, 69),
+    (0x1A90, 0x1A99, 69), (0x1AA0, 0x1AAD, 69), (0x1AB0, 0x1ACE, 0), (0x1B00, 0x1B4C, 70),
+    (0x1B50, 0x1B7E, 70), (0x1B80, 0x1BBF, 71), (0x1BC0, 0x1BF3, 72), (0x1BFC, 0x1BFF, 72),
+    (0x1C00, 0x1C37, 73), (0x1C3B, 0x1C49, 73), (0x1C4D, 0x1C4F, 73), (0x1C50, 0x1C7F, 74),
+    (0x1C80, 0x1C88, 5), (0x1C90, 0x1CBA, 49), (0x1CBD, 0x1CBF, 49), (0x1CC0, 0x1CC7, 71),
+    (0x1CD0, 0x1CD0, 75), (0x1CD1, 0x1CD1, 24), (0x1CD2, 0x1CD2, 75), (0x1CD3, 0x1CD3, 76),
+    (0x1CD4, 0x1CD4, 24), (0x1CD5, 0x1CD6, 77), (0x1CD7, 0x1CD7, 78), (0x1CD8, 0x1CD8, 77),
+    (0x1CD9, 0x1CD9, 78), (0x1CDA, 0x1CDA, 79), (0x1CDB, 0x1CDB, 24), (0x1CDC, 0x1CDD, 78),
+    (0x1CDE, 0x1CDF, 24), (0x1CE0, 0x1CE0, 78), (0x1CE1, 0x1CE1, 77), (0x1CE2, 0x1CE8, 24),
+    (0x1CE9, 0x1CE9, 80), (0x1CEA, 0x1CEA, 77), (0x1CEB, 0x1CEC, 24), (0x1CED, 0x1CED, 77),
+    (0x1CEE, 0x1CF1, 24), (0x1CF2, 0x1CF2, 81), (0x1CF3, 0x1CF3, 76), (0x1CF4, 0x1CF4, 82),
+    (0x1CF5, 0x1CF6, 77), (0x1CF7, 0x1CF7, 30), (0x1CF8, 0x1CF9, 76), (0x1CFA, 0x1CFA, 83),
+    (0x1D00, 0x1D25, 1), (0x1D26, 0x1D2A, 3), (0x1D2B, 0x1D2B, 5), (0x1D2C, 0x1D5C, 1),
+    (0x1D5D, 0x1D61, 3), (0x1D62, 0x1D65, 1), (0x1D66, 0x1D6A, 3), (0x1D6B, 0x1D77, 1),
+    (0x1D78, 0x1D78, 5), (0x1D79, 0x1DBE, 1), (0x1DBF, 0x1DC1, 3), (0x1DC2, 0x1DF7, 0),
+    (0x1DF8, 0x1DF8, 84), (0x1DF9, 0x1DF9, 0), (0x1DFA, 0x1DFA, 19), (0x1DFB, 0x1DFF, 0),
+    (0x1E00, 0x1EFF, 1), (0x1F00, 0x1F15, 3), (0x1F18, 0x1F1D, 3), (0x1F20, 0x1F45, 3),
+    (0x1F48, 0x1F4D, 3), (0x1F50, 0x1F57, 3), (0x1F59, 0x1F59, 3), (0x1F5B, 0x1F5B, 3),
+    (0x1F5D, 0x1F5D, 3), (0x1F5F, 0x1F7D, 3), (0x1F80, 0x1FB4, 3), (0x1FB6, 0x1FC4, 3),
+    (0x1FC6, 0x1FD3, 3), (0x1FD6, 0x1FDB, 3), (0x1FDD, 0x1FEF, 3), (0x1FF2, 0x1FF4, 3),
+    (0x1FF6, 0x1FFE, 3), (0x2000, 0x202E, 0), (0x202F, 0x202F, 85), (0x2030, 0x2064, 0),
+    (0x2066, 0x2070, 0), (0x2071, 0x2071, 1), (0x2074, 0x207E, 0), (0x207F, 0x207F, 1),
+    (0x2080, 0x208E, 0), (0x2090, 0x209C, 1), (0x20A0, 0x20C0, 0), (0x20D0, 0x20EF, 0),
+    (0x20F0, 0x20F0, 86), (0x2100, 0x2125, 0), (0x2126, 0x2126, 3), (0x2127, 0x2129, 0),
+    (0x212A, 0x212B, 1), (0x212C, 0x2131, 0), (0x2132, 0x2132, 1), (0x2133, 0x214D, 0),
+    (0x214E, 0x214E, 1), (0x214F, 0x215F, 0), (0x2160, 0x2188, 1), (0x2189, 0x218B, 0),
+    (0x2190, 0x2426, 0), (0x2440, 0x244A, 0), (0x2460, 0x27FF, 0), (0x2800, 0x28FF, 87),
+    (0x2900, 0x2B73, 0), (0x2B76, 0x2B95, 0), (0x2B97, 0x2BFF, 0), (0x2C00, 0x2C5F, 88),
+    (0x2C60, 0x2C7F, 1), (0x2C80, 0x2CF3, 4), (0x2CF9, 0x2CFF, 4), (0x2D00, 0x2D25, 49),
+    (0x2D27, 0x2D27, 49), (0x2D2D, 0x2D2D, 49), (0x2D30, 0x2D67, 89), (0x2D6F, 0x2D70, 89),
+    (0x2D7F, 0x2D7F, 89), (0x2D80, 0x2D96, 52), (0x2DA0, 0x2DA6, 52), (0x2DA8, 0x2DAE, 52),
+    (0x2DB0, 0x2DB6, 52), (0x2DB8, 0x2DBE, 52), (0x2DC0, 0x2DC6, 52), (0x2DC8, 0x2DCE, 52),
+    (0x2DD0, 0x2DD6, 52), (0x2DD8, 0x2DDE, 52), (0x2DE0, 0x2DFF, 5), (0x2E00, 0x2E42, 0),
+    (0x2E43, 0x2E43, 7), (0x2E44, 0x2E5D, 0), (0x2E80, 0x2E99, 90), (0x2E9B, 0x2EF3, 90),
+    (0x2F00, 0x2FD5, 90), (0x2FF0, 0x2FFB, 0), (0x3000, 0x3000, 0), (0x3001, 0x3002, 91),
+    (0x3003, 0x3003, 92), (0x3004, 0x3004, 0), (0x3005, 0x3007, 90), (0x3008, 0x3011, 91),
+    (0x3012, 0x3012, 0), (0x3013, 0x3013, 92), (0x3014, 0x301B, 91), (0x301C, 0x301F, 92),
+    (0x3020, 0x3020, 0), (0x3021, 0x3029, 90), (0x302A, 0x302D, 93), (0x302E, 0x302F, 51),
+    (0x3030, 0x3030, 92), (0x3031, 0x3035, 94), (0x3036, 0x3036, 0), (0x3037, 0x3037, 92),
+    (0x3038, 0x303B, 90), (0x303C, 0x303D, 95), (0x303E, 0x303F, 90), (0x3041, 0x3096, 96),
+    (0x3099, 0x309C, 94), (0x309D, 0x309F, 96), (0x30A0, 0x30A0, 94), (0x30A1, 0x30FA, 97),
+    (0x30FB, 0x30FB, 91), (0x30FC, 0x30FC, 94), (0x30FD, 0x30FF, 97), (0x3105, 0x312F, 2),
+    (0x3131, 0x318E, 51), (0x3190, 0x319F, 90), (0x31A0, 0x31BF, 2), (0x31C0, 0x31E3, 90),
+    (0x31F0, 0x31FF, 97), (0x3200, 0x321E, 51), (0x3220, 0x3247, 90), (0x3248, 0x325F, 0),
+    (0x3260, 0x327E, 51), (0x327F, 0x327F, 0), (0x3280, 0x32B0, 90), (0x32B1, 0x32BF, 0),
+    (0x32C0, 0x32CB, 90), (0x32CC, 0x32CF, 0), (0x32D0, 0x32FE, 97), (0x32FF, 0x32FF, 90),
+    (0x3300, 0x3357, 97), (0x3358, 0x3370, 90), (0x3371, 0x337A, 0), (0x337B, 0x337F, 90),
+    (0x3380, 0x33DF, 0), (0x33E0, 0x33FE, 90), (0x33FF, 0x33FF, 0), (0x3400, 0x4DBF, 90),
+    (0x4DC0, 0x4DFF, 0), (0x4E00, 0x9FFF, 90), (0xA000, 0xA48C, 98), (0xA490, 0xA4C6, 98),
+    (0xA4D0, 0xA4FF, 99), (0xA500, 0xA62B, 100), (0xA640, 0xA66E, 5), (0xA66F, 0xA66F, 7),
+    (0xA670, 0xA69F, 5), (0xA6A0, 0xA6F7, 101), (0xA700, 0xA707, 102), (0xA708, 0xA721, 0),
+    (0xA722, 0xA787, 1), (0xA788, 0xA78A, 0), (0xA78B, 0xA7CA, 1), (0xA7D0, 0xA7D1, 1),
+    (0xA7D3, 0xA7D3, 1), (0xA7D5, 0xA7D9, 1), (0xA7F2, 0xA7FF, 1), (0xA800, 0xA82C, 103),
+    (0xA830, 0xA832, 104), (0xA833, 0xA835, 105), (0xA836, 0xA839, 106), (0xA840, 0xA877, 107),
+    (0xA880, 0xA8C5, 108), (0xA8CE, 0xA8D9, 108), (0xA8E0, 0xA8F0, 24), (0xA8F1, 0xA8F1, 77),
+    (0xA8F2, 0xA8F2, 24), (0xA8F3, 0xA8F3, 109), (0xA8F4, 0xA8FF, 24), (0xA900, 0xA92D, 110),
+    (0xA92E, 0xA92E, 111), (0xA92F, 0xA92F, 110), (0xA930, 0xA953, 112), (0xA95F, 0xA95F, 112),
+    (0xA960, 0xA97C, 51), (0xA980, 0xA9CD, 113), (0xA9CF, 0xA9CF, 114), (0xA9D0, 0xA9D9, 113),
+    (0xA9DE, 0xA9DF, 113), (0xA9E0, 0xA9FE, 47), (0xAA00, 0xAA36, 115), (0xAA40, 0xAA4D, 115),
+    (0xAA50, 0xAA59, 115), (0xAA5C, 0xAA5F, 115), (0xAA60, 0xAA7F, 47), (0xAA80, 0xAAC2, 116),
+    (0xAADB, 0xAADF, 116), (0xAAE0, 0xAAF6, 117), (0xAB01, 0xAB06, 52), (0xAB09, 0xAB0E, 52),
+    (0xAB11, 0xAB16, 52), (0xAB20, 0xAB26, 52), (0xAB28, 0xAB2E, 52), (0xAB30, 0xAB5A, 1),
+    (0xAB5B, 0xAB5B, 0), (0xAB5C, 0xAB64, 1), (0xAB65, 0xAB65, 3), (0xAB66, 0xAB69, 1),
+    (0xAB6A, 0xAB6B, 0), (0xAB70, 0xABBF, 53), (0xABC0, 0xABED, 117), (0xABF0, 0xABF9, 117),
+    (0xAC00, 0xD7A3, 51), (0xD7B0, 0xD7C6, 51), (0xD7CB, 0xD7FB, 51), (0xF900, 0xFA6D, 90),
+    (0xFA70, 0xFAD9, 90), (0xFB00, 0xFB06, 1), (0xFB13, 0xFB17, 9), (0xFB1D, 0xFB36, 10),
+    (0xFB38, 0xFB3C, 10), (0xFB3E, 0xFB3E, 10), (0xFB40, 0xFB41, 10), (0xFB43, 0xFB44, 10),
+    (0xFB46, 0xFB4F, 10), (0xFB50, 0xFBC2, 11), (0xFBD3, 0xFD3D, 11), (0xFD3E, 0xFD3F, 118),
+    (0xFD40, 0xFD8F, 11), (0xFD92, 0xFDC7, 11), (0xFDCF, 0xFDCF, 11), (0xFDF0, 0xFDF1, 11),
+    (0xFDF2, 0xFDF2, 119), (0xFDF3, 0xFDFC, 11), (0xFDFD, 0xFDFD, 119), (0xFDFE, 0xFDFF, 11),
+    (0xFE00, 0xFE19, 0), (0xFE20, 0xFE2D, 0), (0xFE2E, 0xFE2F, 5), (0xFE30, 0xFE44, 0),
+    (0xFE45, 0xFE46, 92), (0xFE47, 0xFE52, 0), (0xFE54, 0xFE66, 0), (0xFE68, 0xFE6B, 0),
+    (0xFE70, 0xFE74, 11), (0xFE76, 0xFEFC, 11), (0xFEFF, 0xFEFF, 0), (0xFF01, 0xFF20, 0),
+    (0xFF21, 0xFF3A, 1), (0xFF3B, 0xFF40, 0), (0xFF41, 0xFF5A, 1), (0xFF5B, 0xFF60, 0),
+    (0xFF61, 0xFF65, 91), (0xFF66, 0xFF6F, 97), (0xFF70, 0xFF70, 94), (0xFF71, 0xFF9D, 97),
+    (0xFF9E, 0xFF9F, 94), (0xFFA0, 0xFFBE, 51), (0xFFC2, 0xFFC7, 51), (0xFFCA, 0xFFCF, 51),
+    (0xFFD2, 0xFFD7, 51), (0xFFDA, 0xFFDC, 51), (0xFFE0, 0xFFE6, 0), (0xFFE8, 0xFFEE, 0),
+    (0xFFF9, 0xFFFD, 0), (0x10000, 0x1000B, 120), (0x1000D, 0x10026, 120), (0x10028, 0x1003A, 120),
+    (0x1003C, 0x1003D, 120), (0x1003F, 0x1004D, 120), (0x10050, 0x1005D, 120), (0x10080, 0x100FA, 120),
+    (0x10100, 0x10101, 121), (0x10102, 0x10102, 122), (0x10107, 0x10133, 123), (0x10137, 0x1013F, 122),
+    (0x10140, 0x1018E, 3), (0x10190, 0x1019C, 0), (0x101A0, 0x101A0, 3), (0x101D0, 0x101FD, 0),
+    (0x10280, 0x1029C, 124), (0x102A0, 0x102D0, 125), (0x102E0, 0x102FB, 126), (0x10300, 0x10323, 127),
+    (0x1032D, 0x1032F, 127), (0x10330, 0x1034A, 128), (0x10350, 0x1037A, 129), (0x10380, 0x1039D, 130),
+    (0x1039F, 0x1039F, 130), (0x103A0, 0x103C3, 131), (0x103C8, 0x103D5, 131), (0x10400, 0x1044F, 132),
+    (0x10450, 0x1047F, 133), (0x10480, 0x1049D, 134), (0x104A0, 0x104A9, 134), (0x104B0, 0x104D3, 135),
+    (0x104D8, 0x104FB, 135), (0x10500, 0x10527, 136), (0x10530, 0x10563, 137), (0x1056F, 0x1056F, 137),
+    (0x10570, 0x1057A, 138), (0x1057C, 0x1058A, 138), (0x1058C, 0x10592, 138), (0x10594, 0x10595, 138),
+    (0x10597, 0x105A1, 138), (0x105A3, 0x105B1, 138), (0x105B3, 0x105B9, 138), (0x105BB, 0x105BC, 138),
+    (0x10600, 0x10736, 139), (0x10740, 0x10755, 139), (0x10760, 0x10767, 139), (0x10780, 0x10785, 1),
+    (0x10787, 0x107B0, 1), (0x107B2, 0x107BA, 1), (0x10800, 0x10805, 140), (0x10808, 0x10808, 140),
+    (0x1080A, 0x10835, 140), (0x10837, 0x10838, 140), (0x1083C, 0x1083C, 140), (0x1083F, 0x1083F, 140),
+    (0x10840, 0x10855, 141), (0x10857, 0x1085F, 141), (0x10860, 0x1087F, 142), (0x10880, 0x1089E, 143),
+    (0x108A7, 0x108AF, 143), (0x108E0, 0x108F2, 144), (0x108F4, 0x108F5, 144), (0x108FB, 0x108FF, 144),
+    (0x10900, 0x1091B, 145), (0x1091F, 0x1091F, 145), (0x10920, 0x10939, 146), (0x1093F, 0x1093F, 146),
+    (0x10980, 0x1099F, 147), (0x109A0, 0x109B7, 148), (0x109BC, 0x109CF, 148), (0x109D2, 0x109FF, 148),
+    (0x10A00, 0x10A03, 149), (0x10A05, 0x10A06, 149), (0x10A0C, 0x10A13, 149), (0x10A15, 0x10A17, 149),
+    (0x10A19, 0x10A35, 149), (0x10A38, 0x10A3A, 149), (0x10A3F, 0x10A48, 149), (0x10A50, 0x10A58, 149),
+    (0x10A60, 0x10A7F, 150), (0x10A80, 0x10A9F, 151), (0x10AC0, 0x10AE6, 152), (0x10AEB, 0x10AF1, 152),
+    (0x10AF2, 0x10AF2, 153), (0x10AF3, 0x10AF6, 152), (0x10B00, 0x10B35, 154), (0x10B39, 0x10B3F, 154),
+    (0x10B40, 0x10B55, 155), (0x10B58, 0x10B5F, 155), (0x10B60, 0x10B72, 156), (0x10B78, 0x10B7F, 156),
+    (0x10B80, 0x10B91, 157), (0x10B99, 0x10B9C, 157), (0x10BA9, 0x10BAF, 157), (0x10C00, 0x10C48, 158),
+    (0x10C80, 0x10CB2, 159), (0x10CC0, 0x10CF2, 159), (0x10CFA, 0x10CFF, 159), (0x10D00, 0x10D27, 160),
+    (0x10D30, 0x10D39, 160), (0x10E60, 0x10E7E, 11), (0x10E80, 0x10EA9, 161), (0x10EAB, 0x10EAD, 161),
+    (0x10EB0, 0x10EB1, 161), (0x10F00, 0x10F27, 162), (0x10F30, 0x10F59, 163), (0x10F70, 0x10F89, 164),
+    (0x10FB0, 0x10FCB, 165), (0x10FE0, 0x10FF6, 166), (0x11000, 0x1104D, 167), (0x11052, 0x11075, 167),
+    (0x1107F, 0x1107F, 167), (0x11080, 0x110C2, 168), (0x110CD, 0x110CD, 168), (0x110D0, 0x110E8, 169),
+    (0x110F0, 0x110F9, 169), (0x11100, 0x11134, 170), (0x11136, 0x11147, 170), (0x11150, 0x11176, 171),
+    (0x11180, 0x111DF, 172), (0x111E1, 0x111F4, 43), (0x11200, 0x11211, 173), (0x11213, 0x1123E, 173),
+    (0x11280, 0x11286, 174), (0x11288, 0x11288, 174), (0x1128A, 0x1128D, 174), (0x1128F, 0x1129D, 174),
+    (0x1129F, 0x112A9, 174), (0x112B0, 0x112EA, 175), (0x112F0, 0x112F9, 175), (0x11300, 0x11300, 176),
+    (0x11301, 0x11301, 38), (0x11302, 0x11302, 176), (0x11303, 0x11303, 38), (0x11305, 0x1130C, 176),
+    (0x1130F, 0x11310, 176), (0x11313, 0x11328, 176), (0x1132A, 0x11330, 176), (0x11332, 0x11333, 176),
+    (0x11335, 0x11339, 176), (0x1133B, 0x1133C, 38), (0x1133D, 0x11344, 176), (0x11347, 0x11348, 176),
+    (0x1134B, 0x1134D, 176), (0x11350, 0x11350, 176), (0x11357, 0x11357, 176), (0x1135D, 0x11363, 176),
+    (0x11366, 0x1136C, 176), (0x11370, 0x11374, 176), (0x11400, 0x1145B, 177), (0x1145D, 0x11461, 177),
+    (0x11480, 0x114C7, 178), (0x114D0, 0x114D9, 178), (0x11580, 0x115B5, 179), (0x115B8, 0x115DD, 179),
+    (0x11600, 0x11644, 180), (0x11650, 0x11659, 180), (0x11660, 0x1166C, 63), (0x11680, 0x116B9, 181),
+    (0x116C0, 0x116C9, 181), (0x11700, 0x1171A, 182), (0x1171D, 0x1172B, 182), (0x11730, 0x11746, 182),
+    (0x11800, 0x1183B, 183), (0x118A0, 0x118F2, 184), (0x118FF, 0x118FF, 184), (0x11900, 0x11906, 185),
+    (0x11909, 0x11909, 185), (0x1190C, 0x11913, 185), (0x11915, 0x11916, 185), (0x11918, 0x11935, 185),
+    (0x11937, 0x11938, 185), (0x1193B, 0x11946, 185), (0x11950, 0x11959, 185), (0x119A0, 0x119A7, 83),
+    (0x119AA, 0x119D7, 83), (0x119DA, 0x119E4, 83), (0x11A00, 0x11A47, 186), (0x11A50, 0x11AA2, 187),
+    (0x11AB0, 0x11ABF, 54), (0x11AC0, 0x11AF8, 188), (0x11C00, 0x11C08, 189), (0x11C0A, 0x11C36, 189),
+    (0x11C38, 0x11C45, 189), (0x11C50, 0x11C6C, 189), (0x11C70, 0x11C8F, 190), (0x11C92, 0x11CA7, 190),
+    (0x11CA9, 0x11CB6, 190), (0x11D00, 0x11D06, 191), (0x11D08, 0x11D09, 191), (0x11D0B, 0x11D36, 191),
+    (0x11D3A, 0x11D3A, 191), (0x11D3C, 0x11D3D, 191), (0x11D3F, 0x11D47, 191), (0x11D50, 0x11D59, 191),
+    (0x11D60, 0x11D65, 192), (0x11D67, 0x11D68, 192), (0x11D6A, 0x11D8E, 192), (0x11D90, 0x11D91, 192),
+    (0x11D93, 0x11D98, 192), (0x11DA0, 0x11DA9, 192), (0x11EE0, 0x11EF8, 193), (0x11FB0, 0x11FB0, 99),
+    (0x11FC0, 0x11FCF, 37), (0x11FD0, 0x11FD1, 38), (0x11FD2, 0x11FD2, 37), (0x11FD3, 0x11FD3, 38),
+    (0x11FD4, 0x11FF1, 37), (0x11FFF, 0x11FFF, 37), (0x12000, 0x12399, 194), (0x12400, 0x1246E, 194),
+    (0x12470, 0x12474, 194), (0x12480, 0x12543, 194), (0x12F90, 0x12FF2, 195), (0x13000, 0x1342E, 196),
+    (0x13430, 0x13438, 196), (0x14400, 0x14646, 197), (0x16800, 0x16A38, 101), (0x16A40, 0x16A5E, 198),
+    (0x16A60, 0x16A69, 198), (0x16A6E, 0x16A6F, 198), (0x16A70, 0x16ABE, 199), (0x16AC0, 0x16AC9, 199),
+    (0x16AD0, 0x16AED, 200), (0x16AF0, 0x16AF5, 200), (0x16B00, 0x16B45, 201), (0x16B50, 0x16B59, 201),
+    (0x16B5B, 0x16B61, 201), (0x16B63, 0x16B77, 201), (0x16B7D, 0x16B8F, 201), (0x16E40, 0x16E9A, 202),
+    (0x16F00, 0x16F4A, 203), (0x16F4F, 0x16F87, 203), (0x16F8F, 0x16F9F, 203), (0x16FE0, 0x16FE0, 204),
+    (0x16FE1, 0x16FE1, 205), (0x16FE2, 0x16FE3, 90), (0x16FE4, 0x16FE4, 206), (0x16FF0, 0x16FF1, 90),
+    (0x17000, 0x187F7, 204), (0x18800, 0x18AFF, 204), (0x18B00, 0x18CD5, 206), (0x18D00, 0x18D08, 204),
+    (0x1AFF0, 0x1AFF3, 97), (0x1AFF5, 0x1AFFB, 97), (0x1AFFD, 0x1AFFE, 97), (0x1B000, 0x1B000, 97),
+    (0x1B001, 0x1B11F, 96), (0x1B120, 0x1B122, 97), (0x1B150, 0x1B152, 96), (0x1B164, 0x1B167, 97),
+    (0x1B170, 0x1B2FB, 205), (0x1BC00, 0x1BC6A, 207), (0x1BC70, 0x1BC7C, 207), (0x1BC80, 0x1BC88, 207),
+    (0x1BC90, 0x1BC99, 207), (0x1BC9C, 0x1BCA3, 207), (0x1CF00, 0x1CF2D, 0), (0x1CF30, 0x1CF46, 0),
+    (0x1CF50, 0x1CFC3, 0), (0x1D000, 0x1D0F5, 0), (0x1D100, 0x1D126, 0), (0x1D129, 0x1D1EA, 0),
+    (0x1D200, 0x1D245, 3), (0x1D2E0, 0x1D2F3, 0), (0x1D300, 0x1D356, 0), (0x1D360, 0x1D371, 90),
+    (0x1D372, 0x1D378, 0), (0x1D400, 0x1D454, 0), (0x1D456, 0x1D49C, 0), (0x1D49E, 0x1D49F, 0),
+    (0x1D4A2, 0x1D4A2, 0), (0x1D4A5, 0x1D4A6, 0), (0x1D4A9, 0x1D4AC, 0), (0x1D4AE, 0x1D4B9, 0),
+    (0x1D4BB, 0x1D4BB, 0), (0x1D4BD, 0x1D4C3, 0), (0x1D4C5, 0x1D505, 0), (0x1D507, 0x1D50A, 0),
+    (0x1D50D, 0x1D514, 0), (0x1D516, 0x1D51C, 0), (0x1D51E, 0x1D539, 0), (0x1D53B, 0x1D53E, 0),
+    (0x1D540, 0x1D544, 0), (0x1D546, 0x1D546, 0), (0x1D54A, 0x1D550, 0), (0x1D552, 0x1D6A5, 0),
+    (0x1D6A8, 0x1D7CB, 0), (0x1D7CE, 0x1D7FF, 0), (0x1D800, 0x1DA8B, 208), (0x1DA9B, 0x1DA9F, 208),
+    (0x1DAA1, 0x1DAAF, 208), (0x1DF00, 0x1DF1E, 1), (0x1E000, 0x1E006, 88), (0x1E008, 0x1E018, 88),
+    (0x1E01B, 0x1E021, 88), (0x1E023, 0x1E024, 88), (0x1E026, 0x1E02A, 88), (0x1E100, 0x1E12C, 209),
+    (0x1E130, 0x1E13D, 209), (0x1E140, 0x1E149, 209), (0x1E14E, 0x1E14F, 209), (0x1E290, 0x1E2AE, 210),
+    (0x1E2C0, 0x1E2F9, 211), (0x1E2FF, 0x1E2FF, 211), (0x1E7E0, 0x1E7E6, 52), (0x1E7E8, 0x1E7EB, 52),
+    (0x1E7ED, 0x1E7EE, 52), (0x1E7F0, 0x1E7FE, 52), (0x1E800, 0x1E8C4, 212), (0x1E8C7, 0x1E8D6, 212),
+    (0x1E900, 0x1E94B, 213), (0x1E950, 0x1E959, 213), (0x1E95E, 0x1E95F, 213), (0x1EC71, 0x1ECB4, 0),
+    (0x1ED01, 0x1ED3D, 0), (0x1EE00, 0x1EE03, 11), (0x1EE05, 0x1EE1F, 11), (0x1EE21, 0x1EE22, 11),
+    (0x1EE24, 0x1EE24, 11), (0x1EE27, 0x1EE27, 11), (0x1EE29, 0x1EE32, 11), (0x1EE34, 0x1EE37, 11),
+    (0x1EE39, 0x1EE39, 11), (0x1EE3B, 0x1EE3B, 11), (0x1EE42, 0x1EE42, 11), (0x1EE47, 0x1EE47, 11),
+    (0x1EE49, 0x1EE49, 11), (0x1EE4B, 0x1EE4B, 11), (0x1EE4D, 0x1EE4F, 11), (0x1EE51, 0x1EE52, 11),
+    (0x1EE54, 0x1EE54, 11), (0x1EE57, 0x1EE57, 11), (0x1EE59, 0x1EE59, 11), (0x1EE5B, 0x1EE5B, 11),
+    (0x1EE5D, 0x1EE5D, 11), (0x1EE5F, 0x1EE5F, 11), (0x1EE61, 0x1EE62, 11), (0x1EE64, 0x1EE64, 11),
+    (0x1EE67, 0x1EE6A, 11), (0x1EE6C, 0x1EE72, 11), (0x1EE74, 0x1EE77, 11), (0x1EE79, 0x1EE7C, 11),
+    (0x1EE7E, 0x1EE7E, 11), (0x1EE80, 0x1EE89, 11), (0x1EE8B, 0x1EE9B, 11), (0x1EEA1, 0x1EEA3, 11),
+    (0x1EEA5, 0x1EEA9, 11), (0x1EEAB, 0x1EEBB, 11), (0x1EEF0, 0x1EEF1, 11), (0x1F000, 0x1F02B, 0),
+    (0x1F030, 0x1F093, 0), (0x1F0A0, 0x1F0AE, 0), (0x1F0B1, 0x1F0BF, 0), (0x1F0C1, 0x1F0CF, 0),
+    (0x1F0D1, 0x1F0F5, 0), (0x1F100, 0x1F1AD, 0), (0x1F1E6, 0x1F1FF, 0), (0x1F200, 0x1F200, 96),
+    (0x1F201, 0x1F202, 0), (0x1F210, 0x1F23B, 0), (0x1F240, 0x1F248, 0), (0x1F250, 0x1F251, 90),
+    (0x1F260, 0x1F265, 0), (0x1F300, 0x1F6D7, 0), (0x1F6DD, 0x1F6EC, 0), (0x1F6F0, 0x1F6FC, 0),
+    (0x1F700, 0x1F773, 0), (0x1F780, 0x1F7D8, 0), (0x1F7E0, 0x1F7EB, 0), (0x1F7F0, 0x1F7F0, 0),
+    (0x1F800, 0x1F80B, 0), (0x1F810, 0x1F847, 0), (0x1F850, 0x1F859, 0), (0x1F860, 0x1F887, 0),
+    (0x1F890, 0x1F8AD, 0), (0x1F8B0, 0x1F8B1, 0), (0x1F900, 0x1FA53, 0), (0x1FA60, 0x1FA6D, 0),
+    (0x1FA70, 0x1FA74, 0), (0x1FA78, 0x1FA7C, 0), (0x1FA80, 0x1FA86, 0), (0x1FA90, 0x1FAAC, 0),
+    (0x1FAB0, 0x1FABA, 0), (0x1FAC0, 0x1FAC5, 0), (0x1FAD0, 0x1FAD9, 0), (0x1FAE0, 0x1FAE7, 0),
+    (0x1FAF0, 0x1FAF6, 0), (0x1FB00, 0x1FB92, 0), (0x1FB94, 0x1FBCA, 0), (0x1FBF0, 0x1FBF9, 0),
+    (0x20000, 0x2A6DF, 90), (0x2A700, 0x2B738, 90), (0x2B740, 0x2B81D, 90), (0x2B820, 0x2CEA1, 90),
+    (0x2CEB0, 0x2EBE0, 90), (0x2F800, 0x2FA1D, 90), (0x30000, 0x3134A, 90), (0xE0001, 0xE0001, 0),
+    (0xE0020, 0xE007F, 0), (0xE0100, 0xE01EF, 0),
+];
@@ -0,0 +1,330 @@
+//! A dependency-free RFC 3986 URI refiner and rules over its components.
+//!
+//! [`Uri`] accepts absolute URIs only; relative references are rejected with
+//! `missing_scheme`. Components are borrowed from the input and are not
+//! percent-decoded or otherwise normalized.
+
+use core::net::Ipv6Addr;
+
+use crate::refine::Refine;
+use crate::validate::{Rule, ValidationError};
+
+/// The borrowed components of a URI produced by [`Uri`].
+///
+/// Delimiters are excluded: `scheme` has no `:`, `query` has no `?`, and
+/// `fragment` has no `#`. `host` keeps the brackets of an IP literal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UriParts<'input> {
+    pub scheme: &'input str,
+    /// The whole authority after `//`, when present.
+    pub authority: Option<&'input str>,
+    pub user_info: Option<&'input str>,
+    pub host: Option<&'input str>,
+    /// The port digits, when a `:` follows the host. They may be empty.
+    pub port: Option<&'input str>,
+    pub path: &'input str,
+    pub query: Option<&'input str>,
+    pub fragment: Option<&'input str>,
+}
+
+fn unreserved_or_sub_delim(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric()
+        || matches!(
+            byte,
+            b'-' | b'.'
+                | b'_'
+                | b'~'
+                | b'!'
+                | b'$'
+                | b'&'
+                | b'\''
+                | b'('
+                | b')'
+                | b'*'
+                | b'+'
+                | b','
+                | b';'
+                | b'='
+        )
+}
+
+/// Check that `component` contains only bytes allowed by `allowed` and
+/// well-formed percent-encodings.
+fn valid_component(component: &str, allowed: impl Fn(u8) -> bool) -> bool {
+    let bytes = component.as_bytes();
+    let mut index = 0;
+
+    while let Some(&byte) = bytes.get(index) {
+        if byte == b'%' {
+            let encoded = bytes.get(index + 1..index + 3);
+
+            if !encoded.is_some_and(|digits| digits.iter().all(u8::is_ascii_hexdigit)) {
+                return false;
+            }
+
+            index += 3;
+        } else if unreserved_or_sub_delim(byte) || allowed(byte) {
+            index += 1;
+        } else {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn pchar(byte: u8) -> bool {
+    matches!(byte, b':' | b'@')
+}
+
+fn valid_host(host: &str) -> bool {
+    match host
+        .strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+    {
+        Some(literal) => match literal.strip_prefix(['v', 'V']) {
+            Some(future) => future.split_once('.').is_some_and(|(version, address)| {
+                !version.is_empty()
+                    && version.bytes().all(|byte| byte.is_ascii_hexdigit())
+                    && !address.is_empty()
+                    && address
+                        .bytes()
+                        .all(|byte| unreserved_or_sub_delim(byte) || byte == b':')
+            }),
+            None => literal.parse::<Ipv6Addr>().is_ok(),
+        },
+        None => valid_component(host, |_| false),
+    }
+}
+
+fn split_authority(authority: &str) -> Option<(Option<&str>, &str, Option<&str>)> {
+    let (user_info, host_port) = match authority.split_once('@') {
+        Some((user_info, host_port)) => (Some(user_info), host_port),
+        None => (None, authority),
+    };
+
+    let port_start = match host_port.rfind(':') {
+        Some(colon) if !host_port[colon..].contains(']') => Some(colon),
+        _ => None,
+    };
+    let (host, port) = match port_start {
+        Some(colon) => (&host_port[..colon], Some(&host_port[colon + 1..])),
+        None => (host_port, None),
+    };
+
+    let valid = user_info.is_none_or(|user_info| valid_component(user_info, |byte| byte == b':'))
+        && valid_host(host)
+        && port.is_none_or(|port| port.bytes().all(|byte| byte.is_ascii_digit()));
+
+    valid.then_some((user_info, host, port))
+}
+
+/// Parse an absolute RFC 3986 URI into borrowed components.
+///
+/// Codes are `missing_scheme`, `invalid_scheme`, `invalid_authority`,
+/// `invalid_path`, `invalid_query`, and `invalid_fragment`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Uri;
+
+impl Refine<str> for Uri {
+    type Output<'input>
+        = UriParts<'input>
+    where
+        str: 'input;
+
+    fn name(&self) -> &'static str {
+        "uri"
+    }
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        let (scheme, rest) = input
+            .split_once(':')
+            .filter(|(scheme, _)| !scheme.contains(['/', '?', '#']))
+            .ok_or_else(|| ValidationError::new("uri", "missing_scheme"))?;
+
+        let scheme_valid = scheme
+            .bytes()
+            .next()
+            .is_some_and(|byte| byte.is_ascii_alphabetic())
+            && scheme
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-' | b'.'));
+
+        if !scheme_valid {
+            return Err(ValidationError::new("uri", "invalid_scheme"));
+        }
+
+        let (rest, fragment) = match rest.split_once('#') {
+            Some((rest, fragment)) => (rest, Some(fragment)),
+            None => (rest, None),
+        };
+        let (hierarchy, query) = match rest.split_once('?') {
+            Some((hierarchy, query)) => (hierarchy, Some(query)),
+            None => (rest, None),
+        };
+
+        let (authority, path) = match hierarchy.strip_prefix("//") {
+            Some(hierarchy) => {
+                let end = hierarchy.find('/').unwrap_or(hierarchy.len());
+                (Some(&hierarchy[..end]), &hierarchy[end..])
+            }
+            None => (None, hierarchy),
+        };
+
+        let (user_info, host, port) = match authority {
+            Some(authority) => {
+                let (user_info, host, port) = split_authority(authority)
+                    .ok_or_else(|| ValidationError::new("uri", "invalid_authority"))?;
+                (user_info, Some(host), port)
+            }
+            None => (None, None, None),
+        };
+
+        if !valid_component(path, |byte| pchar(byte) || byte == b'/') {
+            return Err(ValidationError::new("uri", "invalid_path"));
+        }
+
+        let query_or_fragment = |byte| pchar(byte) || matches!(byte, b'/' | b'?');
+
+        if query.is_some_and(|query| !valid_component(query, query_or_fragment)) {
+            return Err(ValidationError::new("uri", "invalid_query"));
+        }
+
+        if fragment.is_some_and(|fragment| !valid_component(fragment, query_or_fragment)) {
+            return Err(ValidationError::new("uri", "invalid_fragment"));
+        }
+
+        Ok(UriParts {
+            scheme,
+            authority,
+            user_info,
+            host,
+            port,
+            path,
+            query,
+            fragment,
+        })
+    }
+}
+
+/// Require the URI scheme to be one of a fixed set, ignoring ASCII case.
+#[derive(Debug, Clone, Copy)]
+pub struct SchemeIn {
+    schemes: &'static [&'static str],
+}
+
+impl SchemeIn {
+    pub const fn new(schemes: &'static [&'static str]) -> Self {
+        Self { schemes }
+    }
+
+    pub const fn schemes(&self) -> &'static [&'static str] {
+        self.schemes
+    }
+}
+
+impl Rule<UriParts<'_>> for SchemeIn {
+    fn name(&self) -> &'static str {
+        "scheme_in"
+    }
+
+    fn check(&self, value: &UriParts<'_>) -> Result<(), ValidationError> {
+        if self
+            .schemes
+            .iter()
+            .any(|scheme| scheme.eq_ignore_ascii_case(value.scheme))
+        {
+            Ok(())
+        } else {
+            Err(ValidationError::new("scheme_in", "scheme_not_allowed"))
+        }
+    }
+}
+
+/// Reject URIs that carry user information, such as credentials, in their
+/// authority.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoUserInfo;
+
+impl Rule<UriParts<'_>> for NoUserInfo {
+    fn name(&self) -> &'static str {
+        "no_user_info"
+    }
+
+    fn check(&self, value: &UriParts<'_>) -> Result<(), ValidationError> {
+        if value.user_info.is_some() {
+            Err(ValidationError::new("no_user_info", "user_info_present"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn is_dot_segment(segment: &str) -> bool {
+    let mut rest = segment.as_bytes();
+    let mut dots = 0;
+
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix(b".") {
+            rest = after;
+        } else if rest
+            .get(..3)
+            .is_some_and(|dot| dot.eq_ignore_ascii_case(b"%2e"))
+        {
+            rest = &rest[3..];
+        } else {
+            return false;
+        }
+
+        dots += 1;
+    }
+
+    (1..=2).contains(&dots)
+}
+
+/// Reject paths with `.` or `..` segments, including percent-encoded forms.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PathNoDotSegments;
+
+impl Rule<UriParts<'_>> for PathNoDotSegments {
+    fn name(&self) -> &'static str {
+        "path_no_dot_segments"
+    }
+
+    fn check(&self, value: &UriParts<'_>) -> Result<(), ValidationError> {
+        let dot_segment = value.path.split('/').any(is_dot_segment);
+
+        if dot_segment {
+            Err(ValidationError::new("path_no_dot_segments", "dot_segment"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Bound the number of `&`-separated pairs in the query.
+///
+/// Empty pairs are not counted, and a URI without a query has zero pairs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaxQueryPairs<const MAX: usize>;
+
+impl<const MAX: usize> Rule<UriParts<'_>> for MaxQueryPairs<MAX> {
+    fn name(&self) -> &'static str {
+        "max_query_pairs"
+    }
+
+    fn check(&self, value: &UriParts<'_>) -> Result<(), ValidationError> {
+        let pairs = value.query.map_or(0, |query| {
+            query.split('&').filter(|pair| !pair.is_empty()).count()
+        });
+
+        if pairs > MAX {
+            Err(ValidationError::new(
+                "max_query_pairs",
+                "too_many_query_pairs",
+            ))
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! Name-keyed strategy registries for configuration-driven pipeline assembly.
+//!
+//! A registry holds strategies of one fixed shape, `Input -> Result<Input, Error>`,
+//! so that any sequence of registered keys composes into a pipeline of the same
+//! type. Selection happens at runtime; each registered strategy is still an
+//! ordinary [`Strategy`].
+
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::dyn_strategy::SendArcStrategy;
+use crate::strategy::Strategy;
+
+/// A typed map from string keys to shared `Input -> Result<Input, Error>`
+/// strategies.
+///
+/// Registered strategies must be `Send + Sync + 'static` so that registries and
+/// the pipelines assembled from them can be shared across threads.
+pub struct StrategyRegistry<Input, Error> {
+    entries: BTreeMap<String, SendArcStrategy<Input, Result<Input, Error>>>,
+}
+
+impl<Input, Error> StrategyRegistry<Input, Error> {
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Register `strategy` under `key`, replacing any earlier registration.
+    pub fn register<S>(mut self, key: impl Into<String>, strategy: S) -> Self
+    where
+        S: Strategy<Input, Output = Result<Input, Error>> + Send + Sync + 'static,
+    {
+        self.insert(key, strategy);
+        self
+    }
+
+    /// Register `strategy` under `key` in place, replacing any earlier
+    /// registration.
+    pub fn insert<S>(&mut self, key: impl Into<String>, strategy: S)
+    where
+        S: Strategy<Input, Output = Result<Input, Error>> + Send + Sync + 'static,
+    {
+        self.entries
+            .insert(key.into(), SendArcStrategy::new(strategy));
+    }
+
+    /// Borrow the strategy registered under `key`.
+    pub fn get(&self, key: &str) -> Option<&SendArcStrategy<Input, Result<Input, Error>>> {
+        self.entries.get(key)
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Iterate over registered keys in sorted order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Start assembling a pipeline from registered keys.
+    pub fn builder(&self) -> PipelineBuilder<'_, Input, Error> {
+        PipelineBuilder {
+            registry: self,
+            stages: Vec::new(),
+            unknown: Vec::new(),
+        }
+    }
+
+    /// Assemble a pipeline running `keys` in order.
+    pub fn compose<I, K>(&self, keys: I) -> Result<RegistryPipeline<Input, Error>, UnknownStrategy>
+    where
+        I: IntoIterator<Item = K>,
+        K: AsRef<str>,
+    {
+        keys.into_iter()
+            .fold(self.builder(), |builder, key| builder.stage(key.as_ref()))
+            .build()
+    }
+}
+
+impl<Input, Error> Default for StrategyRegistry<Input, Error> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Input, Error> fmt::Debug for StrategyRegistry<Input, Error> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("StrategyRegistry")
+            .field("keys", &self.entries.keys())
+            .finish()
+    }
+}
+
+/// Incrementally select registered strategies by key.
+///
+/// Unknown keys are recorded rather than reported immediately, so
+/// [`PipelineBuilder::build`] can report every unknown key at once.
+pub struct PipelineBuilder<'registry, Input, Error> {
+    registry: &'registry StrategyRegistry<Input, Error>,
+    stages: Vec<SendArcStrategy<Input, Result<Input, Error>>>,
+    unknown: Vec<String>,
+}
+
+impl<Input, Error> PipelineBuilder<'_, Input, Error> {
+    /// Append the strategy registered under `key`.
+    pub fn stage(mut self, key: &str) -> Self {
+        match self.registry.get(key) {
+            Some(strategy) => self.stages.push(strategy.clone()),
+            None => self.unknown.push(key.to_owned()),
+        }
+
+        self
+    }
+
+    /// Finish the pipeline, or report every key that was not registered.
+    pub fn build(self) -> Result<RegistryPipeline<Input, Error>, UnknownStrategy> {
+        if self.unknown.is_empty() {
+            Ok(RegistryPipeline {
+                stages: self.stages,
+            })
+        } else {
+            Err(UnknownStrategy { keys: self.unknown })
+        }
+    }
+}
+
+impl<Input, Error> fmt::Debug for PipelineBuilder<'_, Input, Error> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("PipelineBuilder")
+            .field("stages", &self.stages.len())
+            .field("unknown", &self.unknown)
+            .finish()
+    }
+}
+
+/// A runtime-assembled pipeline that short-circuits on the first error.
+///
+/// An empty pipeline returns its input unchanged.
+pub struct RegistryPipeline<Input, Error> {
+    stages: Vec<SendArcStrategy<Input, Result<Input, Error>>>,
+}
+
+impl<Input, Error> RegistryPipeline<Input, Error> {
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+}
+
+impl<Input, Error> Clone for RegistryPipeline<Input, Error> {
+    fn clone(&self) -> Self {
+        Self {
+            stages: self.stages.clone(),
+        }
+    }
+}
+
+impl<Input, Error> Strategy<Input> for RegistryPipeline<Input, Error> {
+    type Output = Result<Input, Error>;
+
+    fn apply(&self, input: Input) -> Self::Output {
+        self.stages
+            .iter()
+            .try_fold(input, |value, stage| stage.apply(value))
+    }
+}
+
+impl<Input, Error> fmt::Debug for RegistryPipeline<Input, Error> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("RegistryPipeline")
+            .field("stages", &self.stages.len())
+            .finish()
+    }
+}
+
+/// One or more pipeline keys had no registered strategy.
+///
+/// Keys come from configuration rather than from validated input, so they are
+/// included in diagnostics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownStrategy {
+    keys: Vec<String>,
+}
+
+impl UnknownStrategy {
+    /// Return every unknown key in the order it was requested.
+    pub fn keys(&self) -> &[String] {
+        &self.keys
+    }
+}
+
+impl fmt::Display for UnknownStrategy {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "unknown strategy key(s): {}",
+            self.keys.join(", ")
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for UnknownStrategy {}
@@ -0,0 +1,358 @@
+//! Runtime-agnostic retry and timeout wrappers for asynchronous strategies.
+//!
+//! Strustegy does not provide timers. Callers implement [`Clock`] for the
+//! runtime they already use, or use [`MockClock`] for deterministic tests.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cell::{Cell, RefCell};
+use core::fmt;
+use core::future::{Future, poll_fn};
+#[cfg(feature = "alloc")]
+use core::pin::Pin;
+use core::pin::pin;
+#[cfg(feature = "alloc")]
+use core::task::Context;
+use core::task::Poll;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use crate::async_strategy::AsyncStrategy;
+
+/// A caller-supplied source of asynchronous delays.
+///
+/// Implementations typically forward to their runtime's sleep function. The
+/// returned future may borrow from `self`.
+pub trait Clock {
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()>;
+}
+
+impl<C: Clock + ?Sized> Clock for &C {
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> {
+        (**self).sleep(duration)
+    }
+}
+
+/// A schedule of delays between retry attempts.
+pub trait Backoff {
+    /// Return the delay before retry number `retry`, starting at `1`.
+    fn delay(&self, retry: u32) -> Duration;
+}
+
+/// Wait the same duration before every retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ConstantBackoff {
+    delay: Duration,
+}
+
+impl ConstantBackoff {
+    pub const fn new(delay: Duration) -> Self {
+        Self { delay }
+    }
+}
+
+impl Backoff for ConstantBackoff {
+    fn delay(&self, _retry: u32) -> Duration {
+        self.delay
+    }
+}
+
+/// Double the delay after every retry, saturating at `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExponentialBackoff {
+    initial: Duration,
+    max: Duration,
+}
+
+impl ExponentialBackoff {
+    pub const fn new(initial: Duration, max: Duration) -> Self {
+        Self { initial, max }
+    }
+}
+
+impl Backoff for ExponentialBackoff {
+    fn delay(&self, retry: u32) -> Duration {
+        2_u32
+            .checked_pow(retry.saturating_sub(1))
+            .and_then(|factor| self.initial.checked_mul(factor))
+            .map_or(self.max, |delay| delay.min(self.max))
+    }
+}
+
+/// The attempt budget, backoff schedule, and clock used by [`Retry`].
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy<B, C> {
+    attempts: u32,
+    backoff: B,
+    clock: C,
+}
+
+impl<B, C> RetryPolicy<B, C> {
+    /// Allow up to `attempts` calls in total, including the first.
+    ///
+    /// An attempt count of zero is treated as one: the wrapped strategy always
+    /// runs at least once.
+    pub const fn new(attempts: u32, backoff: B, clock: C) -> Self {
+        Self {
+            attempts,
+            backoff,
+            clock,
+        }
+    }
+
+    /// Return the effective total attempt count.
+    pub const fn attempts(&self) -> u32 {
+        if self.attempts == 0 { 1 } else { self.attempts }
+    }
+
+    pub const fn backoff(&self) -> &B {
+        &self.backoff
+    }
+
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+}
+
+/// How many attempts [`Retry`] makes and how it waits between them.
+///
+/// [`RetryPolicy`] combines an attempt budget, a [`Backoff`], and a [`Clock`].
+/// Implement this trait for schedules that need more, such as jitter or an
+/// overall deadline.
+pub trait RetrySchedule {
+    /// Return the total attempt count, including the first.
+    ///
+    /// [`Retry`] always makes at least one attempt, even when this is zero.
+    fn attempts(&self) -> u32;
+
+    /// Wait before retry number `retry`, starting at `1`.
+    fn wait(&self, retry: u32) -> impl Future<Output = ()>;
+}
+
+impl<B: Backoff, C: Clock> RetrySchedule for RetryPolicy<B, C> {
+    fn attempts(&self) -> u32 {
+        RetryPolicy::attempts(self)
+    }
+
+    fn wait(&self, retry: u32) -> impl Future<Output = ()> {
+        self.clock.sleep(self.backoff.delay(retry))
+    }
+}
+
+/// Retry a `Result`-returning asynchronous strategy according to a
+/// [`RetrySchedule`].
+///
+/// Every failed attempt except the last is followed by the schedule's wait.
+/// The input is cloned for each attempt but the final one, and the last error
+/// is returned once the attempt budget is exhausted.
+#[derive(Debug, Clone, Copy)]
+pub struct Retry<S, P> {
+    strategy: S,
+    policy: P,
+}
+
+impl<S, P> Retry<S, P> {
+    pub const fn new(strategy: S, policy: P) -> Self {
+        Self { strategy, policy }
+    }
+
+    pub const fn get_ref(&self) -> &S {
+        &self.strategy
+    }
+
+    pub const fn policy(&self) -> &P {
+        &self.policy
+    }
+
+    pub fn into_inner(self) -> S {
+        self.strategy
+    }
+}
+
+impl<Input, Output, Error, S, P> AsyncStrategy<Input> for Retry<S, P>
+where
+    Input: Clone,
+    S: AsyncStrategy<Input, Output = Result<Output, Error>>,
+    P: RetrySchedule,
+{
+    type Output = Result<Output, Error>;
+
+    async fn apply_async(&self, input: Input) -> Self::Output {
+        for retry in 1..self.policy.attempts() {
+            if let Ok(output) = self.strategy.apply_async(input.clone()).await {
+                return Ok(output);
+            }
+
+            self.policy.wait(retry).await;
+        }
+
+        self.strategy.apply_async(input).await
+    }
+}
+
+/// The error produced when a [`Timeout`] deadline passes first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Elapsed;
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("strategy deadline elapsed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for Elapsed {}
+
+/// Bound a `Result`-returning asynchronous strategy by a deadline.
+///
+/// The wrapped future and the clock's sleep are polled together. When the sleep
+/// completes first, the wrapped future is dropped and the strategy resolves to
+/// `Err(Error::from(Elapsed))`.
+#[derive(Debug, Clone, Copy)]
+pub struct Timeout<S, C> {
+    strategy: S,
+    duration: Duration,
+    clock: C,
+}
+
+impl<S, C> Timeout<S, C> {
+    pub const fn new(strategy: S, duration: Duration, clock: C) -> Self {
+        Self {
+            strategy,
+            duration,
+            clock,
+        }
+    }
+
+    pub const fn get_ref(&self) -> &S {
+        &self.strategy
+    }
+
+    pub const fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    pub fn into_inner(self) -> S {
+        self.strategy
+    }
+}
+
+impl<Input, Output, Error, S, C> AsyncStrategy<Input> for Timeout<S, C>
+where
+    S: AsyncStrategy<Input, Output = Result<Output, Error>>,
+    Error: From<Elapsed>,
+    C: Clock,
+{
+    type Output = Result<Output, Error>;
+
+    async fn apply_async(&self, input: Input) -> Self::Output {
+        let mut work = pin!(self.strategy.apply_async(input));
+        let mut deadline = pin!(self.clock.sleep(self.duration));
+
+        poll_fn(|context| {
+            if let Poll::Ready(output) = work.as_mut().poll(context) {
+                return Poll::Ready(output);
+            }
+
+            match deadline.as_mut().poll(context) {
+                Poll::Ready(()) => Poll::Ready(Err(Error::from(Elapsed))),
+                Poll::Pending => Poll::Pending,
+            }
+        })
+        .await
+    }
+}
+
+/// A deterministic virtual clock for offline tests.
+///
+/// Requires the `alloc` feature.
+///
+/// Virtual time starts at zero and moves only through [`MockClock::advance`]
+/// or [`MockClock::advance_to_next_deadline`]. A sleep future completes once
+/// virtual time reaches its deadline. It does not register a waker, so drive it
+/// with a polling loop that advances the clock between polls.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default)]
+pub struct MockClock {
+    now: Cell<Duration>,
+    sleeps: RefCell<Vec<Duration>>,
+    deadlines: RefCell<Vec<Duration>>,
+}
+
+#[cfg(feature = "alloc")]
+impl MockClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Return the virtual time elapsed since the clock was created.
+    pub fn now(&self) -> Duration {
+        self.now.get()
+    }
+
+    /// Move virtual time forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        self.now.set(self.now.get().saturating_add(duration));
+    }
+
+    /// Move virtual time to the earliest requested deadline still in the
+    /// future, returning `false` when no such deadline exists.
+    pub fn advance_to_next_deadline(&self) -> bool {
+        let now = self.now.get();
+        let next = self
+            .deadlines
+            .borrow()
+            .iter()
+            .copied()
+            .filter(|deadline| *deadline > now)
+            .min();
+
+        match next {
+            Some(deadline) => {
+                self.now.set(deadline);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Return every requested sleep duration in request order.
+    pub fn sleeps(&self) -> Vec<Duration> {
+        self.sleeps.borrow().clone()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Clock for MockClock {
+    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> {
+        let deadline = self.now.get().saturating_add(duration);
+        self.sleeps.borrow_mut().push(duration);
+        self.deadlines.borrow_mut().push(deadline);
+
+        MockSleep {
+            clock: self,
+            deadline,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct MockSleep<'clock> {
+    clock: &'clock MockClock,
+    deadline: Duration,
+}
+
+#[cfg(feature = "alloc")]
+impl Future for MockSleep<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, _context: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.clock.now.get() >= self.deadline {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
@@ -1,5 +1,10 @@
 //! Type-directed strategies and static composition.
 
+#[cfg(feature = "alloc")]
+use crate::dyn_strategy::BoxStrategy;
+use crate::observe::Instrumented;
+use crate::strategy_mut::IntoStrategyMut;
+
 /// A shared, type-directed strategy from `Input` to an associated output type.
 ///
 /// Implementations intended to participate in composition laws should behave
@@ -91,6 +96,26 @@ pub trait StrategyExt: Sized {
     fn and_then<G>(self, next: G) -> AndThen<Self, G> {
         AndThen::new(self, next)
     }
+
+    /// Own this strategy as a [`StrategyMut`](crate::StrategyMut), for stateful
+    /// pipelines and [`HMapMut`](crate::HMapMut) that need one by value.
+    fn into_strategy_mut(self) -> IntoStrategyMut<Self> {
+        IntoStrategyMut::new(self)
+    }
+
+    /// Report each application to `observer` as the stage `stage`.
+    fn instrument<O>(self, stage: &'static str, observer: O) -> Instrumented<Self, O> {
+        Instrumented::new(self, stage, observer)
+    }
+
+    /// Erase this strategy's concrete type behind a [`BoxStrategy`].
+    #[cfg(feature = "alloc")]
+    fn boxed<Input>(self) -> BoxStrategy<Input, <Self as Strategy<Input>>::Output>
+    where
+        Self: Strategy<Input> + 'static,
+    {
+        BoxStrategy::new(self)
+    }
 }
 
 impl<S> StrategyExt for S {}
@@ -0,0 +1,225 @@
+//! Stateful strategies that take `&mut self`.
+//!
+//! [`Strategy`] takes `&self` so that composed strategies behave
+//! extensionally. [`StrategyMut`] is the parallel trait for strategies that
+//! keep state between calls, such as deduplication, sequence numbering, or
+//! running totals.
+
+use crate::strategy::Strategy;
+
+/// A type-directed strategy that may update its own state on every call.
+///
+/// Every [`Strategy`] is a `StrategyMut` through a shared reference: `&S`
+/// calls [`Strategy::apply`]. An owned strategy joins a stateful pipeline with
+/// [`into_strategy_mut`](crate::StrategyExt::into_strategy_mut). A blanket
+/// implementation for every `S: Strategy` would overlap with the stateful
+/// adapters under Rust's coherence rules. [`StrategyMutExt::by_mut`] lends a
+/// stateful strategy to a composition so it can be inspected afterwards.
+///
+/// ```
+/// use strustegy::prelude::*;
+///
+/// fn run<S: StrategyMut<i32>>(mut strategy: S) -> S::Output {
+///     strategy.apply_mut(21)
+/// }
+///
+/// let double = strategy_fn(|value: i32| value * 2);
+///
+/// assert_eq!(run(&double), 42);
+/// assert_eq!(run(double.into_strategy_mut()), 42);
+/// ```
+pub trait StrategyMut<Input> {
+    type Output;
+
+    fn apply_mut(&mut self, input: Input) -> Self::Output;
+}
+
+impl<Input, S> StrategyMut<Input> for &S
+where
+    S: Strategy<Input> + ?Sized,
+{
+    type Output = S::Output;
+
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        (**self).apply(input)
+    }
+}
+
+/// Own a [`Strategy`] as a [`StrategyMut`] that calls [`Strategy::apply`].
+///
+/// Constructed by [`StrategyExt::into_strategy_mut`](crate::StrategyExt::into_strategy_mut).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct IntoStrategyMut<S> {
+    strategy: S,
+}
+
+impl<S> IntoStrategyMut<S> {
+    pub const fn new(strategy: S) -> Self {
+        Self { strategy }
+    }
+
+    pub const fn get_ref(&self) -> &S {
+        &self.strategy
+    }
+
+    pub fn into_inner(self) -> S {
+        self.strategy
+    }
+}
+
+impl<Input, S> StrategyMut<Input> for IntoStrategyMut<S>
+where
+    S: Strategy<Input>,
+{
+    type Output = S::Output;
+
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.strategy.apply(input)
+    }
+}
+
+/// Lend a stateful strategy to a composition by mutable reference.
+///
+/// Constructed by [`StrategyMutExt::by_mut`]. State updated through the
+/// adapter stays in the borrowed strategy.
+#[derive(Debug)]
+pub struct ByMut<'a, S: ?Sized> {
+    strategy: &'a mut S,
+}
+
+impl<'a, S: ?Sized> ByMut<'a, S> {
+    pub const fn new(strategy: &'a mut S) -> Self {
+        Self { strategy }
+    }
+
+    pub const fn get_ref(&self) -> &S {
+        self.strategy
+    }
+}
+
+impl<Input, S> StrategyMut<Input> for ByMut<'_, S>
+where
+    S: StrategyMut<Input> + ?Sized,
+{
+    type Output = S::Output;
+
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        self.strategy.apply_mut(input)
+    }
+}
+
+/// Adapt a closure that mutates its captured state into a [`StrategyMut`].
+#[derive(Debug, Clone, Copy)]
+pub struct FnMutStrategy<F> {
+    function: F,
+}
+
+impl<F> FnMutStrategy<F> {
+    /// Create a stateful strategy backed by `function`.
+    pub const fn new(function: F) -> Self {
+        Self { function }
+    }
+
+    /// Borrow the wrapped callable.
+    pub const fn get_ref(&self) -> &F {
+        &self.function
+    }
+
+    /// Consume the adapter and return the wrapped callable.
+    pub fn into_inner(self) -> F {
+        self.function
+    }
+}
+
+impl<Input, Output, F> StrategyMut<Input> for FnMutStrategy<F>
+where
+    F: FnMut(Input) -> Output,
+{
+    type Output = Output;
+
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        (self.function)(input)
+    }
+}
+
+/// Construct a [`FnMutStrategy`] with type inference for the callable.
+pub const fn strategy_fn_mut<F>(function: F) -> FnMutStrategy<F> {
+    FnMutStrategy::new(function)
+}
+
+/// Static composition of two stateful strategies.
+#[derive(Debug, Clone, Copy)]
+pub struct ComposeMut<F, G> {
+    pub first: F,
+    pub second: G,
+}
+
+impl<F, G> ComposeMut<F, G> {
+    pub const fn new(first: F, second: G) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<Input, F, G> StrategyMut<Input> for ComposeMut<F, G>
+where
+    F: StrategyMut<Input>,
+    G: StrategyMut<<F as StrategyMut<Input>>::Output>,
+{
+    type Output = <G as StrategyMut<<F as StrategyMut<Input>>::Output>>::Output;
+
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        let intermediate = self.first.apply_mut(input);
+        self.second.apply_mut(intermediate)
+    }
+}
+
+/// Static short-circuiting composition for stateful strategies sharing one
+/// error type.
+///
+/// The second strategy, and so its state, is untouched when the first fails.
+#[derive(Debug, Clone, Copy)]
+pub struct AndThenMut<F, G> {
+    pub first: F,
+    pub second: G,
+}
+
+impl<F, G> AndThenMut<F, G> {
+    pub const fn new(first: F, second: G) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<Input, Intermediate, Output, Error, F, G> StrategyMut<Input> for AndThenMut<F, G>
+where
+    F: StrategyMut<Input, Output = Result<Intermediate, Error>>,
+    G: StrategyMut<Intermediate, Output = Result<Output, Error>>,
+{
+    type Output = Result<Output, Error>;
+
+    fn apply_mut(&mut self, input: Input) -> Self::Output {
+        let intermediate = self.first.apply_mut(input)?;
+        self.second.apply_mut(intermediate)
+    }
+}
+
+/// Fluent stateful strategy composition.
+pub trait StrategyMutExt: Sized {
+    /// Compose two stateful strategies.
+    fn then_mut<G>(self, next: G) -> ComposeMut<Self, G> {
+        ComposeMut::new(self, next)
+    }
+
+    /// Compose two stateful strategies returning `Result<_, E>` and
+    /// short-circuit on the first error.
+    fn and_then_mut<G>(self, next: G) -> AndThenMut<Self, G> {
+        AndThenMut::new(self, next)
+    }
+
+    /// Borrow this strategy mutably, so a composition updates its state in
+    /// place.
+    fn by_mut(&mut self) -> ByMut<'_, Self> {
+        ByMut::new(self)
+    }
+}
+
+impl<S> StrategyMutExt for S {}
@@ -3,11 +3,18 @@
 //! Successful validation creates a receipt for the value as it existed when the
 //! rules ran. It is not authorization and does not freeze interior-mutable state.
 
-use core::{fmt, marker::PhantomData};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
 use std::error::Error;
 
 use crate::hlist::{HCons, HList, HNil};
+use crate::observe::Observer;
 use crate::proof::Validated;
+#[cfg(feature = "alloc")]
 use crate::strategy::Strategy;
 
 pub mod rules;
@@ -39,6 +46,53 @@ macro_rules! validation_policy {
     };
 }
 
+/// Validate a string literal at compile time and yield a
+/// `Validated<&'static str, P>`.
+///
+/// Every rule of `P` must implement [`ConstRule`]. A literal that fails the
+/// policy is a compile error naming the failed rule and code, so constants no
+/// longer need a runtime `unwrap`.
+///
+/// ```
+/// use strustegy::prelude::*;
+///
+/// enum ToolNamePolicy {}
+///
+/// validation_policy! {
+///     ToolNamePolicy: &'static str => [NonEmpty, MaxBytes<16>, AsciiIdentifier]
+/// }
+///
+/// const DEFAULT_TOOL: Validated<&str, ToolNamePolicy> =
+///     const_validated!(ToolNamePolicy, "sync_status");
+///
+/// assert_eq!(*DEFAULT_TOOL.get(), "sync_status");
+/// ```
+///
+/// ```compile_fail
+/// use strustegy::prelude::*;
+///
+/// enum ToolNamePolicy {}
+///
+/// validation_policy! {
+///     ToolNamePolicy: &'static str => [NonEmpty, MaxBytes<16>, AsciiIdentifier]
+/// }
+///
+/// const DEFAULT_TOOL: Validated<&str, ToolNamePolicy> =
+///     const_validated!(ToolNamePolicy, "sync status");
+/// ```
+#[macro_export]
+macro_rules! const_validated {
+    ($policy:ty, $literal:expr $(,)?) => {{
+        struct Literal;
+
+        impl $crate::validate::ConstLiteral for Literal {
+            const VALUE: &'static str = $literal;
+        }
+
+        $crate::validate::validate_const::<$policy, Literal>()
+    }};
+}
+
 /// A redaction-safe validation failure.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ValidationError {
@@ -70,17 +124,37 @@ impl fmt::Display for ValidationError {
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for ValidationError {}
 
+/// The number of failures [`ValidationErrors`] stores before allocating.
+#[cfg(feature = "alloc")]
+const INLINE_ERRORS: usize = 3;
+
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+enum ErrorStorage {
+    Inline(ErrorBuffer<INLINE_ERRORS>),
+    Heap(Vec<ValidationError>),
+}
+
 /// One or more validation failures collected without echoing rejected input.
-#[derive(Debug, Clone, PartialEq, Eq)]
+///
+/// Up to three failures are stored inline; only a fourth moves them to the heap.
+/// Requires the `alloc` feature. [`ErrorBuffer`] is the allocation-free
+/// alternative.
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
 pub struct ValidationErrors {
-    errors: Vec<ValidationError>,
+    errors: ErrorStorage,
 }
 
+#[cfg(feature = "alloc")]
 impl ValidationErrors {
-    fn new(errors: Vec<ValidationError>) -> Self {
-        Self { errors }
+    pub(crate) const fn new() -> Self {
+        Self {
+            errors: ErrorStorage::Inline(ErrorBuffer::new()),
+        }
     }
 
     /// Return the first collected validation error, if any.
@@ -88,45 +162,195 @@ impl ValidationErrors {
     /// Errors retain deterministic policy/rule execution order. The first error
     /// is not inherently more authoritative or severe than later failures.
     pub fn first(&self) -> Option<&ValidationError> {
-        self.errors.first()
+        self.as_slice().first()
     }
 
     /// Iterate over collected validation errors in policy/rule execution order.
     pub fn iter(&self) -> core::slice::Iter<'_, ValidationError> {
-        self.errors.iter()
+        self.as_slice().iter()
     }
 
     pub fn as_slice(&self) -> &[ValidationError] {
-        &self.errors
+        match &self.errors {
+            ErrorStorage::Inline(buffer) => buffer.as_slice(),
+            ErrorStorage::Heap(errors) => errors,
+        }
     }
 
     pub fn into_vec(self) -> Vec<ValidationError> {
-        self.errors
+        match self.errors {
+            ErrorStorage::Inline(buffer) => buffer.as_slice().to_vec(),
+            ErrorStorage::Heap(errors) => errors,
+        }
     }
 
     pub fn len(&self) -> usize {
-        self.errors.len()
+        self.as_slice().len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.errors.is_empty()
+        self.as_slice().is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ErrorSink for ValidationErrors {
+    fn push(&mut self, error: ValidationError) {
+        match &mut self.errors {
+            ErrorStorage::Inline(buffer) if buffer.len() < INLINE_ERRORS => buffer.push(error),
+            ErrorStorage::Inline(buffer) => {
+                let mut errors = Vec::with_capacity(INLINE_ERRORS * 2);
+                errors.extend_from_slice(buffer.as_slice());
+                errors.push(error);
+                self.errors = ErrorStorage::Heap(errors);
+            }
+            ErrorStorage::Heap(errors) => errors.push(error),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl PartialEq for ValidationErrors {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Eq for ValidationErrors {}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for ValidationErrors {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ValidationErrors")
+            .field("errors", &self.as_slice())
+            .finish()
+    }
+}
+
+#[cfg(feature = "alloc")]
 impl fmt::Display for ValidationErrors {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            formatter,
-            "validation failed with {} error(s)",
-            self.errors.len()
-        )
+        write!(formatter, "validation failed with {} error(s)", self.len())
     }
 }
 
+#[cfg(feature = "std")]
 impl Error for ValidationErrors {}
 
+/// A destination for the failures collected by [`ValidateRules::check_all`]
+/// and [`Prove::prove_each`](crate::refine::Prove::prove_each).
+pub trait ErrorSink {
+    fn push(&mut self, error: ValidationError);
+}
+
+#[cfg(feature = "alloc")]
+impl ErrorSink for Vec<ValidationError> {
+    fn push(&mut self, error: ValidationError) {
+        Vec::push(self, error);
+    }
+}
+
+/// Up to `N` validation failures stored inline, without allocating.
+///
+/// [`validate_all_buffered`] checks at compile time that `N` covers every rule
+/// of its policy. When a buffer is filled by other means, failures beyond `N`
+/// are dropped.
+#[derive(Clone, Copy)]
+pub struct ErrorBuffer<const N: usize> {
+    errors: [ValidationError; N],
+    len: usize,
+}
+
+impl<const N: usize> ErrorBuffer<N> {
+    pub const fn new() -> Self {
+        Self {
+            errors: [ValidationError::new("", ""); N],
+            len: 0,
+        }
+    }
+
+    /// Return the first collected validation error, if any.
+    pub fn first(&self) -> Option<&ValidationError> {
+        self.as_slice().first()
+    }
+
+    /// Iterate over collected validation errors in policy/rule execution order.
+    pub fn iter(&self) -> core::slice::Iter<'_, ValidationError> {
+        self.as_slice().iter()
+    }
+
+    pub fn as_slice(&self) -> &[ValidationError] {
+        &self.errors[..self.len]
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize> Default for ErrorBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> ErrorSink for ErrorBuffer<N> {
+    fn push(&mut self, error: ValidationError) {
+        if let Some(slot) = self.errors.get_mut(self.len) {
+            *slot = error;
+            self.len += 1;
+        }
+    }
+}
+
+impl<const N: usize> PartialEq for ErrorBuffer<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<const N: usize> Eq for ErrorBuffer<N> {}
+
+impl<const N: usize> fmt::Debug for ErrorBuffer<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter
+            .debug_struct("ErrorBuffer")
+            .field("errors", &self.as_slice())
+            .finish()
+    }
+}
+
+impl<const N: usize> fmt::Display for ErrorBuffer<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "validation failed with {} error(s)", self.len)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<const N: usize> Error for ErrorBuffer<N> {}
+
 /// One validation strategy for a borrowed value.
 pub trait Rule<T> {
+    /// The name observers receive for this rule, whether it passes or fails.
+    ///
+    /// Built-in rules return the rule name carried by their errors. The
+    /// default is [`core::any::type_name`], which is not stable across
+    /// compiler versions and does not match [`ValidationError::rule`], so a
+    /// custom rule should override it with the name its errors use.
+    fn name(&self) -> &'static str {
+        core::any::type_name::<Self>()
+    }
+
     fn check(&self, value: &T) -> Result<(), ValidationError>;
 }
 
@@ -134,7 +358,21 @@ pub trait Rule<T> {
 pub trait ValidateRules<T>: HList {
     fn check_first(&self, value: &T) -> Result<(), ValidationError>;
 
-    fn check_all(&self, value: &T, errors: &mut Vec<ValidationError>);
+    fn check_all<E: ErrorSink + ?Sized>(&self, value: &T, errors: &mut E);
+
+    /// Run [`check_all`](Self::check_all), reporting each rule's outcome to
+    /// `observer`.
+    ///
+    /// The default runs `check_all` without reporting. The HList
+    /// implementations report every rule.
+    fn check_observed<E, O>(&self, value: &T, errors: &mut E, observer: &O)
+    where
+        E: ErrorSink + ?Sized,
+        O: Observer + ?Sized,
+    {
+        let _ = observer;
+        self.check_all(value, errors);
+    }
 }
 
 impl<T> ValidateRules<T> for HNil {
@@ -142,7 +380,7 @@ impl<T> ValidateRules<T> for HNil {
         Ok(())
     }
 
-    fn check_all(&self, _value: &T, _errors: &mut Vec<ValidationError>) {}
+    fn check_all<E: ErrorSink + ?Sized>(&self, _value: &T, _errors: &mut E) {}
 }
 
 impl<T, R, Tail> ValidateRules<T> for HCons<R, Tail>
@@ -155,13 +393,116 @@ where
         self.tail.check_first(value)
     }
 
-    fn check_all(&self, value: &T, errors: &mut Vec<ValidationError>) {
+    fn check_all<E: ErrorSink + ?Sized>(&self, value: &T, errors: &mut E) {
         if let Err(error) = self.head.check(value) {
             errors.push(error);
         }
 
         self.tail.check_all(value, errors);
     }
+
+    fn check_observed<E, O>(&self, value: &T, errors: &mut E, observer: &O)
+    where
+        E: ErrorSink + ?Sized,
+        O: Observer + ?Sized,
+    {
+        let rule = self.head.name();
+
+        match self.head.check(value) {
+            Ok(()) => observer.rule_passed(rule),
+            Err(error) => {
+                observer.rule_failed(rule, error.code());
+                errors.push(error);
+            }
+        }
+
+        self.tail.check_observed(value, errors, observer);
+    }
+}
+
+/// A `&'static str` lifted to the type level so [`ConstRule`] can check it.
+///
+/// [`const_validated!`](crate::const_validated) implements this for each
+/// literal it validates.
+pub trait ConstLiteral {
+    const VALUE: &'static str;
+}
+
+/// The compile-time mirror of a string [`Rule`].
+///
+/// Trait methods cannot be `const`, so the check is an associated constant
+/// evaluated for each literal. The built-in string rules implement it with the
+/// same `const fn` their [`Rule`] implementation calls, and rule HLists
+/// implement it by checking each rule in order.
+pub trait ConstRule<L: ConstLiteral> {
+    const CHECK: Result<(), ValidationError>;
+}
+
+impl<L: ConstLiteral> ConstRule<L> for HNil {
+    const CHECK: Result<(), ValidationError> = Ok(());
+}
+
+impl<L, R, Tail> ConstRule<L> for HCons<R, Tail>
+where
+    L: ConstLiteral,
+    R: ConstRule<L>,
+    Tail: ConstRule<L> + HList,
+{
+    const CHECK: Result<(), ValidationError> = match R::CHECK {
+        Ok(()) => Tail::CHECK,
+        Err(error) => Err(error),
+    };
+}
+
+/// Write `validation rule '<rule>' failed with code '<code>'` into `buffer`
+/// during constant evaluation, truncating at its capacity.
+const fn const_message(error: ValidationError, buffer: &mut [u8; 128]) -> &str {
+    let parts = [
+        "validation rule '",
+        error.rule,
+        "' failed with code '",
+        error.code,
+        "'",
+    ];
+    let mut length = 0;
+    let mut part = 0;
+
+    while part < parts.len() {
+        let bytes = parts[part].as_bytes();
+        let mut index = 0;
+
+        while index < bytes.len() && length < buffer.len() {
+            buffer[length] = bytes[index];
+            length += 1;
+            index += 1;
+        }
+        part += 1;
+    }
+
+    match core::str::from_utf8(buffer.split_at(length).0) {
+        Ok(message) => message,
+        Err(_) => "validation rule failed",
+    }
+}
+
+/// Produce the receipt for a literal checked by
+/// [`const_validated!`](crate::const_validated).
+///
+/// The policy runs while the caller is compiled; a failure is a compile error.
+pub const fn validate_const<P, L>() -> Validated<&'static str, P>
+where
+    P: Policy<&'static str>,
+    P::Rules: ConstRule<L>,
+    L: ConstLiteral,
+{
+    const {
+        if let Err(error) = <P::Rules as ConstRule<L>>::CHECK {
+            let mut buffer = [0; 128];
+            panic!("{}", const_message(error, &mut buffer));
+        }
+    }
+
+    Validated::new(L::VALUE)
 }
 
 /// A policy fixes the exact rule-list type used to produce its proof.
@@ -192,18 +533,100 @@ where
 ///
 /// On success, the returned wrapper is a receipt for this validation event; it
 /// is not an authorization grant or a permanent fact about external state.
+#[cfg(feature = "alloc")]
 pub fn validate_all<P, T>(value: T) -> Result<Validated<T, P>, ValidationErrors>
 where
     P: Policy<T>,
 {
     let rules = P::rules();
-    let mut errors = Vec::with_capacity(<P::Rules as HList>::LEN);
+
+    // A policy that cannot spill fills the inline buffer without checking for
+    // heap storage on every failure.
+    if <P::Rules as HList>::LEN <= INLINE_ERRORS {
+        let mut errors = ErrorBuffer::new();
+        rules.check_all(&value, &mut errors);
+
+        return if errors.is_empty() {
+            Ok(Validated::new(value))
+        } else {
+            Err(ValidationErrors {
+                errors: ErrorStorage::Inline(errors),
+            })
+        };
+    }
+
+    let mut errors = ValidationErrors::new();
+    rules.check_all(&value, &mut errors);
+
+    if errors.is_empty() {
+        Ok(Validated::new(value))
+    } else {
+        Err(errors)
+    }
+}
+
+/// Evaluate every rule like [`validate_all`], reporting each outcome to
+/// `observer`.
+///
+/// The observer receives rule names and failure codes, never `value`.
+#[cfg(feature = "alloc")]
+pub fn validate_all_observed<P, T, O>(
+    value: T,
+    observer: &O,
+) -> Result<Validated<T, P>, ValidationErrors>
+where
+    P: Policy<T>,
+    O: Observer + ?Sized,
+{
+    let rules = P::rules();
+    let mut errors = ValidationErrors::new();
+    rules.check_observed(&value, &mut errors, observer);
+
+    if errors.is_empty() {
+        Ok(Validated::new(value))
+    } else {
+        Err(errors)
+    }
+}
+
+/// Evaluate every rule like [`validate_all`], collecting errors into an
+/// inline [`ErrorBuffer`] instead of allocating.
+///
+/// `N` must be at least the number of rules in `P`; a smaller buffer fails to
+/// compile.
+///
+/// ```compile_fail
+/// use strustegy::prelude::*;
+///
+/// enum NamePolicy {}
+///
+/// validation_policy! {
+///     NamePolicy: &'static str => [NonEmpty, MaxBytes<16>]
+/// }
+///
+/// let _ = validate_all_buffered::<NamePolicy, _, 1>("sync_status");
+/// ```
+pub fn validate_all_buffered<P, T, const N: usize>(
+    value: T,
+) -> Result<Validated<T, P>, ErrorBuffer<N>>
+where
+    P: Policy<T>,
+{
+    const {
+        assert!(
+            N >= <P::Rules as HList>::LEN,
+            "ErrorBuffer is smaller than the policy's rule list"
+        );
+    }
+
+    let rules = P::rules();
+    let mut errors = ErrorBuffer::new();
     rules.check_all(&value, &mut errors);
 
     if errors.is_empty() {
         Ok(Validated::new(value))
     } else {
-        Err(ValidationErrors::new(errors))
+        Err(errors)
     }
 }
 
@@ -212,10 +635,12 @@ where
 /// `ValidateWith<P>` delegates to [`validate_all`], preserving its rule ordering,
 /// accumulated diagnostics, redaction behavior, and [`Validated`] receipt
 /// semantics. It does not construct or require a value of `P`.
+#[cfg(feature = "alloc")]
 pub struct ValidateWith<P> {
     marker: PhantomData<fn() -> P>,
 }
 
+#[cfg(feature = "alloc")]
 impl<P> ValidateWith<P> {
     /// Construct a validation strategy for policy `P`.
     pub const fn new() -> Self {
@@ -225,26 +650,31 @@ impl<P> ValidateWith<P> {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<P> Copy for ValidateWith<P> {}
 
+#[cfg(feature = "alloc")]
 impl<P> Clone for ValidateWith<P> {
     fn clone(&self) -> Self {
         *self
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<P> Default for ValidateWith<P> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<P> fmt::Debug for ValidateWith<P> {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
         formatter.write_str("ValidateWith")
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T, P> Strategy<T> for ValidateWith<P>
 where
     P: Policy<T>,
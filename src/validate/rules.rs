@@ -1,6 +1,61 @@
 //! Small dependency-free validation rules.
 
-use super::{Rule, ValidationError};
+use super::{ConstLiteral, ConstRule, Rule, ValidationError};
+
+const fn non_empty(value: &str) -> Result<(), ValidationError> {
+    if value.is_empty() {
+        Err(ValidationError::new("non_empty", "empty"))
+    } else {
+        Ok(())
+    }
+}
+
+const fn max_bytes<const MAX: usize>(value: &str) -> Result<(), ValidationError> {
+    if value.len() > MAX {
+        Err(ValidationError::new("max_bytes", "too_long"))
+    } else {
+        Ok(())
+    }
+}
+
+const fn max_unicode_scalars<const MAX: usize>(value: &str) -> Result<(), ValidationError> {
+    let bytes = value.as_bytes();
+    let mut scalars = 0;
+    let mut index = 0;
+
+    // Every scalar value starts with exactly one non-continuation byte.
+    while index < bytes.len() {
+        if bytes[index] & 0xC0 != 0x80 {
+            scalars += 1;
+        }
+        index += 1;
+    }
+
+    if scalars > MAX {
+        Err(ValidationError::new("max_unicode_scalars", "too_long"))
+    } else {
+        Ok(())
+    }
+}
+
+const fn ascii_identifier(value: &str) -> Result<(), ValidationError> {
+    let bytes = value.as_bytes();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        if !(byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-') {
+            return Err(ValidationError::new(
+                "ascii_identifier",
+                "invalid_character",
+            ));
+        }
+        index += 1;
+    }
+
+    Ok(())
+}
 
 /// Require a string-like value to contain at least one byte.
 #[derive(Debug, Clone, Copy, Default)]
@@ -10,15 +65,19 @@ impl<T> Rule<T> for NonEmpty
 where
     T: AsRef<str>,
 {
+    fn name(&self) -> &'static str {
+        "non_empty"
+    }
+
     fn check(&self, value: &T) -> Result<(), ValidationError> {
-        if value.as_ref().is_empty() {
-            Err(ValidationError::new("non_empty", "empty"))
-        } else {
-            Ok(())
-        }
+        non_empty(value.as_ref())
     }
 }
 
+impl<L: ConstLiteral> ConstRule<L> for NonEmpty {
+    const CHECK: Result<(), ValidationError> = non_empty(L::VALUE);
+}
+
 /// Bound a string-like value by its UTF-8 byte length.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct MaxBytes<const MAX: usize>;
@@ -27,15 +86,19 @@ impl<T, const MAX: usize> Rule<T> for MaxBytes<MAX>
 where
     T: AsRef<str>,
 {
+    fn name(&self) -> &'static str {
+        "max_bytes"
+    }
+
     fn check(&self, value: &T) -> Result<(), ValidationError> {
-        if value.as_ref().len() > MAX {
-            Err(ValidationError::new("max_bytes", "too_long"))
-        } else {
-            Ok(())
-        }
+        max_bytes::<MAX>(value.as_ref())
     }
 }
 
+impl<L: ConstLiteral, const MAX: usize> ConstRule<L> for MaxBytes<MAX> {
+    const CHECK: Result<(), ValidationError> = max_bytes::<MAX>(L::VALUE);
+}
+
 /// Bound a string-like value by its Unicode scalar-value (`char`) count.
 ///
 /// This is distinct from UTF-8 byte length and from grapheme-cluster or
@@ -47,20 +110,28 @@ impl<T, const MAX: usize> Rule<T> for MaxUnicodeScalars<MAX>
 where
     T: AsRef<str>,
 {
+    fn name(&self) -> &'static str {
+        "max_unicode_scalars"
+    }
+
     fn check(&self, value: &T) -> Result<(), ValidationError> {
-        if value.as_ref().chars().count() > MAX {
-            Err(ValidationError::new("max_unicode_scalars", "too_long"))
-        } else {
-            Ok(())
-        }
+        max_unicode_scalars::<MAX>(value.as_ref())
     }
 }
 
+impl<L: ConstLiteral, const MAX: usize> ConstRule<L> for MaxUnicodeScalars<MAX> {
+    const CHECK: Result<(), ValidationError> = max_unicode_scalars::<MAX>(L::VALUE);
+}
+
 /// Require a `u64` value to lie within the closed interval `MIN..=MAX`.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct InclusiveU64<const MIN: u64, const MAX: u64>;
 
 impl<const MIN: u64, const MAX: u64> Rule<u64> for InclusiveU64<MIN, MAX> {
+    fn name(&self) -> &'static str {
+        "inclusive_u64"
+    }
+
     fn check(&self, value: &u64) -> Result<(), ValidationError> {
         if MIN <= *value && *value <= MAX {
             Ok(())
@@ -78,19 +149,15 @@ impl<T> Rule<T> for AsciiIdentifier
 where
     T: AsRef<str>,
 {
-    fn check(&self, value: &T) -> Result<(), ValidationError> {
-        let valid = value
-            .as_ref()
-            .bytes()
-            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-');
+    fn name(&self) -> &'static str {
+        "ascii_identifier"
+    }
 
-        if valid {
-            Ok(())
-        } else {
-            Err(ValidationError::new(
-                "ascii_identifier",
-                "invalid_character",
-            ))
-        }
+    fn check(&self, value: &T) -> Result<(), ValidationError> {
+        ascii_identifier(value.as_ref())
     }
 }
+
+impl<L: ConstLiteral> ConstRule<L> for AsciiIdentifier {
+    const CHECK: Result<(), ValidationError> = ascii_identifier(L::VALUE);
+}
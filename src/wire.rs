@@ -0,0 +1,154 @@
+//! Serde integration for wire boundaries.
+//!
+//! This module requires the `serde` feature. `Validated<T, P>` serializes as
+//! its plain value and deserializes by reading `T` and running
+//! [`validate_all`] with `P`, so a receiver always executes the current policy
+//! before it holds a receipt. [`Projected`] does the same for borrowed input
+//! and a [`ProjectEvidence`] policy. Deserialization errors carry only each
+//! failed rule and code, never the rejected input.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, Serializer};
+
+use crate::proof::Validated;
+use crate::refine::{ProjectEvidence, prove_projected_all};
+use crate::validate::{Policy, ValidationError, validate_all};
+
+/// Render every failure as `rule 'name' failed with code 'code'`, separated by
+/// `; `.
+struct Diagnostics<'errors>(&'errors [ValidationError]);
+
+impl fmt::Display for Diagnostics<'_> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, error) in self.0.iter().enumerate() {
+            if index > 0 {
+                formatter.write_str("; ")?;
+            }
+
+            write!(
+                formatter,
+                "rule '{}' failed with code '{}'",
+                error.rule(),
+                error.code()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Serialize, P> Serialize for Validated<T, P> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, T, P> Deserialize<'de> for Validated<T, P>
+where
+    T: Deserialize<'de>,
+    P: Policy<T>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = T::deserialize(deserializer)?;
+
+        validate_all::<P, T>(value)
+            .map_err(|errors| D::Error::custom(Diagnostics(errors.as_slice())))
+    }
+}
+
+/// The projected evidence of a [`ProjectEvidence`] policy, deserialized from a
+/// borrowed `&'de str` without copying.
+///
+/// Use it as a `#[serde(borrow)]` field. Deserialization borrows the string
+/// from the input buffer and runs [`prove_projected_all`] with `P`, so the
+/// output keeps the same lifetime guarantees as a direct call. Strings that
+/// the format cannot lend, such as JSON strings with escapes, are rejected.
+///
+/// ```
+/// use serde::Deserialize;
+/// use strustegy::prelude::*;
+///
+/// enum ToolNameProof {}
+///
+/// impl ProofPolicy<str> for ToolNameProof {
+///     type Refiners = hlist_ty![TrimmedAsciiIdentifier];
+///
+///     fn refiners() -> Self::Refiners {
+///         hlist![TrimmedAsciiIdentifier]
+///     }
+/// }
+///
+/// impl ProjectEvidence<str> for ToolNameProof {
+///     type Output<'input> = &'input str;
+///
+///     fn project<'input>(
+///         _input: &'input str,
+///         evidence: <Self::Refiners as Prove<str>>::Evidence<'input>,
+///     ) -> Self::Output<'input> {
+///         let hlist_pat![name] = evidence;
+///         name
+///     }
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Request<'input> {
+///     #[serde(borrow)]
+///     tool: Projected<'input, ToolNameProof>,
+/// }
+///
+/// let body = String::from(r#"{"tool":"  sync_status "}"#);
+/// let request: Request<'_> = serde_json::from_str(&body).unwrap();
+///
+/// assert_eq!(*request.tool.get(), "sync_status");
+/// ```
+#[must_use = "projected evidence should be consumed by trusted code"]
+pub struct Projected<'input, P>
+where
+    P: ProjectEvidence<str>,
+{
+    output: P::Output<'input>,
+    _policy: PhantomData<fn() -> P>,
+}
+
+impl<'input, P> Projected<'input, P>
+where
+    P: ProjectEvidence<str>,
+{
+    /// Borrow the projected output.
+    pub fn get(&self) -> &P::Output<'input> {
+        &self.output
+    }
+
+    /// Consume the wrapper and return the projected output.
+    pub fn into_inner(self) -> P::Output<'input> {
+        self.output
+    }
+}
+
+impl<P> fmt::Debug for Projected<'_, P>
+where
+    P: ProjectEvidence<str>,
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("Projected(<redacted>)")
+    }
+}
+
+impl<'de: 'input, 'input, P> Deserialize<'de> for Projected<'input, P>
+where
+    P: ProjectEvidence<str>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let input: &'input str = <&'de str>::deserialize(deserializer)?;
+        let output = prove_projected_all::<P, str>(input)
+            .map_err(|errors| D::Error::custom(Diagnostics(errors.as_slice())))?;
+
+        Ok(Self {
+            output,
+            _policy: PhantomData,
+        })
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg(feature = "blocking")]
+
+use strustegy::prelude::*;
+
+#[test]
+fn block_on_drives_an_async_strategy_to_completion() {
+    let double = async_strategy_fn(async |value: i32| value * 2);
+
+    assert_eq!(block_on(double.apply_async(21)), 42);
+}
+
+#[test]
+fn blocking_adapter_turns_an_async_strategy_into_a_strategy() {
+    let describe = into_blocking(async_strategy_fn(async |value: i32| {
+        format!("value:{value}")
+    }));
+
+    assert_eq!(describe.apply(7), "value:7");
+}
+
+#[test]
+fn blocking_adapter_composes_with_synchronous_strategies() {
+    let parse = strategy_fn(|input: &str| input.parse::<u32>().map_err(|_| "parse"));
+    let bound = into_blocking(async_strategy_fn(async |value: u32| {
+        if value <= 10 {
+            Ok(value * 2)
+        } else {
+            Err("range")
+        }
+    }));
+    let pipeline = parse.and_then(bound);
+
+    assert_eq!(pipeline.apply("4"), Ok(8));
+    assert_eq!(pipeline.apply("bad"), Err("parse"));
+    assert_eq!(pipeline.apply("20"), Err("range"));
+}
+
+#[test]
+fn lifting_and_lowering_round_trips() {
+    let increment = strategy_fn(|value: u8| value + 1);
+    let round_trip = BlockOn::new(into_async(increment));
+
+    assert_eq!(round_trip.apply(1), 2);
+    assert_eq!(round_trip.into_inner().into_inner().apply(2), 3);
+}
+
+#[test]
+fn blocking_adapter_waits_on_pending_futures() {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll};
+
+    struct YieldOnce(bool);
+
+    impl Future for YieldOnce {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+            if self.0 {
+                Poll::Ready(())
+            } else {
+                self.0 = true;
+                context.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    let delayed = into_blocking(async_strategy_fn(async |value: u8| {
+        YieldOnce(false).await;
+        value
+    }));
+
+    assert_eq!(delayed.apply(9), 9);
+}
+
+#[test]
+fn block_on_parks_until_another_thread_wakes_the_future() {
+    use core::future::Future;
+    use core::pin::Pin;
+    use core::task::{Context, Poll, Waker};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct Shared {
+        value: Option<u8>,
+        waker: Option<Waker>,
+    }
+
+    struct Receive(Arc<Mutex<Shared>>);
+
+    impl Future for Receive {
+        type Output = u8;
+
+        fn poll(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<u8> {
+            let mut shared = self.0.lock().unwrap();
+            match shared.value {
+                Some(value) => Poll::Ready(value),
+                None => {
+                    shared.waker = Some(context.waker().clone());
+                    Poll::Pending
+                }
+            }
+        }
+    }
+
+    let shared = Arc::new(Mutex::new(Shared::default()));
+    let sender = Arc::clone(&shared);
+    let handle = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        let mut shared = sender.lock().unwrap();
+        shared.value = Some(5);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    });
+
+    assert_eq!(block_on(Receive(shared)), 5);
+    handle.join().unwrap();
+}
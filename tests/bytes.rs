@@ -0,0 +1,141 @@
+#![cfg(feature = "alloc")]
+
+use strustegy::prelude::*;
+use strustegy::refine::bytes::{
+    LengthPrefixed, Magic, Prefix, U8, U16Be, U16Le, U32Be, U32Le, U64Be, U64Le,
+};
+
+fn within(input: &[u8], view: &[u8]) -> bool {
+    let start = input.as_ptr() as usize;
+    let view_start = view.as_ptr() as usize;
+
+    start <= view_start && view_start + view.len() <= start + input.len()
+}
+
+#[test]
+fn prefix_borrows_a_fixed_size_array() {
+    let input = vec![1, 2, 3, 4, 5];
+    let prefix = Prefix::<4>.refine(input.as_slice()).expect("long enough");
+
+    assert_eq!(prefix, &[1, 2, 3, 4]);
+    assert!(within(&input, prefix));
+    assert_eq!(Prefix::<0>.refine(&[][..]).expect("empty prefix"), &[]);
+    assert_eq!(
+        Prefix::<6>.refine(input.as_slice()).unwrap_err().code(),
+        "too_short"
+    );
+}
+
+#[test]
+fn magic_distinguishes_mismatch_from_short_input() {
+    let magic = Magic::new(b"STR\0");
+
+    assert_eq!(magic.refine(&b"STR\0rest"[..]).expect("magic"), b"STR\0");
+    assert_eq!(magic.refine(&b"HTTP"[..]).unwrap_err().code(), "mismatch");
+    assert_eq!(magic.refine(&b"ST"[..]).unwrap_err().code(), "too_short");
+}
+
+#[test]
+fn fixed_width_integers_honor_offset_and_endianness() {
+    let input = [0x00, 0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0];
+
+    assert_eq!(U8::<1>.refine(&input[..]).expect("u8"), 0x12);
+    assert_eq!(U16Be::<1>.refine(&input[..]).expect("u16"), 0x1234);
+    assert_eq!(U16Le::<1>.refine(&input[..]).expect("u16"), 0x3412);
+    assert_eq!(U32Be::<0>.refine(&input[..]).expect("u32"), 0x0012_3456);
+    assert_eq!(U32Le::<0>.refine(&input[..]).expect("u32"), 0x5634_1200);
+    assert_eq!(
+        U64Be::<1>.refine(&input[..]).expect("u64"),
+        0x1234_5678_9abc_def0
+    );
+    assert_eq!(
+        U64Le::<1>.refine(&input[..]).expect("u64"),
+        0xf0de_bc9a_7856_3412
+    );
+    assert_eq!(
+        U32Be::<6>.refine(&input[..]).unwrap_err().code(),
+        "too_short"
+    );
+    assert_eq!(
+        U16Le::<{ usize::MAX }>
+            .refine(&input[..])
+            .unwrap_err()
+            .code(),
+        "too_short"
+    );
+}
+
+#[test]
+fn length_prefixed_borrows_the_declared_payload() {
+    let input = vec![0, 0, 0, 3, b'a', b'b', b'c', b'!'];
+    let payload = LengthPrefixed::<U32Be>::new()
+        .refine(input.as_slice())
+        .expect("payload");
+
+    assert_eq!(payload, b"abc");
+    assert!(within(&input, payload));
+    assert_eq!(
+        LengthPrefixed::<U16Le<1>>::new()
+            .refine(&[9, 0, 0][..])
+            .expect("empty payload"),
+        b""
+    );
+    assert_eq!(
+        LengthPrefixed::<U32Be>::new()
+            .refine(&[0, 0, 1][..])
+            .unwrap_err()
+            .code(),
+        "too_short"
+    );
+    assert_eq!(
+        LengthPrefixed::<U32Be>::new()
+            .refine(&[0, 0, 0, 4, 1, 2, 3][..])
+            .unwrap_err()
+            .code(),
+        "truncated"
+    );
+    assert_eq!(
+        LengthPrefixed::<U64Le>::new()
+            .refine(&[0xff; 8][..])
+            .unwrap_err()
+            .code(),
+        "truncated"
+    );
+}
+
+enum FrameHeaderProof {}
+
+impl ProofPolicy<[u8]> for FrameHeaderProof {
+    type Refiners = hlist_ty![Magic, U8<4>, U16Le<5>, Prefix<7>, LengthPrefixed<U32Be<7>>];
+
+    fn refiners() -> Self::Refiners {
+        hlist![
+            Magic::new(b"STR\0"),
+            U8,
+            U16Le,
+            Prefix,
+            LengthPrefixed::new(),
+        ]
+    }
+}
+
+#[test]
+fn frame_headers_are_proven_in_one_call() {
+    let frame = b"STR\0\x02\x34\x12\x00\x00\x00\x05hello".to_vec();
+    let hlist_pat![magic, version, flags, header, payload] =
+        prove::<FrameHeaderProof, _>(frame.as_slice())
+            .expect("frame should satisfy the policy")
+            .into_evidence();
+
+    assert_eq!(magic, b"STR\0");
+    assert_eq!((version, flags), (2, 0x1234));
+    assert_eq!(header, &frame[..7]);
+    assert_eq!(payload, b"hello");
+    assert!(within(&frame, payload));
+
+    let errors = prove_all::<FrameHeaderProof, _>(&b"HTTP\x01"[..]).expect_err("bad frame");
+    assert_eq!(
+        errors.iter().map(ValidationError::code).collect::<Vec<_>>(),
+        ["mismatch", "too_short", "too_short", "too_short"]
+    );
+}
@@ -0,0 +1,137 @@
+#![cfg(feature = "alloc")]
+
+use std::borrow::Cow;
+
+use strustegy::prelude::*;
+use strustegy::refine::refiners::{
+    AsciiLowercase, CollapseWhitespace, NormalizeLineEndings, StripPrefix,
+};
+
+#[allow(clippy::ptr_arg)]
+fn borrowed_from(input: &str, evidence: &Cow<'_, str>) -> bool {
+    let start = input.as_ptr() as usize;
+    let view_start = evidence.as_ptr() as usize;
+
+    !evidence.allocated()
+        && start <= view_start
+        && view_start + evidence.len() <= start + input.len()
+}
+
+#[test]
+fn ascii_lowercase_borrows_already_lowercase_input() {
+    let input = String::from("sync_status");
+    let evidence = AsciiLowercase.refine(input.as_str()).expect("infallible");
+
+    assert!(!evidence.allocated());
+    assert!(borrowed_from(&input, &evidence));
+
+    let evidence = AsciiLowercase.refine("Sync_Status").expect("infallible");
+    assert!(evidence.allocated());
+    assert_eq!(evidence, "sync_status");
+}
+
+#[test]
+fn ascii_lowercase_leaves_non_ascii_characters_alone() {
+    let evidence = AsciiLowercase.refine("ÉCOLE").expect("infallible");
+
+    assert!(evidence.allocated());
+    assert_eq!(evidence, "École");
+    assert!(
+        !AsciiLowercase
+            .refine("école")
+            .expect("infallible")
+            .allocated()
+    );
+}
+
+#[test]
+fn collapse_whitespace_borrows_input_that_only_needs_trimming() {
+    let input = String::from("  rose  core  ");
+    let evidence = CollapseWhitespace
+        .refine(input.as_str())
+        .expect("infallible");
+    assert!(evidence.allocated());
+    assert_eq!(evidence, "rose core");
+
+    let input = String::from("\t rose core \n");
+    let evidence = CollapseWhitespace
+        .refine(input.as_str())
+        .expect("infallible");
+    assert!(!evidence.allocated());
+    assert!(borrowed_from(&input, &evidence));
+    assert_eq!(evidence, "rose core");
+}
+
+#[test]
+fn collapse_whitespace_replaces_non_space_separators() {
+    let evidence = CollapseWhitespace
+        .refine("a\tb\u{3000}c")
+        .expect("infallible");
+
+    assert!(evidence.allocated());
+    assert_eq!(evidence, "a b c");
+    assert_eq!(CollapseWhitespace.refine("   ").expect("infallible"), "");
+}
+
+#[test]
+fn strip_prefix_always_borrows() {
+    let strip = StripPrefix::new("refs/heads/");
+    let input = String::from("refs/heads/main");
+
+    let stripped = strip.refine(input.as_str()).expect("infallible");
+    assert_eq!(stripped, "main");
+    assert!(!stripped.allocated());
+    assert!(borrowed_from(&input, &stripped));
+
+    let unchanged = strip.refine("main").expect("infallible");
+    assert_eq!(unchanged, "main");
+    assert!(!unchanged.allocated());
+}
+
+#[test]
+fn normalize_line_endings_allocates_only_for_carriage_returns() {
+    let input = String::from("one\ntwo\n");
+    let evidence = NormalizeLineEndings
+        .refine(input.as_str())
+        .expect("infallible");
+    assert!(!evidence.allocated());
+    assert!(borrowed_from(&input, &evidence));
+
+    let evidence = NormalizeLineEndings
+        .refine("one\r\ntwo\rthree\r\n")
+        .expect("infallible");
+    assert!(evidence.allocated());
+    assert_eq!(evidence, "one\ntwo\nthree\n");
+}
+
+enum DisplayNameProof {}
+
+impl ProofPolicy<str> for DisplayNameProof {
+    type Refiners = hlist_ty![TrimmedNonEmpty, CollapseWhitespace, AsciiLowercase, ByteLen];
+
+    fn refiners() -> Self::Refiners {
+        hlist![TrimmedNonEmpty, CollapseWhitespace, AsciiLowercase, ByteLen]
+    }
+}
+
+#[test]
+fn canonicalizing_refiners_slot_into_proof_policies() {
+    let input = String::from(" rose core ");
+    let hlist_pat![trimmed, collapsed, lowercase, byte_len] =
+        prove::<DisplayNameProof, _>(input.as_str())
+            .expect("input should satisfy the policy")
+            .into_evidence();
+
+    assert_eq!(trimmed, "rose core");
+    assert!(!collapsed.allocated());
+    assert!(!lowercase.allocated());
+    assert!(borrowed_from(&input, &collapsed));
+    assert!(borrowed_from(&input, &lowercase));
+    assert_eq!(byte_len, input.len());
+
+    let hlist_pat![_, collapsed, lowercase, _] = prove::<DisplayNameProof, _>(" Rose  Core ")
+        .expect("input should satisfy the policy")
+        .into_evidence();
+    assert!(collapsed.allocated());
+    assert!(lowercase.allocated());
+}
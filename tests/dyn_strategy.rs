@@ -0,0 +1,129 @@
+#![cfg(feature = "alloc")]
+
+use core::future::Future;
+use core::task::{Context, Poll, Waker};
+use std::pin::pin;
+use std::sync::Arc;
+use std::thread;
+
+use strustegy::prelude::*;
+
+fn ready<F: Future>(future: F) -> F::Output {
+    let mut context = Context::from_waker(Waker::noop());
+
+    match pin!(future).poll(&mut context) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("future should be ready"),
+    }
+}
+
+fn assert_send<T: Send>(_value: &T) {}
+
+fn assert_send_sync<T: Send + Sync>(_value: &T) {}
+
+#[test]
+fn boxed_erases_a_nested_composition_type() {
+    let parse = strategy_fn(|input: &'static str| input.parse::<u32>().map_err(|_| "parse"));
+    let bound = strategy_fn(|value: u32| if value <= 10 { Ok(value) } else { Err("range") });
+    let describe = strategy_fn(|value: u32| Ok(format!("value:{value}")));
+
+    let pipeline: BoxStrategy<&'static str, Result<String, &'static str>> =
+        parse.and_then(bound).and_then(describe).boxed();
+
+    assert_eq!(pipeline.apply("4"), Ok(String::from("value:4")));
+    assert_eq!(pipeline.apply("bad"), Err("parse"));
+    assert_eq!(pipeline.apply("20"), Err("range"));
+}
+
+#[test]
+fn runtime_selection_chooses_between_unrelated_strategy_types() {
+    fn select(name: &str) -> BoxStrategy<String, String> {
+        match name {
+            "upper" => strategy_fn(|value: String| value.to_uppercase()).boxed(),
+            "trim" => strategy_fn(|value: String| value.trim().to_owned()).boxed(),
+            _ => Identity.boxed(),
+        }
+    }
+
+    let stages: Vec<_> = ["trim", "upper", "unknown"].map(select).into();
+    let output = stages
+        .iter()
+        .fold(String::from("  rose  "), |value, stage| stage.apply(value));
+
+    assert_eq!(output, "ROSE");
+}
+
+#[test]
+fn boxed_strategies_keep_composing_statically() {
+    let boxed = BoxStrategy::new(strategy_fn(|value: i32| i64::from(value) * 2));
+    let pipeline = boxed.then(strategy_fn(|value: i64| value + 1));
+
+    assert_eq!(pipeline.apply(20), 41);
+}
+
+#[test]
+fn arc_strategies_are_shared_between_clones() {
+    let shared = ArcStrategy::new(strategy_fn(|value: u8| value + 1));
+    let clone = shared.clone();
+
+    assert_eq!(shared.apply(1), 2);
+    assert_eq!(clone.apply(2), 3);
+}
+
+#[test]
+fn send_variants_cross_threads() {
+    let boxed = SendBoxStrategy::new(strategy_fn(|value: u32| value * 3));
+    let shared = SendArcStrategy::new(strategy_fn(|value: u32| value + 3));
+    assert_send_sync(&boxed);
+    assert_send_sync(&shared);
+
+    let remote = shared.clone();
+    let handle = thread::spawn(move || (boxed.apply(2), remote.apply(2)));
+
+    assert_eq!(handle.join().expect("thread should finish"), (6, 5));
+    assert_eq!(shared.apply(0), 3);
+}
+
+#[test]
+fn dyn_strategy_is_object_safe() {
+    let strategies: [&dyn DynStrategy<i32, i32>; 2] =
+        [&strategy_fn(|value: i32| value + 1), &Identity];
+
+    assert_eq!(strategies.map(|strategy| strategy.apply_dyn(1)), [2, 1]);
+}
+
+#[test]
+fn boxed_async_strategy_returns_a_boxed_future() {
+    let pipeline = async_strategy_fn(async |value: i32| i64::from(value))
+        .then_async(async_strategy_fn(async |value: i64| value * 2))
+        .boxed_async();
+    let erased: &dyn DynAsyncStrategy<i32, i64> = &pipeline;
+
+    assert_eq!(ready(pipeline.apply_async(21)), 42);
+    assert_eq!(ready(erased.apply_boxed(4)), 8);
+}
+
+#[test]
+fn send_boxed_async_strategies_cross_threads() {
+    let offset = Arc::new(async_strategy_fn(async |value: u32| value + 1));
+    let erased: SendBoxAsyncStrategy<u32, u32> = SendBoxAsyncStrategy::new(move |value| {
+        let offset = Arc::clone(&offset);
+        async move { offset.apply_async(value).await * 2 }
+    });
+    assert_send_sync(&erased);
+    assert_send(&erased.apply_async(1));
+
+    let handle = thread::spawn(move || ready(erased.apply_async(20)));
+
+    assert_eq!(handle.join().expect("thread should finish"), 42);
+}
+
+#[test]
+fn erased_debug_output_names_only_the_wrapper() {
+    let boxed = Identity.boxed::<u8>();
+    let async_boxed = BoxAsyncStrategy::new(into_async(Identity));
+    let _: &BoxAsyncStrategy<u8, u8> = &async_boxed;
+
+    assert_eq!(format!("{boxed:?}"), "BoxStrategy");
+    assert_eq!(format!("{async_boxed:?}"), "BoxAsyncStrategy");
+}
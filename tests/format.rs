@@ -0,0 +1,174 @@
+use strustegy::prelude::*;
+use strustegy::refine::format::{
+    Base64, Base64Url, Hex, HexLower, HexUpper, SemVer, SemVerParts, Uuid,
+};
+
+#[test]
+fn uuid_decodes_the_canonical_hyphenated_form() {
+    let uuid = Uuid
+        .refine("67e55044-10b1-426f-9247-BB680E5FE0C8")
+        .expect("canonical uuid");
+
+    assert_eq!(
+        uuid,
+        [
+            0x67, 0xe5, 0x50, 0x44, 0x10, 0xb1, 0x42, 0x6f, 0x92, 0x47, 0xbb, 0x68, 0x0e, 0x5f,
+            0xe0, 0xc8,
+        ]
+    );
+    assert_eq!(
+        Uuid.refine("67e5504410b1426f9247bb680e5fe0c8")
+            .unwrap_err()
+            .code(),
+        "invalid_length"
+    );
+    assert_eq!(
+        Uuid.refine("67e55044-10b1-426f-9247bb680e5fe0c8-")
+            .unwrap_err()
+            .code(),
+        "invalid_syntax"
+    );
+    assert_eq!(
+        Uuid.refine("67e5504-410b1-426f-9247-bb680e5fe0c8")
+            .unwrap_err()
+            .code(),
+        "invalid_syntax"
+    );
+    assert_eq!(
+        Uuid.refine("g7e55044-10b1-426f-9247-bb680e5fe0c8")
+            .unwrap_err()
+            .code(),
+        "invalid_syntax"
+    );
+}
+
+#[test]
+fn fixed_hex_decodes_and_enforces_case() {
+    assert_eq!(
+        HexLower::<4>.refine("deadbeef").expect("lower"),
+        [0xde, 0xad, 0xbe, 0xef]
+    );
+    assert_eq!(HexUpper::<2>.refine("BEEF").expect("upper"), [0xbe, 0xef]);
+    assert_eq!(Hex::<2>.refine("bEeF").expect("mixed"), [0xbe, 0xef]);
+    assert_eq!(
+        HexLower::<4>.refine("DEADBEEF").unwrap_err().code(),
+        "invalid_digit"
+    );
+    assert_eq!(
+        HexUpper::<2>.refine("beef").unwrap_err().code(),
+        "invalid_digit"
+    );
+    assert_eq!(
+        Hex::<2>.refine("beefs").unwrap_err().code(),
+        "invalid_length"
+    );
+    assert_eq!(Hex::<2>.refine("be+f").unwrap_err().code(), "invalid_digit");
+}
+
+#[test]
+fn base64_reports_the_decoded_length() {
+    assert_eq!(Base64.refine("").expect("empty"), 0);
+    assert_eq!(Base64.refine("Zg==").expect("one byte"), 1);
+    assert_eq!(Base64.refine("Zm8=").expect("two bytes"), 2);
+    assert_eq!(Base64.refine("Zm9v").expect("three bytes"), 3);
+    assert_eq!(Base64.refine("+/+/").expect("symbols"), 3);
+
+    assert_eq!(Base64.refine("Zg").unwrap_err().code(), "invalid_padding");
+    assert_eq!(Base64.refine("Zg=").unwrap_err().code(), "invalid_padding");
+    assert_eq!(Base64.refine("Zh==").unwrap_err().code(), "invalid_padding");
+    assert_eq!(Base64.refine("Zm9vY").unwrap_err().code(), "invalid_length");
+    assert_eq!(
+        Base64.refine("-_-_").unwrap_err().code(),
+        "invalid_character"
+    );
+    assert_eq!(
+        Base64.refine("Zg==Zg==").unwrap_err().code(),
+        "invalid_character"
+    );
+}
+
+#[test]
+fn url_safe_base64_accepts_optional_padding() {
+    assert_eq!(Base64Url.refine("-_-_").expect("symbols"), 3);
+    assert_eq!(Base64Url.refine("Zg").expect("unpadded"), 1);
+    assert_eq!(Base64Url.refine("Zg==").expect("padded"), 1);
+    assert_eq!(
+        Base64Url.refine("Zg=").unwrap_err().code(),
+        "invalid_padding"
+    );
+    assert_eq!(
+        Base64Url.refine("+/+/").unwrap_err().code(),
+        "invalid_character"
+    );
+}
+
+#[test]
+fn semver_borrows_every_component() {
+    let input = String::from("1.20.3-rc.1+build.5");
+    let version = SemVer.refine(input.as_str()).expect("semver");
+
+    assert_eq!(
+        version,
+        SemVerParts {
+            major: "1",
+            minor: "20",
+            patch: "3",
+            pre: Some("rc.1"),
+            build: Some("build.5"),
+        }
+    );
+    assert_eq!(version.major.as_ptr(), input.as_ptr());
+
+    let version = SemVer
+        .refine("0.0.0+001")
+        .expect("build may have leading zeros");
+    assert_eq!((version.pre, version.build), (None, Some("001")));
+    assert_eq!(
+        SemVer.refine("1.0.0-x-y.0").expect("hyphenated").pre,
+        Some("x-y.0")
+    );
+}
+
+#[test]
+fn semver_rejects_malformed_components() {
+    for input in ["1.0", "1.0.0.0", "01.0.0", "v1.0.0", "1..0", "1.0.x"] {
+        assert_eq!(
+            SemVer.refine(input).unwrap_err().code(),
+            "invalid_core",
+            "{input}"
+        );
+    }
+
+    for input in ["1.0.0-", "1.0.0-01", "1.0.0-a..b", "1.0.0-a_b"] {
+        assert_eq!(
+            SemVer.refine(input).unwrap_err().code(),
+            "invalid_pre_release",
+            "{input}"
+        );
+    }
+
+    for input in ["1.0.0+", "1.0.0+a..b", "1.0.0+a+b"] {
+        assert_eq!(
+            SemVer.refine(input).unwrap_err().code(),
+            "invalid_build",
+            "{input}"
+        );
+    }
+}
+
+enum ChecksumPolicy {}
+
+validation_policy! {
+    ChecksumPolicy: String => [NonEmpty, HexLower<32>]
+}
+
+#[test]
+fn format_refiners_are_validation_rules() {
+    let checksum = "a".repeat(64);
+
+    assert!(validate_first::<ChecksumPolicy, _>(checksum).is_ok());
+
+    let error = validate_first::<ChecksumPolicy, _>("A".repeat(64)).expect_err("uppercase");
+    assert_eq!((error.rule(), error.code()), ("hex_lower", "invalid_digit"));
+    assert!(Rule::<&str>::check(&SemVer, &"1.2.3").is_ok());
+}
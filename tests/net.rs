@@ -0,0 +1,160 @@
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+use strustegy::prelude::*;
+use strustegy::refine::net::{Cidr, CidrBlock, Hostname, IpAddrRefiner, Port, SocketAddrRefiner};
+
+#[test]
+fn addresses_parse_into_core_net_types() {
+    assert_eq!(
+        IpAddrRefiner.refine("192.0.2.1").expect("ipv4"),
+        IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))
+    );
+    assert_eq!(
+        IpAddrRefiner.refine("::1").expect("ipv6"),
+        IpAddr::V6(Ipv6Addr::LOCALHOST)
+    );
+    assert_eq!(
+        SocketAddrRefiner.refine("[::1]:8443").expect("socket"),
+        SocketAddr::new(IpAddr::V6(Ipv6Addr::LOCALHOST), 8443)
+    );
+
+    for input in ["", "192.0.2", "192.0.2.256", "localhost", " 192.0.2.1"] {
+        let error = IpAddrRefiner.refine(input).expect_err("invalid address");
+        assert_eq!((error.rule(), error.code()), ("ip_addr", "invalid_address"));
+    }
+
+    assert_eq!(
+        SocketAddrRefiner.refine("::1:8443").unwrap_err().code(),
+        "invalid_address"
+    );
+    assert_eq!(
+        SocketAddrRefiner.refine("192.0.2.1").unwrap_err().code(),
+        "invalid_address"
+    );
+}
+
+#[test]
+fn port_enforces_digits_and_range() {
+    let port = Port::<1, { u16::MAX }>;
+
+    assert_eq!(port.refine("443").expect("port"), 443);
+    assert_eq!(port.refine("65535").expect("port"), 65535);
+    assert_eq!(port.refine("0").unwrap_err().code(), "out_of_range");
+    assert_eq!(port.refine("65536").unwrap_err().code(), "out_of_range");
+    assert_eq!(port.refine("+80").unwrap_err().code(), "not_a_number");
+    assert_eq!(port.refine("").unwrap_err().code(), "not_a_number");
+
+    let unprivileged = Port::<1024, 49151>;
+    assert_eq!(unprivileged.refine("8080").expect("port"), 8080);
+    assert_eq!(
+        unprivileged.refine("80").unwrap_err().code(),
+        "out_of_range"
+    );
+    assert_eq!(Port::<0, 0>.refine("0").expect("ephemeral"), 0);
+}
+
+#[test]
+fn hostname_borrows_rfc_1123_labels() {
+    let input = String::from("api-1.example.com");
+    let labels = Hostname.refine(input.as_str()).expect("hostname");
+
+    assert_eq!(labels.as_str(), input);
+    assert_eq!(
+        labels.iter().collect::<Vec<_>>(),
+        ["api-1", "example", "com"]
+    );
+    assert_eq!(labels.iter().next().map(str::as_ptr), Some(input.as_ptr()));
+    assert_eq!(Hostname.refine("1.2.3").expect("digits").count(), 3);
+
+    let longest_label = "a".repeat(63);
+    assert!(Hostname.refine(&longest_label).is_ok());
+
+    assert_eq!(Hostname.refine("").unwrap_err().code(), "empty");
+    assert_eq!(
+        Hostname.refine(&["a"; 128].join(".")).unwrap_err().code(),
+        "too_long"
+    );
+    for input in [
+        "-api.example",
+        "api-.example",
+        "api..example",
+        "example.",
+        "api_1.example",
+        "bücher.example",
+        &"a".repeat(64),
+    ] {
+        assert_eq!(
+            Hostname.refine(input).unwrap_err().code(),
+            "invalid_label",
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn cidr_parses_address_and_prefix() {
+    let block = Cidr.refine("192.0.2.10/24").expect("ipv4 cidr");
+
+    assert_eq!(
+        block,
+        CidrBlock {
+            address: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 10)),
+            prefix_len: 24,
+        }
+    );
+    assert!(block.contains(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 200))));
+    assert!(!block.contains(IpAddr::V4(Ipv4Addr::new(192, 0, 3, 1))));
+    assert!(!block.contains(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+
+    let everything = Cidr.refine("0.0.0.0/0").expect("default route");
+    assert!(everything.contains(IpAddr::V4(Ipv4Addr::BROADCAST)));
+
+    let block = Cidr.refine("2001:db8::/32").expect("ipv6 cidr");
+    assert_eq!(block.prefix_len, 32);
+    assert!(block.contains("2001:db8::1".parse().expect("address")));
+    assert!(!block.contains("2001:db9::1".parse().expect("address")));
+    assert_eq!(Cidr.refine("::1/128").expect("host").prefix_len, 128);
+
+    assert_eq!(
+        Cidr.refine("192.0.2.0").unwrap_err().code(),
+        "missing_prefix"
+    );
+    assert_eq!(
+        Cidr.refine("192.0.2/24").unwrap_err().code(),
+        "invalid_address"
+    );
+    assert_eq!(
+        Cidr.refine("192.0.2.0/33").unwrap_err().code(),
+        "invalid_prefix"
+    );
+    assert_eq!(Cidr.refine("::/129").unwrap_err().code(), "invalid_prefix");
+    assert_eq!(
+        Cidr.refine("192.0.2.0/+8").unwrap_err().code(),
+        "invalid_prefix"
+    );
+    assert_eq!(
+        Cidr.refine("192.0.2.0/").unwrap_err().code(),
+        "invalid_prefix"
+    );
+}
+
+enum PortProof {}
+
+impl ProofPolicy<str> for PortProof {
+    type Refiners = hlist_ty![Port<1024>];
+
+    fn refiners() -> Self::Refiners {
+        hlist![Port]
+    }
+}
+
+#[test]
+fn port_minimum_is_fixed_by_the_proof_policy() {
+    let hlist_pat![port] = prove::<PortProof, _>("5432")
+        .expect("port should satisfy the policy")
+        .into_evidence();
+    assert_eq!(port, 5432);
+
+    let error = prove::<PortProof, _>("80").expect_err("privileged port");
+    assert_eq!((error.rule(), error.code()), ("port", "out_of_range"));
+}
@@ -0,0 +1,173 @@
+#![cfg(feature = "alloc")]
+
+use core::future::Future;
+use core::task::{Context, Poll, Waker};
+use std::pin::pin;
+
+use strustegy::observe::{Event, EventRecorder};
+use strustegy::prelude::*;
+use strustegy::refine::parse::{Ensure, ParseInt};
+use strustegy::refine::refiners::Checked;
+
+pub enum ToolNamePolicy {}
+
+validation_policy! {
+    ToolNamePolicy: String => [NonEmpty, MaxBytes<8>, AsciiIdentifier]
+}
+
+pub enum ToolNameProof {}
+
+impl ProofPolicy<str> for ToolNameProof {
+    type Refiners = hlist_ty![TrimmedAsciiIdentifier, ByteLen];
+
+    fn refiners() -> Self::Refiners {
+        hlist![TrimmedAsciiIdentifier, ByteLen]
+    }
+}
+
+fn ready<F: Future>(future: F) -> F::Output {
+    let mut context = Context::from_waker(Waker::noop());
+
+    match pin!(future).poll(&mut context) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("future should be ready"),
+    }
+}
+
+#[test]
+fn validate_all_observed_reports_every_rule_without_the_value() {
+    let recorder = EventRecorder::new();
+    let rejected = "not/an identifier";
+
+    let errors = validate_all_observed::<ToolNamePolicy, _, _>(String::from(rejected), &recorder)
+        .unwrap_err();
+
+    assert_eq!(
+        recorder.events(),
+        [
+            Event::RulePassed("non_empty"),
+            Event::RuleFailed {
+                rule: "max_bytes",
+                code: "too_long",
+            },
+            Event::RuleFailed {
+                rule: "ascii_identifier",
+                code: "invalid_character",
+            },
+        ]
+    );
+    assert_eq!(
+        errors,
+        validate_all::<ToolNamePolicy, _>(String::from(rejected)).unwrap_err()
+    );
+    assert!(!format!("{:?}", recorder.events()).contains(rejected));
+
+    recorder.clear();
+    assert!(validate_all_observed::<ToolNamePolicy, _, _>(String::from("sync"), &recorder).is_ok());
+    assert_eq!(
+        recorder.events(),
+        [
+            Event::RulePassed("non_empty"),
+            Event::RulePassed("max_bytes"),
+            Event::RulePassed("ascii_identifier"),
+        ]
+    );
+}
+
+#[test]
+fn prove_all_observed_reports_each_refiner() {
+    let recorder = EventRecorder::new();
+
+    let hlist_pat![name, length] = prove_all_observed::<ToolNameProof, _, _>(" sync ", &recorder)
+        .expect("valid tool name")
+        .into_evidence();
+    assert_eq!((name, length), ("sync", 6));
+    assert_eq!(
+        recorder.events(),
+        [
+            Event::RefinerRan {
+                refiner: "trimmed_ascii_identifier",
+                outcome: Ok(()),
+            },
+            Event::RefinerRan {
+                refiner: "byte_len",
+                outcome: Ok(()),
+            },
+        ]
+    );
+
+    recorder.clear();
+    let errors = prove_all_observed::<ToolNameProof, _, _>("a/b", &recorder).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        recorder.events()[0],
+        Event::RefinerRan {
+            refiner: "trimmed_ascii_identifier",
+            outcome: Err(*errors.first().expect("one error")),
+        }
+    );
+}
+
+#[test]
+fn refiner_names_match_the_rules_their_errors_carry() {
+    let parse = ParseInt::<u64>::new();
+    let ensure = Ensure::new(ParseInt::<u64>::new(), InclusiveU64::<1, 10>);
+    let checked = Checked::new(MaxBytes::<4>);
+
+    assert_eq!(parse.name(), parse.refine("x").unwrap_err().rule());
+    assert_eq!(ensure.name(), "parse_int");
+    assert_eq!(
+        checked.name(),
+        checked.refine("too long").unwrap_err().rule()
+    );
+    assert_eq!(Utf8.name(), Utf8.refine(&[0xff][..]).unwrap_err().rule());
+}
+
+#[test]
+fn instrumented_stages_nest_in_execution_order() {
+    let recorder = EventRecorder::new();
+    let trim = strategy_fn(|value: String| value.trim().to_owned()).instrument("trim", &recorder);
+    let length = strategy_fn(|value: String| value.len()).instrument("length", &recorder);
+    let pipeline = trim.then(length).instrument("pipeline", &recorder);
+
+    assert_eq!(pipeline.apply(String::from("  sync  ")), 4);
+    assert_eq!(
+        recorder.events(),
+        [
+            Event::StageEntered("pipeline"),
+            Event::StageEntered("trim"),
+            Event::StageExited("trim"),
+            Event::StageEntered("length"),
+            Event::StageExited("length"),
+            Event::StageExited("pipeline"),
+        ]
+    );
+    assert_eq!(pipeline.stage(), "pipeline");
+}
+
+#[test]
+fn instrumented_async_strategies_exit_after_completion() {
+    let recorder = EventRecorder::new();
+    let double = Instrumented::new(
+        async_strategy_fn(async |value: u32| value * 2),
+        "double",
+        &recorder,
+    );
+
+    assert_eq!(ready(double.apply_async(21)), 42);
+    assert_eq!(
+        recorder.events(),
+        [Event::StageEntered("double"), Event::StageExited("double")]
+    );
+}
+
+#[test]
+fn noop_observer_changes_nothing() {
+    let validated =
+        validate_all_observed::<ToolNamePolicy, _, _>(String::from("sync"), &NoopObserver)
+            .expect("valid tool name");
+    assert_eq!(validated.get(), "sync");
+
+    let doubled = strategy_fn(|value: u32| value * 2).instrument("double", NoopObserver);
+    assert_eq!(doubled.apply(4), 8);
+}
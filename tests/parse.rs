@@ -0,0 +1,164 @@
+use std::net::Ipv4Addr;
+
+use strustegy::prelude::*;
+use strustegy::refine::parse::{Ensure, ParseBool, ParseFloat, ParseFromStr, ParseInt};
+
+#[test]
+fn parse_int_maps_errors_to_stable_codes() {
+    let parse = ParseInt::<u8>::new();
+
+    assert_eq!(parse.refine("42"), Ok(42));
+    assert_eq!(parse.refine("+7"), Ok(7));
+    assert_eq!(
+        parse.refine(""),
+        Err(ValidationError::new("parse_int", "empty"))
+    );
+    assert_eq!(
+        parse.refine("4x"),
+        Err(ValidationError::new("parse_int", "not_a_number"))
+    );
+    assert_eq!(
+        parse.refine(" 4"),
+        Err(ValidationError::new("parse_int", "not_a_number"))
+    );
+    assert_eq!(
+        parse.refine("256"),
+        Err(ValidationError::new("parse_int", "overflow"))
+    );
+    assert_eq!(
+        parse.refine("-1"),
+        Err(ValidationError::new("parse_int", "not_a_number"))
+    );
+    assert_eq!(
+        ParseInt::<i8>::new().refine("-129"),
+        Err(ValidationError::new("parse_int", "underflow"))
+    );
+}
+
+#[test]
+fn parse_int_honours_its_radix() {
+    const HEX: ParseInt<u32> = ParseInt::with_radix(16);
+
+    assert_eq!(HEX.radix(), 16);
+    assert_eq!(HEX.refine("ff"), Ok(255));
+    assert_eq!(ParseInt::<i64>::with_radix(2).refine("-101"), Ok(-5));
+    assert_eq!(
+        HEX.refine("fg"),
+        Err(ValidationError::new("parse_int", "not_a_number"))
+    );
+}
+
+#[test]
+#[should_panic(expected = "radix must be within 2..=36")]
+fn parse_int_rejects_unsupported_radixes() {
+    let _ = ParseInt::<u8>::with_radix(37);
+}
+
+#[test]
+fn parse_float_accepts_only_finite_values() {
+    let parse = ParseFloat::<f64>::new();
+
+    assert_eq!(parse.refine("2.5"), Ok(2.5));
+    assert_eq!(parse.refine("-1e3"), Ok(-1000.0));
+    assert_eq!(
+        parse.refine(""),
+        Err(ValidationError::new("parse_float", "empty"))
+    );
+    assert_eq!(
+        parse.refine("two"),
+        Err(ValidationError::new("parse_float", "not_a_number"))
+    );
+    assert_eq!(
+        parse.refine("inf"),
+        Err(ValidationError::new("parse_float", "non_finite"))
+    );
+    assert_eq!(
+        parse.refine("NaN"),
+        Err(ValidationError::new("parse_float", "non_finite"))
+    );
+    assert_eq!(
+        parse.refine("-Infinity"),
+        Err(ValidationError::new("parse_float", "non_finite"))
+    );
+    assert_eq!(
+        parse.refine("1e400"),
+        Err(ValidationError::new("parse_float", "overflow"))
+    );
+    assert_eq!(
+        parse.refine("-1e400"),
+        Err(ValidationError::new("parse_float", "underflow"))
+    );
+    assert_eq!(
+        ParseFloat::<f32>::new().refine("1e39"),
+        Err(ValidationError::new("parse_float", "overflow"))
+    );
+}
+
+#[test]
+fn parse_bool_uses_its_configured_vocabulary() {
+    let strict = ParseBool::default();
+    assert_eq!(strict.refine("true"), Ok(true));
+    assert_eq!(strict.refine("false"), Ok(false));
+    assert_eq!(
+        strict.refine("TRUE"),
+        Err(ValidationError::new("parse_bool", "not_a_boolean"))
+    );
+
+    let permissive = ParseBool::permissive();
+    assert_eq!(permissive.refine("Yes"), Ok(true));
+    assert_eq!(permissive.refine("OFF"), Ok(false));
+    assert_eq!(
+        permissive.refine("maybe"),
+        Err(ValidationError::new("parse_bool", "not_a_boolean"))
+    );
+
+    let custom = ParseBool::new(&["enabled"], &["disabled"]);
+    assert_eq!(custom.refine("enabled"), Ok(true));
+    assert_eq!(
+        custom.refine("true"),
+        Err(ValidationError::new("parse_bool", "not_a_boolean"))
+    );
+}
+
+#[test]
+fn parse_from_str_supports_any_from_str_type() {
+    let parse = ParseFromStr::<Ipv4Addr>::new();
+
+    assert_eq!(parse.refine("127.0.0.1"), Ok(Ipv4Addr::LOCALHOST));
+    assert_eq!(
+        parse.refine("localhost"),
+        Err(ValidationError::new("parse_from_str", "invalid_syntax"))
+    );
+}
+
+enum TimeoutMillisProof {}
+
+impl ProofPolicy<str> for TimeoutMillisProof {
+    type Refiners = hlist_ty![Ensure<ParseInt<u64>, InclusiveU64<1, 60_000>>, ByteLen];
+
+    fn refiners() -> Self::Refiners {
+        hlist![
+            Ensure::new(ParseInt::new(), InclusiveU64::<1, 60_000>),
+            ByteLen
+        ]
+    }
+}
+
+#[test]
+fn parsing_and_range_checks_share_one_proof_policy() {
+    let witnessed = prove::<TimeoutMillisProof, _>("30000").expect("timeout should be in range");
+    assert_eq!(witnessed.into_evidence(), hlist![30_000_u64, 5]);
+
+    assert_eq!(
+        prove::<TimeoutMillisProof, _>("0").unwrap_err(),
+        ValidationError::new("inclusive_u64", "out_of_range")
+    );
+    assert_eq!(
+        prove::<TimeoutMillisProof, _>("thirty").unwrap_err(),
+        ValidationError::new("parse_int", "not_a_number")
+    );
+    assert_eq!(
+        prove::<TimeoutMillisProof, _>("99999999999999999999").unwrap_err(),
+        ValidationError::new("parse_int", "overflow")
+    );
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "std")]
+
+use std::path::{Path, PathBuf};
+
+use strustegy::prelude::*;
+use strustegy::refine::parse::Ensure;
+use strustegy::refine::refiners::{FileExtensionIn, MaxPathDepth, SafeRelativePath};
+
+#[test]
+fn safe_relative_path_borrows_normalized_components() {
+    let input = String::from("./assets//img/./logo.PNG/");
+    let path = SafeRelativePath
+        .refine(input.as_str())
+        .expect("relative path");
+
+    assert_eq!(path.as_str(), input);
+    assert_eq!(
+        path.components().collect::<Vec<_>>(),
+        ["assets", "img", "logo.PNG"]
+    );
+    assert_eq!(
+        path.components().rev().collect::<Vec<_>>(),
+        ["logo.PNG", "img", "assets"]
+    );
+    assert_eq!(path.depth(), 3);
+    assert_eq!(path.file_name(), "logo.PNG");
+    assert_eq!(path.extension(), Some("PNG"));
+    assert_eq!(
+        path.into_iter().next().map(str::as_ptr),
+        Some(input[2..].as_ptr())
+    );
+
+    let dotfile = SafeRelativePath.refine(".profile").expect("dotfile");
+    assert_eq!(dotfile.extension(), None);
+    assert_eq!(
+        SafeRelativePath
+            .refine("a/..b/c..")
+            .expect("dots inside names")
+            .depth(),
+        3
+    );
+}
+
+#[test]
+fn safe_relative_path_rejects_escapes() {
+    for input in ["", ".", "./", ".//", "./././"] {
+        assert_eq!(
+            SafeRelativePath.refine(input).unwrap_err().code(),
+            "empty",
+            "{input:?}"
+        );
+    }
+
+    for input in ["/etc/passwd", "/", "//server/share"] {
+        assert_eq!(
+            SafeRelativePath.refine(input).unwrap_err().code(),
+            "absolute",
+            "{input:?}"
+        );
+    }
+
+    for input in ["..", "../x", "a/../../x", "a/b/.."] {
+        assert_eq!(
+            SafeRelativePath.refine(input).unwrap_err().code(),
+            "parent_dir",
+            "{input:?}"
+        );
+    }
+
+    for input in ["C:", "c:/windows", "a/D:evil", "Z:x"] {
+        assert_eq!(
+            SafeRelativePath.refine(input).unwrap_err().code(),
+            "drive_prefix",
+            "{input:?}"
+        );
+    }
+
+    for input in ["a\\b", "..\\x", "\\\\server\\share"] {
+        assert_eq!(
+            SafeRelativePath.refine(input).unwrap_err().code(),
+            "backslash",
+            "{input:?}"
+        );
+    }
+
+    let error = SafeRelativePath.refine("a\0b").expect_err("nul");
+    assert_eq!(
+        (error.rule(), error.code()),
+        ("safe_relative_path", "nul_byte")
+    );
+}
+
+#[test]
+fn relative_path_joins_beneath_base() {
+    let base = Path::new("/srv/uploads");
+    let path = SafeRelativePath
+        .refine("user/./reports//q3.csv")
+        .expect("path");
+    let joined = path.join_onto(base);
+
+    assert_eq!(joined, PathBuf::from("/srv/uploads/user/reports/q3.csv"));
+    assert!(joined.starts_with(base));
+}
+
+#[test]
+fn path_rules_check_extension_and_depth() {
+    let images = FileExtensionIn::new(&["png", "jpg"]);
+    let path = SafeRelativePath.refine("a/b/photo.JPG").expect("path");
+
+    assert!(images.check(&path).is_ok());
+    assert_eq!(
+        images
+            .check(&SafeRelativePath.refine("a/archive.tar.gz").expect("path"))
+            .expect_err("extension")
+            .code(),
+        "extension_not_allowed"
+    );
+    assert_eq!(
+        images
+            .check(&SafeRelativePath.refine("a/Makefile").expect("path"))
+            .expect_err("no extension")
+            .code(),
+        "missing_extension"
+    );
+    assert_eq!(
+        images
+            .check(&SafeRelativePath.refine("photo.").expect("path"))
+            .expect_err("empty extension")
+            .code(),
+        "extension_not_allowed"
+    );
+
+    assert!(MaxPathDepth::<3>.check(&path).is_ok());
+    assert_eq!(
+        MaxPathDepth::<2>.check(&path).expect_err("too deep").code(),
+        "too_deep"
+    );
+    assert!(
+        MaxPathDepth::<2>
+            .check(&SafeRelativePath.refine("a//./b/").expect("path"))
+            .is_ok()
+    );
+}
+
+enum UploadPathProof {}
+
+impl ProofPolicy<str> for UploadPathProof {
+    type Refiners = hlist_ty![Ensure<Ensure<SafeRelativePath, MaxPathDepth<4>>, FileExtensionIn>,];
+
+    fn refiners() -> Self::Refiners {
+        hlist![Ensure::new(
+            Ensure::new(SafeRelativePath, MaxPathDepth),
+            FileExtensionIn::new(&["csv", "json"]),
+        )]
+    }
+}
+
+#[test]
+fn path_rules_compose_in_proof_policies() {
+    let hlist_pat![path] = prove::<UploadPathProof, _>("team/exports/2026.json")
+        .expect("upload path should satisfy the policy")
+        .into_evidence();
+
+    assert_eq!(
+        path.join_onto("/data"),
+        PathBuf::from("/data/team/exports/2026.json")
+    );
+
+    let error = prove::<UploadPathProof, _>("../../etc/passwd").expect_err("traversal");
+    assert_eq!(
+        (error.rule(), error.code()),
+        ("safe_relative_path", "parent_dir")
+    );
+    assert_eq!(
+        prove::<UploadPathProof, _>("a/b/c/d/e.csv")
+            .unwrap_err()
+            .code(),
+        "too_deep"
+    );
+}
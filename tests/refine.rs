@@ -1,4 +1,7 @@
+#![cfg(feature = "alloc")]
+
 use strustegy::prelude::*;
+use strustegy::refine::refiners::Checked;
 
 pub enum ToolNameProof {}
 
@@ -119,3 +122,195 @@ fn named_evidence_projection_preserves_borrowed_identity() {
     assert_eq!(projected.source_bytes, input.len());
     assert_eq!(projected.name.as_ptr(), input.as_ptr().wrapping_add(2));
 }
+
+pub enum ChainedToolNameProof {}
+
+impl ProofPolicy<[u8]> for ChainedToolNameProof {
+    type Refiners = hlist_ty![
+        RefineChain<hlist_ty![Utf8, Trimmed, Checked<AsciiIdentifier>]>,
+        ByteLen,
+    ];
+
+    fn refiners() -> Self::Refiners {
+        hlist![
+            RefineChain::new(hlist![Utf8, Trimmed, Checked::new(AsciiIdentifier)]),
+            ByteLen,
+        ]
+    }
+}
+
+#[test]
+fn chained_refiners_consume_the_previous_stage_output() {
+    let bytes = b"  sync_status  ".to_vec();
+    let witnessed = prove::<ChainedToolNameProof, _>(bytes.as_slice())
+        .expect("bytes should satisfy the chained policy");
+
+    let hlist_pat![hlist_pat![text, trimmed, identifier], byte_len] = witnessed.into_evidence();
+
+    assert_eq!(text, "  sync_status  ");
+    assert_eq!(trimmed, "sync_status");
+    assert_eq!(identifier, "sync_status");
+    assert_eq!(byte_len, bytes.len());
+    assert_eq!(text.as_ptr(), bytes.as_ptr());
+    assert_eq!(identifier.as_ptr(), bytes.as_ptr().wrapping_add(2));
+}
+
+#[test]
+fn chained_refinement_stops_at_the_first_failing_stage() {
+    let invalid_utf8 = [b' ', 0xff];
+    let error = prove::<ChainedToolNameProof, _>(invalid_utf8.as_slice())
+        .expect_err("invalid UTF-8 should fail the first stage");
+    assert_eq!((error.rule(), error.code()), ("utf8", "invalid_utf8"));
+
+    let bytes = b" not/allowed ".to_vec();
+    let error = prove::<ChainedToolNameProof, _>(bytes.as_slice())
+        .expect_err("slash should fail the last stage");
+    assert_eq!(
+        (error.rule(), error.code()),
+        ("ascii_identifier", "invalid_character")
+    );
+}
+
+#[test]
+fn single_stage_and_empty_chains_behave_like_their_refiners() {
+    let input = " rose ";
+
+    let single = RefineChain::new(hlist![Trimmed]);
+    assert_eq!(
+        single.refine(input).expect("trim never fails"),
+        hlist!["rose"]
+    );
+
+    let empty = RefineChain::new(hlist![]);
+    assert_eq!(
+        Refine::<str>::refine(&empty, input).expect("empty chain"),
+        hlist![]
+    );
+}
+
+#[test]
+fn chains_nest_as_ordinary_refiners() {
+    let inner = RefineChain::new(hlist![Trimmed, TrimmedNonEmpty]);
+    let outer = RefineChain::new(hlist![Utf8, inner]);
+    let bytes = b" x ".to_vec();
+
+    let hlist_pat![text, hlist_pat![trimmed, non_empty]] =
+        outer.refine(bytes.as_slice()).expect("input should refine");
+
+    assert_eq!((text, trimmed, non_empty), (" x ", "x", "x"));
+}
+
+#[test]
+fn then_refine_chains_two_refiners_through_a_borrowed_view() {
+    let chain = Trimmed.then_refine(Checked::new(AsciiIdentifier));
+    let input = String::from("  sync_status  ");
+
+    let identifier = chain.refine(input.as_str()).expect("input should refine");
+    assert_eq!(identifier, "sync_status");
+    assert_eq!(identifier.as_ptr(), input.as_ptr().wrapping_add(2));
+
+    let error = chain
+        .refine(" not/allowed ")
+        .expect_err("slash should fail");
+    assert_eq!(error.code(), "invalid_character");
+
+    let error = Utf8
+        .then_refine(Trimmed)
+        .refine([0xff].as_slice())
+        .expect_err("invalid UTF-8 should fail the first stage");
+    assert_eq!(error.code(), "invalid_utf8");
+}
+
+pub enum FormFieldProof {}
+
+impl ProofPolicy<str> for FormFieldProof {
+    type Refiners = hlist_ty![TrimmedNonEmpty, TrimmedAsciiIdentifier, Utf8Length];
+
+    fn refiners() -> Self::Refiners {
+        hlist![TrimmedNonEmpty, TrimmedAsciiIdentifier, Utf8Length]
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Utf8Length;
+
+impl Refine<str> for Utf8Length {
+    type Output<'input>
+        = usize
+    where
+        str: 'input;
+
+    fn refine<'input>(&self, input: &'input str) -> Result<Self::Output<'input>, ValidationError> {
+        if input.len() <= 8 {
+            Ok(input.len())
+        } else {
+            Err(ValidationError::new("utf8_length", "too_long"))
+        }
+    }
+}
+
+impl ProjectEvidence<str> for FormFieldProof {
+    type Output<'input> = (&'input str, usize);
+
+    fn project<'input>(
+        _input: &'input str,
+        evidence: <Self::Refiners as Prove<str>>::Evidence<'input>,
+    ) -> Self::Output<'input> {
+        let hlist_pat![_, identifier, length] = evidence;
+        (identifier, length)
+    }
+}
+
+#[test]
+fn prove_all_collects_every_refiner_failure_in_order() {
+    let input = String::from("          ");
+    let errors = prove_all::<FormFieldProof, _>(input.as_str())
+        .expect_err("blank oversized input should fail three refiners");
+
+    let codes: Vec<_> = errors
+        .iter()
+        .map(|error| (error.rule(), error.code()))
+        .collect();
+    assert_eq!(
+        codes,
+        [
+            ("trimmed_non_empty", "empty"),
+            ("trimmed_ascii_identifier", "invalid_identifier"),
+            ("utf8_length", "too_long"),
+        ]
+    );
+
+    let first = prove::<FormFieldProof, _>(input.as_str()).expect_err("prove short-circuits");
+    assert_eq!(errors.first(), Some(&first));
+}
+
+#[test]
+fn prove_all_returns_the_same_evidence_as_prove_on_success() {
+    let input = " status ";
+    let witnessed = prove_all::<FormFieldProof, _>(input).expect("input should satisfy policy");
+
+    assert_eq!(witnessed.get(), input);
+    assert_eq!(
+        witnessed.into_evidence(),
+        prove::<FormFieldProof, _>(input)
+            .expect("input should satisfy policy")
+            .into_evidence()
+    );
+    assert_eq!(
+        FormFieldProof::refiners().prove_all(input),
+        Ok(hlist!["status", "status", 8])
+    );
+}
+
+#[test]
+fn prove_projected_all_projects_or_collects() {
+    assert_eq!(
+        prove_projected_all::<FormFieldProof, _>(" ok "),
+        Ok(("ok", 4))
+    );
+
+    let errors = prove_projected_all::<FormFieldProof, _>(" a/b      ")
+        .expect_err("slash and length should both fail");
+    assert_eq!(errors.len(), 2);
+    assert!(!errors.to_string().contains("a/b"));
+}
@@ -0,0 +1,134 @@
+use strustegy::prelude::*;
+use strustegy::refine::refiners::Checked;
+
+#[derive(Debug, Clone, Copy, Default)]
+struct TrimLowercase;
+
+impl Strategy<String> for TrimLowercase {
+    type Output = String;
+
+    fn apply(&self, input: String) -> Self::Output {
+        input.trim().to_ascii_lowercase()
+    }
+}
+
+/// Count hyphen-separated segments as owned evidence without changing the value.
+#[derive(Debug, Clone, Copy, Default)]
+struct SegmentCount;
+
+impl RefineOwned<String> for SegmentCount {
+    type Output = String;
+    type Evidence = usize;
+
+    fn refine_owned(
+        &self,
+        input: String,
+    ) -> Result<(Self::Output, Self::Evidence), ValidationError> {
+        let count = input.split('-').count();
+        Ok((input, count))
+    }
+}
+
+/// Parse the canonical value into a different owned type.
+#[derive(Debug, Clone, Copy, Default)]
+struct ParsePort;
+
+impl RefineOwned<String> for ParsePort {
+    type Output = u16;
+    type Evidence = ();
+
+    fn refine_owned(
+        &self,
+        input: String,
+    ) -> Result<(Self::Output, Self::Evidence), ValidationError> {
+        input
+            .parse()
+            .map(|port| (port, ()))
+            .map_err(|_| ValidationError::new("parse_port", "not_a_number"))
+    }
+}
+
+enum CanonicalNameProof {}
+
+impl OwnedProofPolicy<String> for CanonicalNameProof {
+    type Refiners = hlist_ty![
+        Canonicalize<TrimLowercase>,
+        Checked<NonEmpty>,
+        Checked<MaxBytes<16>>,
+        Checked<AsciiIdentifier>,
+        SegmentCount,
+    ];
+
+    fn refiners() -> Self::Refiners {
+        hlist![
+            Canonicalize::new(TrimLowercase),
+            Checked::new(NonEmpty),
+            Checked::new(MaxBytes::<16>),
+            Checked::new(AsciiIdentifier),
+            SegmentCount,
+        ]
+    }
+}
+
+enum PortProof {}
+
+impl OwnedProofPolicy<String> for PortProof {
+    type Refiners = hlist_ty![Canonicalize<TrimLowercase>, ParsePort];
+
+    fn refiners() -> Self::Refiners {
+        hlist![Canonicalize::new(TrimLowercase), ParsePort]
+    }
+}
+
+#[test]
+fn canonicalize_then_prove_runs_as_one_policy() {
+    let proven = prove_owned::<CanonicalNameProof, _>(String::from("  Prod-West  "))
+        .expect("canonical form should satisfy the policy");
+
+    assert_eq!(proven.get(), "prod-west");
+    assert_eq!(proven.evidence(), &hlist![(), (), (), (), 2]);
+
+    let (value, hlist_pat![_, _, _, _, segments]) = proven.into_parts();
+    assert_eq!(value, "prod-west");
+    assert_eq!(segments, 2);
+}
+
+#[test]
+fn checks_run_against_the_canonical_value() {
+    let error = prove_owned::<CanonicalNameProof, _>(String::from("    "))
+        .expect_err("blank input is empty once canonicalized");
+    assert_eq!((error.rule(), error.code()), ("non_empty", "empty"));
+
+    let error = prove_owned::<CanonicalNameProof, _>(String::from(" a.b "))
+        .expect_err("dot is not an identifier character");
+    assert_eq!(
+        (error.rule(), error.code()),
+        ("ascii_identifier", "invalid_character")
+    );
+}
+
+#[test]
+fn owned_stages_may_change_the_value_type() {
+    let proven = prove_owned::<PortProof, _>(String::from(" 8080 ")).expect("port should parse");
+    assert_eq!(proven.into_inner(), 8080_u16);
+
+    let error = prove_owned::<PortProof, _>(String::from("http")).expect_err("not a port");
+    assert_eq!(error.code(), "not_a_number");
+}
+
+#[test]
+fn empty_owned_refiner_list_returns_the_input_unchanged() {
+    assert_eq!(
+        HNil.prove_owned(String::from("as-is")),
+        Ok((String::from("as-is"), HNil))
+    );
+}
+
+#[test]
+fn proven_debug_output_is_redacted() {
+    let proven = prove_owned::<CanonicalNameProof, _>(String::from("secret-name"))
+        .expect("name should satisfy the policy");
+
+    assert_eq!(format!("{proven:?}"), "Proven(<redacted>)");
+    assert_eq!(proven.clone(), proven);
+}
@@ -0,0 +1,129 @@
+#![cfg(feature = "alloc")]
+
+use std::thread;
+
+use strustegy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Trim;
+
+impl Strategy<String> for Trim {
+    type Output = Result<String, &'static str>;
+
+    fn apply(&self, input: String) -> Self::Output {
+        Ok(input.trim().to_owned())
+    }
+}
+
+fn normalizers() -> StrategyRegistry<String, &'static str> {
+    StrategyRegistry::new()
+        .register("trim", Trim)
+        .register(
+            "lowercase",
+            strategy_fn(|value: String| Ok(value.to_ascii_lowercase())),
+        )
+        .register(
+            "ascii_only",
+            strategy_fn(|value: String| {
+                if value.is_ascii() {
+                    Ok(value)
+                } else {
+                    Err("non_ascii")
+                }
+            }),
+        )
+}
+
+#[test]
+fn configured_keys_compose_in_order() {
+    let registry = normalizers();
+    let pipeline = registry
+        .compose(["trim", "lowercase", "ascii_only"])
+        .expect("every key is registered");
+
+    assert_eq!(pipeline.len(), 3);
+    assert_eq!(
+        pipeline.apply(String::from("  Rose  ")),
+        Ok(String::from("rose"))
+    );
+    assert_eq!(pipeline.apply(String::from(" Rosé ")), Err("non_ascii"));
+}
+
+#[test]
+fn builder_reports_every_unknown_key() {
+    let registry = normalizers();
+    let error = registry
+        .builder()
+        .stage("trim")
+        .stage("ascii_fold")
+        .stage("lowercase")
+        .stage("reverse")
+        .build()
+        .expect_err("unregistered keys must be rejected");
+
+    assert_eq!(error.keys(), ["ascii_fold", "reverse"]);
+    assert_eq!(
+        error.to_string(),
+        "unknown strategy key(s): ascii_fold, reverse"
+    );
+}
+
+#[test]
+fn empty_pipeline_returns_its_input() {
+    let registry = normalizers();
+    let pipeline = registry
+        .compose(Vec::<String>::new())
+        .expect("no keys are unknown");
+
+    assert!(pipeline.is_empty());
+    assert_eq!(pipeline.apply(String::from(" x ")), Ok(String::from(" x ")));
+}
+
+#[test]
+fn later_registrations_replace_earlier_ones() {
+    let mut registry = normalizers();
+    registry.insert(
+        "trim",
+        strategy_fn(|value: String| Ok(value.replace(' ', ""))),
+    );
+
+    assert_eq!(registry.len(), 3);
+    assert!(registry.contains("trim"));
+    assert_eq!(
+        registry.keys().collect::<Vec<_>>(),
+        ["ascii_only", "lowercase", "trim"]
+    );
+
+    let trim = registry.get("trim").expect("trim is registered");
+    assert_eq!(trim.apply(String::from(" a b ")), Ok(String::from("ab")));
+}
+
+#[test]
+fn pipelines_outlive_their_registry_and_cross_threads() {
+    let pipeline = normalizers()
+        .compose(["trim", "lowercase"])
+        .expect("every key is registered");
+    let remote = pipeline.clone();
+
+    let handle = thread::spawn(move || remote.apply(String::from(" TENANT ")));
+
+    assert_eq!(
+        handle.join().expect("thread should finish"),
+        Ok(String::from("tenant"))
+    );
+    assert_eq!(
+        pipeline.apply(String::from(" Local ")),
+        Ok(String::from("local"))
+    );
+}
+
+#[test]
+fn runtime_pipelines_compose_with_static_strategies() {
+    let registry = normalizers();
+    let pipeline = registry
+        .compose(["trim", "lowercase"])
+        .expect("every key is registered")
+        .and_then(strategy_fn(|value: String| Ok(value.len())));
+
+    assert_eq!(pipeline.apply(String::from("  ABC ")), Ok(3));
+}
@@ -0,0 +1,202 @@
+#![cfg(feature = "alloc")]
+
+use core::cell::Cell;
+use core::future::{Future, pending};
+use core::task::{Context, Poll, Waker};
+use core::time::Duration;
+use std::pin::pin;
+
+use strustegy::prelude::*;
+use strustegy::resilience::{
+    Backoff, Clock, ConstantBackoff, Elapsed, ExponentialBackoff, MockClock, RetryPolicy,
+    RetrySchedule, Timeout,
+};
+
+/// Poll `future` to completion, advancing virtual time whenever it stalls.
+fn drive<F>(clock: &MockClock, future: F) -> F::Output
+where
+    F: Future,
+{
+    let mut context = Context::from_waker(Waker::noop());
+    let mut future = pin!(future);
+
+    loop {
+        match future.as_mut().poll(&mut context) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => assert!(
+                clock.advance_to_next_deadline(),
+                "future stalled without a pending deadline"
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LookupError {
+    Unavailable,
+    TimedOut,
+}
+
+impl From<Elapsed> for LookupError {
+    fn from(_: Elapsed) -> Self {
+        Self::TimedOut
+    }
+}
+
+#[test]
+fn retry_stops_at_the_first_success() {
+    let clock = MockClock::new();
+    let calls = Cell::new(0_u32);
+    let flaky = async_strategy_fn(async |value: u32| {
+        calls.set(calls.get() + 1);
+        if calls.get() < 3 {
+            Err(LookupError::Unavailable)
+        } else {
+            Ok(value * 2)
+        }
+    });
+    let policy = RetryPolicy::new(5, ConstantBackoff::new(Duration::from_millis(10)), &clock);
+    let retrying = flaky.retry(policy);
+
+    assert_eq!(drive(&clock, retrying.apply_async(21)), Ok(42));
+    assert_eq!(calls.get(), 3);
+    assert_eq!(clock.sleeps(), [Duration::from_millis(10); 2]);
+    assert_eq!(clock.now(), Duration::from_millis(20));
+}
+
+#[test]
+fn retry_returns_the_last_error_after_exhausting_attempts() {
+    let clock = MockClock::new();
+    let calls = Cell::new(0_u32);
+    let failing = async_strategy_fn(async |_value: u32| {
+        calls.set(calls.get() + 1);
+        Err::<u32, _>(LookupError::Unavailable)
+    });
+    let backoff = ExponentialBackoff::new(Duration::from_millis(10), Duration::from_millis(30));
+    let retrying = failing.retry(RetryPolicy::new(4, backoff, &clock));
+
+    assert_eq!(
+        drive(&clock, retrying.apply_async(1)),
+        Err(LookupError::Unavailable)
+    );
+    assert_eq!(calls.get(), 4);
+    assert_eq!(
+        clock.sleeps(),
+        [10, 20, 30].map(Duration::from_millis).as_slice()
+    );
+}
+
+#[test]
+fn zero_attempts_still_run_the_strategy_once() {
+    let clock = MockClock::new();
+    let policy = RetryPolicy::new(0, ConstantBackoff::default(), &clock);
+    let retrying = async_strategy_fn(async |_value: u8| Err::<u8, _>("down")).retry(policy);
+
+    assert_eq!(policy.attempts(), 1);
+    assert_eq!(drive(&clock, retrying.apply_async(0)), Err("down"));
+    assert!(clock.sleeps().is_empty());
+}
+
+/// Retry immediately, recording each retry number instead of sleeping.
+struct Immediate<'log> {
+    attempts: u32,
+    retries: &'log Cell<u32>,
+}
+
+impl RetrySchedule for Immediate<'_> {
+    fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    async fn wait(&self, retry: u32) {
+        self.retries.set(retry);
+    }
+}
+
+#[test]
+fn retry_accepts_a_custom_schedule() {
+    let calls = Cell::new(0_u32);
+    let retries = Cell::new(0_u32);
+    let failing = async_strategy_fn(async |_value: u32| {
+        calls.set(calls.get() + 1);
+        Err::<u32, _>(LookupError::Unavailable)
+    });
+    let retrying = failing.retry(Immediate {
+        attempts: 3,
+        retries: &retries,
+    });
+
+    assert_eq!(
+        drive(&MockClock::new(), retrying.apply_async(1)),
+        Err(LookupError::Unavailable)
+    );
+    assert_eq!((calls.get(), retries.get()), (3, 2));
+}
+
+#[test]
+fn exponential_backoff_saturates_at_its_maximum() {
+    let backoff = ExponentialBackoff::new(Duration::from_secs(1), Duration::from_secs(60));
+
+    assert_eq!(backoff.delay(1), Duration::from_secs(1));
+    assert_eq!(backoff.delay(4), Duration::from_secs(8));
+    assert_eq!(backoff.delay(7), Duration::from_secs(60));
+    assert_eq!(backoff.delay(u32::MAX), Duration::from_secs(60));
+}
+
+#[test]
+fn timeout_passes_through_results_that_finish_in_time() {
+    let clock = MockClock::new();
+    let slow = async_strategy_fn(async |value: u32| {
+        clock.sleep(Duration::from_millis(5)).await;
+        Ok::<_, LookupError>(value + 1)
+    });
+    let bounded = slow.timeout(Duration::from_millis(10), &clock);
+
+    assert_eq!(drive(&clock, bounded.apply_async(1)), Ok(2));
+    assert_eq!(clock.now(), Duration::from_millis(5));
+}
+
+#[test]
+fn timeout_resolves_to_an_error_when_the_deadline_passes() {
+    let clock = MockClock::new();
+    let slow = async_strategy_fn(async |value: u32| {
+        clock.sleep(Duration::from_millis(50)).await;
+        Ok::<_, LookupError>(value)
+    });
+    let bounded = slow.timeout(Duration::from_millis(10), &clock);
+
+    assert_eq!(
+        drive(&clock, bounded.apply_async(1)),
+        Err(LookupError::TimedOut)
+    );
+    assert_eq!(clock.now(), Duration::from_millis(10));
+}
+
+#[test]
+fn timeout_bounds_futures_that_never_complete() {
+    let clock = MockClock::new();
+    let stuck = async_strategy_fn(async |_value: u32| pending::<Result<u32, Elapsed>>().await);
+    let bounded = Timeout::new(stuck, Duration::from_secs(1), &clock);
+
+    assert_eq!(drive(&clock, bounded.apply_async(1)), Err(Elapsed));
+}
+
+#[test]
+fn timed_out_attempts_are_retried() {
+    let clock = MockClock::new();
+    let calls = Cell::new(0_u32);
+    let lookup = async_strategy_fn(async |value: u32| {
+        calls.set(calls.get() + 1);
+        let delay = if calls.get() == 1 { 100 } else { 1 };
+        clock.sleep(Duration::from_millis(delay)).await;
+        Ok::<_, LookupError>(value)
+    });
+    let policy = RetryPolicy::new(3, ConstantBackoff::new(Duration::from_millis(5)), &clock);
+    let resilient = lookup
+        .timeout(Duration::from_millis(20), &clock)
+        .retry(policy);
+
+    assert_eq!(drive(&clock, resilient.apply_async(9)), Ok(9));
+    assert_eq!(calls.get(), 2);
+    assert_eq!(clock.now(), Duration::from_millis(26));
+}
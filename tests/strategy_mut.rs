@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use strustegy::prelude::*;
+
+#[test]
+fn closure_adapter_updates_captured_state() {
+    let mut seen = HashSet::new();
+    let mut dedup = strategy_fn_mut(|name: &'static str| {
+        if seen.insert(name) {
+            Ok(name)
+        } else {
+            Err("duplicate")
+        }
+    });
+
+    assert_eq!(dedup.apply_mut("alpha"), Ok("alpha"));
+    assert_eq!(dedup.apply_mut("beta"), Ok("beta"));
+    assert_eq!(dedup.apply_mut("alpha"), Err("duplicate"));
+}
+
+#[test]
+fn running_total_composes_with_a_shared_strategy() {
+    let mut total = 0_u64;
+    let widen = strategy_fn(u64::from);
+
+    {
+        let mut pipeline = (&widen).then_mut(strategy_fn_mut(|amount: u64| {
+            total += amount;
+            total
+        }));
+
+        assert_eq!(pipeline.apply_mut(3_u32), 3);
+        assert_eq!(pipeline.apply_mut(4_u32), 7);
+    }
+
+    assert_eq!(total, 7);
+}
+
+#[test]
+fn every_strategy_is_a_stateful_strategy_by_reference() {
+    fn run<S: StrategyMut<i32>>(mut strategy: S, input: i32) -> S::Output {
+        strategy.apply_mut(input)
+    }
+
+    let double = strategy_fn(|value: i32| value * 2);
+
+    assert_eq!(run(&double, 21), 42);
+    assert_eq!(double.apply(21), 42);
+}
+
+#[test]
+fn into_strategy_mut_owns_a_shared_strategy() {
+    let mut double = strategy_fn(|value: i32| value * 2).into_strategy_mut();
+
+    let doubled = hlist![1, 2, 3].hmap_stateful(&mut double);
+
+    assert_eq!(doubled, hlist![2, 4, 6]);
+    assert_eq!(double.into_inner().apply(21), 42);
+}
+
+#[test]
+fn and_then_mut_leaves_later_state_untouched_after_an_error() {
+    let mut counter = strategy_fn_mut({
+        let mut count = 0_usize;
+        move |value: i32| {
+            count += 1;
+            Ok::<_, &'static str>((count, value))
+        }
+    });
+    let reject_negative = strategy_fn(|value: i32| {
+        if value < 0 {
+            Err("negative")
+        } else {
+            Ok(value)
+        }
+    });
+
+    let mut pipeline = (&reject_negative).and_then_mut(counter.by_mut());
+
+    assert_eq!(pipeline.apply_mut(5), Ok((1, 5)));
+    assert_eq!(pipeline.apply_mut(-1), Err("negative"));
+    assert_eq!(pipeline.apply_mut(6), Ok((2, 6)));
+}
+
+#[test]
+fn hmap_mut_threads_state_across_heterogeneous_values() {
+    #[derive(Default)]
+    struct Sequence {
+        next: u32,
+    }
+
+    impl<T> StrategyMut<T> for Sequence {
+        type Output = (u32, T);
+
+        fn apply_mut(&mut self, input: T) -> Self::Output {
+            self.next += 1;
+            (self.next, input)
+        }
+    }
+
+    let mut sequence = Sequence::default();
+    let numbered = hlist!["first", 2_u8, true].hmap_stateful(&mut sequence);
+
+    assert_eq!(numbered, hlist![(1, "first"), (2, 2_u8), (3, true)]);
+    assert_eq!(sequence.next, 3);
+}
+
+#[test]
+fn adapter_exposes_and_returns_the_original_callable() {
+    let mut strategy = FnMutStrategy::new(|value: u8| value + 1);
+
+    assert_eq!((strategy.get_ref())(1), 2);
+    assert_eq!(strategy.apply_mut(1), 2);
+    assert_eq!((strategy.into_inner())(2), 3);
+}
@@ -0,0 +1,273 @@
+#![cfg(feature = "std")]
+
+use core::time::Duration;
+
+use strustegy::prelude::*;
+use strustegy::refine::parse::Ensure;
+use strustegy::refine::time::{
+    FixedClock, HumanDuration, MaxDuration, NotAfter, NotBefore, Rfc3339, SystemClock, Timestamp,
+    WallClock,
+};
+
+#[test]
+fn human_duration_sums_descending_units() {
+    assert_eq!(
+        HumanDuration.refine("30s").expect("seconds"),
+        Duration::from_secs(30)
+    );
+    assert_eq!(
+        HumanDuration.refine("1h30m").expect("hours and minutes"),
+        Duration::from_secs(5_400)
+    );
+    assert_eq!(
+        HumanDuration.refine("2d4h").expect("days"),
+        Duration::from_secs(187_200)
+    );
+    assert_eq!(
+        HumanDuration.refine("1s250ms").expect("milliseconds"),
+        Duration::from_millis(1_250)
+    );
+    assert_eq!(
+        HumanDuration.refine("90m").expect("large minutes"),
+        Duration::from_secs(5_400)
+    );
+    assert_eq!(HumanDuration.refine("0s").expect("zero"), Duration::ZERO);
+    assert_eq!(
+        HumanDuration.refine("3us7ns").expect("sub-millisecond"),
+        Duration::from_nanos(3_007)
+    );
+}
+
+#[test]
+fn human_duration_rejects_malformed_input() {
+    assert_eq!(HumanDuration.refine("").unwrap_err().code(), "empty");
+
+    for input in ["s", "h30m", "-5s"] {
+        assert_eq!(
+            HumanDuration.refine(input).unwrap_err().code(),
+            "invalid_number",
+            "{input}"
+        );
+    }
+
+    for input in ["30", "5 s", "1h m", "1.5s", "1hour", "2w", "10S", "1h30m "] {
+        assert_eq!(
+            HumanDuration.refine(input).unwrap_err().code(),
+            "invalid_unit",
+            "{input}"
+        );
+    }
+
+    for input in ["30m1h", "1s1s", "5ms2s"] {
+        assert_eq!(
+            HumanDuration.refine(input).unwrap_err().code(),
+            "unit_order",
+            "{input}"
+        );
+    }
+
+    let error = HumanDuration
+        .refine("99999999999999999999s")
+        .expect_err("overflow");
+    assert_eq!((error.rule(), error.code()), ("human_duration", "overflow"));
+    assert_eq!(
+        HumanDuration
+            .refine("18446744073709551615d")
+            .unwrap_err()
+            .code(),
+        "overflow"
+    );
+}
+
+#[test]
+fn max_duration_bounds_whole_seconds() {
+    assert!(MaxDuration::<60>.check(&Duration::from_secs(60)).is_ok());
+    assert_eq!(
+        MaxDuration::<60>
+            .check(&Duration::new(60, 1))
+            .expect_err("too long")
+            .code(),
+        "too_long"
+    );
+}
+
+#[test]
+fn rfc3339_parses_fields_and_offset() {
+    let timestamp = Rfc3339
+        .refine("2026-10-19T08:30:00.25+02:00")
+        .expect("timestamp");
+
+    assert_eq!(
+        timestamp,
+        Timestamp {
+            year: 2026,
+            month: 10,
+            day: 19,
+            hour: 8,
+            minute: 30,
+            second: 0,
+            nanosecond: 250_000_000,
+            offset_minutes: 120,
+        }
+    );
+    assert_eq!(timestamp.unix_seconds(), 1_792_398_600 - 7_200);
+
+    let utc = Rfc3339.refine("2026-10-19t08:30:00z").expect("lowercase");
+    assert_eq!(utc.unix_seconds(), 1_792_398_600);
+
+    let before_epoch = Rfc3339.refine("1969-12-31T23:00:00Z").expect("pre-epoch");
+    assert_eq!(before_epoch.unix_seconds(), -3_600);
+
+    let leap_day = Rfc3339
+        .refine("2000-02-29T12:00:00.000000001-05:30")
+        .expect("leap day");
+    assert_eq!(
+        (leap_day.unix_seconds(), leap_day.nanosecond),
+        (951_845_400, 1)
+    );
+    assert_eq!(leap_day.offset_minutes, -330);
+
+    assert_eq!(
+        Rfc3339
+            .refine("2016-12-31T23:59:60Z")
+            .expect("leap second")
+            .second,
+        60
+    );
+    assert!(Rfc3339.refine("2016-12-31T18:59:60-05:00").is_ok());
+}
+
+#[test]
+fn rfc3339_rejects_malformed_timestamps() {
+    for input in [
+        "",
+        "2026-10-19",
+        "2026-10-19 08:30:00Z",
+        "2026-10-19T08:30Z",
+        "2026-10-19T08:30:00",
+    ] {
+        assert_eq!(
+            Rfc3339.refine(input).unwrap_err().code(),
+            "invalid_syntax",
+            "{input}"
+        );
+    }
+
+    for input in [
+        "2026-13-01T00:00:00Z",
+        "2026-00-10T00:00:00Z",
+        "2026-02-29T00:00:00Z",
+        "1900-02-29T00:00:00Z",
+        "2026-04-31T00:00:00Z",
+        "2026/10/19T00:00:00Z",
+        "+026-10-19T00:00:00Z",
+    ] {
+        assert_eq!(
+            Rfc3339.refine(input).unwrap_err().code(),
+            "invalid_date",
+            "{input}"
+        );
+    }
+
+    for input in [
+        "2026-10-19T24:00:00Z",
+        "2026-10-19T23:60:00Z",
+        "2026-10-19T23:59:61Z",
+        "2026-10-19T08:30:60Z",
+        "2016-12-31T23:59:60+01:00",
+        "2026-10-19T08-30-00Z",
+        "2026-10-19T08:30:00.Z",
+        "2026-10-19T08:30:00.1234567891Z",
+    ] {
+        assert_eq!(
+            Rfc3339.refine(input).unwrap_err().code(),
+            "invalid_time",
+            "{input}"
+        );
+    }
+
+    for input in [
+        "2026-10-19T08:30:00.5",
+        "2026-10-19T08:30:00+0200",
+        "2026-10-19T08:30:00+24:00",
+        "2026-10-19T08:30:00UTC",
+        "2026-10-19T08:30:00Zjunk",
+    ] {
+        assert_eq!(
+            Rfc3339.refine(input).unwrap_err().code(),
+            "invalid_offset",
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn not_before_and_not_after_use_the_injected_clock() {
+    let clock = FixedClock::new(Duration::new(1_792_398_600, 500));
+    let now = Rfc3339.refine("2026-10-19T08:30:00.0000005Z").expect("now");
+    let earlier = Rfc3339
+        .refine("2026-10-19T10:29:59+02:00")
+        .expect("earlier");
+    let later = Rfc3339
+        .refine("2026-10-19T08:30:00.000001Z")
+        .expect("later");
+
+    assert!(NotBefore::new(clock).check(&now).is_ok());
+    assert!(NotBefore::new(clock).check(&later).is_ok());
+    assert_eq!(
+        NotBefore::new(clock)
+            .check(&earlier)
+            .expect_err("past deadline")
+            .code(),
+        "too_early"
+    );
+
+    assert!(NotAfter::new(&clock).check(&now).is_ok());
+    assert!(NotAfter::new(&clock).check(&earlier).is_ok());
+    let error = NotAfter::new(&clock).check(&later).expect_err("future");
+    assert_eq!((error.rule(), error.code()), ("not_after", "too_late"));
+
+    let issued = Rfc3339.refine("2000-01-01T00:00:00Z").expect("past");
+    assert!(NotAfter::<SystemClock>::default().check(&issued).is_ok());
+    assert!(SystemClock.now() > Duration::from_secs(1_700_000_000));
+}
+
+enum DeadlineProof {}
+
+impl ProofPolicy<str> for DeadlineProof {
+    type Refiners = hlist_ty![Ensure<Rfc3339, NotBefore<FixedClock>>];
+
+    fn refiners() -> Self::Refiners {
+        hlist![Ensure::new(
+            Rfc3339,
+            NotBefore::new(FixedClock::new(Duration::from_secs(1_792_398_600))),
+        )]
+    }
+}
+
+enum TimeoutPolicy {}
+
+validation_policy! {
+    TimeoutPolicy: Duration => [MaxDuration<60>]
+}
+
+#[test]
+fn time_refiners_compose_with_policies() {
+    let hlist_pat![deadline] = prove::<DeadlineProof, _>("2026-12-31T23:59:59Z")
+        .expect("future deadline")
+        .into_evidence();
+    assert_eq!((deadline.year, deadline.month), (2026, 12));
+    assert_eq!(
+        prove::<DeadlineProof, _>("2025-01-01T00:00:00Z")
+            .unwrap_err()
+            .code(),
+        "too_early"
+    );
+
+    let timeout = HumanDuration.refine("45s").expect("duration");
+    assert!(validate_first::<TimeoutPolicy, _>(timeout).is_ok());
+    assert!(
+        validate_first::<TimeoutPolicy, _>(HumanDuration.refine("1m1s").expect("duration"))
+            .is_err()
+    );
+}
@@ -0,0 +1,148 @@
+use strustegy::prelude::*;
+use strustegy::refine::token::{KeyValue, KeyValuePair, Segments, SplitExact, SplitOnce};
+
+fn within(input: &str, view: &str) -> bool {
+    let start = input.as_ptr() as usize;
+    let view_start = view.as_ptr() as usize;
+
+    start <= view_start && view_start + view.len() <= start + input.len()
+}
+
+#[test]
+fn split_once_borrows_both_sides() {
+    let input = String::from("host:8080:extra");
+    let (host, rest) = SplitOnce::<':'>.refine(input.as_str()).expect("delimited");
+
+    assert_eq!((host, rest), ("host", "8080:extra"));
+    assert!(within(&input, host));
+    assert!(within(&input, rest));
+    assert_eq!(SplitOnce::<':'>.refine(":").expect("delimited"), ("", ""));
+    assert_eq!(
+        SplitOnce::<':'>.refine("host").unwrap_err().code(),
+        "missing_token"
+    );
+}
+
+#[test]
+fn split_exact_requires_the_exact_arity() {
+    let input = String::from("GET /projects STR/1");
+    let tokens = SplitExact::<' ', 3>
+        .refine(input.as_str())
+        .expect("three tokens");
+
+    assert_eq!(tokens, ["GET", "/projects", "STR/1"]);
+    assert!(tokens.iter().all(|token| within(&input, token)));
+    assert_eq!(
+        SplitExact::<',', 2>.refine("a,b").expect("comma tokens"),
+        ["a", "b"]
+    );
+    assert_eq!(
+        SplitExact::<' ', 3>
+            .refine("GET /projects")
+            .unwrap_err()
+            .code(),
+        "missing_token"
+    );
+    assert_eq!(
+        SplitExact::<' ', 3>.refine("").unwrap_err().code(),
+        "missing_token"
+    );
+    assert_eq!(
+        SplitExact::<' ', 3>
+            .refine("GET / STR/1 x")
+            .unwrap_err()
+            .code(),
+        "too_many_tokens"
+    );
+    assert_eq!(
+        SplitExact::<' ', 3>
+            .refine("GET  / STR/1")
+            .unwrap_err()
+            .code(),
+        "too_many_tokens"
+    );
+    assert_eq!(
+        SplitExact::<' ', 3>.refine("GET  /").unwrap_err().code(),
+        "empty_token"
+    );
+    assert_eq!(SplitExact::<' ', 0>.refine("").expect("no tokens"), [""; 0]);
+    assert_eq!(
+        SplitExact::<' ', 0>.refine(" ").unwrap_err().code(),
+        "too_many_tokens"
+    );
+}
+
+#[test]
+fn key_value_requires_a_separator_and_a_key() {
+    let input = String::from("mode=a=b");
+    let pair = KeyValue::<'='>.refine(input.as_str()).expect("pair");
+
+    assert_eq!(
+        pair,
+        KeyValuePair {
+            key: "mode",
+            value: "a=b",
+        }
+    );
+    assert!(within(&input, pair.key));
+    assert_eq!(KeyValue::<'='>.refine("flag=").expect("pair").value, "");
+    assert_eq!(
+        KeyValue::<'='>.refine("flag").unwrap_err().code(),
+        "missing_token"
+    );
+    assert_eq!(
+        KeyValue::<'='>.refine("=value").unwrap_err().code(),
+        "empty_token"
+    );
+}
+
+#[test]
+fn segments_iterate_non_empty_borrowed_tokens() {
+    let input = String::from("projects/rose/core");
+    let segments = Segments::<'/'>.refine(input.as_str()).expect("segments");
+
+    assert_eq!(segments.count(), 3);
+    assert_eq!(segments.as_str(), input);
+    assert!(segments.iter().all(|segment| within(&input, segment)));
+    assert_eq!(
+        segments.into_iter().collect::<Vec<_>>(),
+        ["projects", "rose", "core"]
+    );
+    assert_eq!(
+        Segments::<'/'>.refine("").unwrap_err().code(),
+        "missing_token"
+    );
+    assert_eq!(
+        Segments::<'/'>.refine("/projects").unwrap_err().code(),
+        "empty_token"
+    );
+    assert_eq!(
+        Segments::<'/'>.refine("a//b").unwrap_err().code(),
+        "empty_token"
+    );
+    assert_eq!(
+        Segments::<'/'>.refine("a/").unwrap_err().code(),
+        "empty_token"
+    );
+}
+
+enum SettingProof {}
+
+impl ProofPolicy<str> for SettingProof {
+    type Refiners = hlist_ty![KeyValue<'='>, SplitOnce<'='>];
+
+    fn refiners() -> Self::Refiners {
+        hlist![KeyValue::<'='>, SplitOnce::<'='>]
+    }
+}
+
+#[test]
+fn key_value_and_split_once_agree_on_the_borrowed_split() {
+    let input = String::from("retries=3");
+    let hlist_pat![pair, (key, value)] = prove::<SettingProof, _>(input.as_str())
+        .expect("input should satisfy the policy")
+        .into_evidence();
+
+    assert_eq!((pair.key, pair.value), (key, value));
+    assert!(within(&input, key) && within(&input, value));
+}
@@ -0,0 +1,160 @@
+#![cfg(feature = "tracing")]
+
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use strustegy::prelude::*;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event as TracingEvent, Metadata, Subscriber};
+
+#[derive(Clone, Default)]
+struct Log {
+    lines: Arc<Mutex<Vec<String>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl Log {
+    fn push(&self, line: String) {
+        self.lines.lock().expect("log lock").push(line);
+    }
+
+    fn lines(&self) -> Vec<String> {
+        self.lines.lock().expect("log lock").clone()
+    }
+}
+
+impl Subscriber for Log {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) + 1;
+        self.push(format!("new {} {id}", span.metadata().name()));
+        Id::from_u64(id)
+    }
+
+    fn record(&self, _span: &Id, _values: &Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, event: &TracingEvent<'_>) {
+        self.push(format!("{} event", event.metadata().level()));
+    }
+
+    fn enter(&self, span: &Id) {
+        self.push(format!("enter {}", span.into_u64()));
+    }
+
+    fn exit(&self, span: &Id) {
+        self.push(format!("exit {}", span.into_u64()));
+    }
+}
+
+pub enum ToolNamePolicy {}
+
+validation_policy! {
+    ToolNamePolicy: String => [NonEmpty, MaxBytes<8>]
+}
+
+#[test]
+fn tracing_observer_opens_a_span_per_stage() {
+    let log = Log::default();
+    let observer = TracingObserver::new();
+    let validate = strategy_fn(|value: String| {
+        validate_all_observed::<ToolNamePolicy, _, _>(value, &observer)
+    });
+    let stage = Instrumented::new(validate, "validate", &observer);
+
+    tracing::subscriber::with_default(log.clone(), || {
+        assert!(stage.apply(String::from("too long for policy")).is_err());
+    });
+
+    assert_eq!(
+        log.lines(),
+        [
+            "new strustegy.stage 1",
+            "enter 1",
+            "TRACE event",
+            "DEBUG event",
+            "exit 1",
+        ]
+    );
+}
+
+#[derive(Default)]
+struct YieldOnce {
+    yielded: bool,
+}
+
+impl Future for YieldOnce {
+    type Output = ();
+
+    fn poll(mut self: core::pin::Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        if self.yielded {
+            Poll::Ready(())
+        } else {
+            self.yielded = true;
+            context.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+struct Pause;
+
+impl AsyncStrategy<()> for Pause {
+    type Output = ();
+
+    async fn apply_async(&self, input: ()) {
+        YieldOnce::default().await;
+        input
+    }
+}
+
+#[test]
+fn tracing_observer_scopes_each_span_to_its_own_future() {
+    fn assert_shareable<T: Send + Sync>() {}
+    assert_shareable::<TracingObserver>();
+
+    let log = Log::default();
+    let observer = TracingObserver::new();
+    let first = Instrumented::new(Pause, "first", observer);
+    let second = Instrumented::new(Pause, "second", observer);
+    let mut context = Context::from_waker(Waker::noop());
+
+    let polled = tracing::subscriber::with_default(log.clone(), || {
+        let mut first = pin!(first.apply_async(()));
+        let mut second = pin!(second.apply_async(()));
+
+        assert!(first.as_mut().poll(&mut context).is_pending());
+        assert!(second.as_mut().poll(&mut context).is_pending());
+        assert!(first.as_mut().poll(&mut context).is_ready());
+        assert!(second.as_mut().poll(&mut context).is_ready());
+        log.lines()
+    });
+
+    // A completed stage re-enters its span once more to drop the inner future.
+    assert_eq!(
+        polled,
+        [
+            "new strustegy.stage 1",
+            "enter 1",
+            "exit 1",
+            "new strustegy.stage 2",
+            "enter 2",
+            "exit 2",
+            "enter 1",
+            "exit 1",
+            "enter 1",
+            "exit 1",
+            "enter 2",
+            "exit 2",
+            "enter 2",
+            "exit 2",
+        ]
+    );
+}
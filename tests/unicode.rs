@@ -0,0 +1,149 @@
+#![cfg(feature = "unicode")]
+
+use strustegy::prelude::*;
+use strustegy::refine::unicode::{
+    GraphemeCount, MaxGraphemes, Nfc, NoBidiControls, SingleScript, graphemes,
+};
+
+fn code(result: Result<(), ValidationError>) -> &'static str {
+    match result {
+        Ok(()) => panic!("input should be rejected"),
+        Err(error) => error.code(),
+    }
+}
+
+#[test]
+fn graphemes_follow_extended_cluster_boundaries() {
+    let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+    let flags = "\u{1F1EF}\u{1F1F5}\u{1F1FA}\u{1F1F8}";
+    let input = format!("e\u{301}{family}{flags}\r\n\u{1100}\u{1161}\u{11A8}\u{D55C}");
+
+    assert_eq!(
+        graphemes(&input).collect::<Vec<_>>(),
+        [
+            "e\u{301}",
+            family,
+            "\u{1F1EF}\u{1F1F5}",
+            "\u{1F1FA}\u{1F1F8}",
+            "\r\n",
+            "\u{1100}\u{1161}\u{11A8}",
+            "\u{D55C}",
+        ]
+    );
+    assert_eq!(graphemes("").next(), None);
+    assert_eq!(GraphemeCount.refine(input.as_str()).expect("count"), 7);
+    assert_eq!(
+        GraphemeCount
+            .refine("\u{1F1EF}\u{1F1F5}\u{1F1FA}")
+            .expect("count"),
+        2
+    );
+}
+
+#[test]
+fn max_graphemes_counts_user_perceived_characters() {
+    let rule = MaxGraphemes::<3>;
+
+    assert!(
+        rule.check(&"e\u{301}\u{1F44D}\u{1F3FD}\u{1F1EF}\u{1F1F5}")
+            .is_ok()
+    );
+    assert!(rule.check(&"").is_ok());
+    assert_eq!(code(rule.check(&"abcd")), "too_long");
+    assert!(MaxUnicodeScalars::<3>.check(&"e\u{301}ab").is_err());
+    assert!(rule.check(&"e\u{301}ab").is_ok());
+}
+
+#[test]
+fn nfc_rejects_decomposed_and_misordered_text() {
+    for input in [
+        "",
+        "plain ascii",
+        "caf\u{E9}",
+        "\u{D55C}\u{AE00}",
+        "\u{1EAD}",
+    ] {
+        assert!(Nfc.check(&input).is_ok(), "{input:?}");
+    }
+
+    for input in [
+        "cafe\u{301}",
+        "\u{1112}\u{1161}\u{11AB}",
+        "a\u{302}\u{323}",
+        "\u{212B}",
+        "\u{F900}",
+    ] {
+        assert_eq!(code(Nfc.check(&input)), "not_normalized", "{input:?}");
+    }
+}
+
+#[test]
+fn bidi_controls_are_rejected() {
+    assert!(
+        NoBidiControls
+            .check(&"\u{5E9}\u{5DC}\u{5D5}\u{5DD} world")
+            .is_ok()
+    );
+
+    for control in ['\u{202E}', '\u{2066}', '\u{200F}', '\u{61C}'] {
+        let input = format!("invoice{control}fdp.exe");
+        assert_eq!(code(NoBidiControls.check(&input)), "bidi_control");
+    }
+}
+
+#[test]
+fn single_script_detects_mixed_script_spoofs() {
+    for input in [
+        "paypal",
+        "\u{43F}\u{430}\u{439}\u{43F}\u{430}\u{43B}",
+        "user_42-name",
+        "\u{6771}\u{4EAC}\u{3068}\u{30AB}\u{30BF}\u{30AB}\u{30CA}",
+        "\u{D55C}\u{AD6D}\u{C5B4}\u{6F22}\u{5B57}",
+        "\u{3042}\u{30FC}",
+    ] {
+        assert!(SingleScript.check(&input).is_ok(), "{input:?}");
+    }
+
+    for input in [
+        "p\u{430}ypal",
+        "\u{3B1}lpha",
+        "\u{3042}\u{D55C}",
+        "\u{10FFFF}a",
+    ] {
+        assert_eq!(
+            code(SingleScript.check(&input)),
+            "mixed_script",
+            "{input:?}"
+        );
+    }
+}
+
+enum DisplayNamePolicy {}
+
+validation_policy! {
+    DisplayNamePolicy: String => [
+        NonEmpty,
+        MaxBytes<64>,
+        MaxGraphemes<16>,
+        Nfc,
+        NoBidiControls,
+        SingleScript,
+    ]
+}
+
+#[test]
+fn unicode_rules_compose_with_string_rules() {
+    assert!(validate_all::<DisplayNamePolicy, _>(String::from("Ren\u{E9}e")).is_ok());
+    assert!(validate_all::<DisplayNamePolicy, _>(String::from("\u{3059}\u{305A}\u{304D}")).is_ok());
+
+    let errors = validate_all::<DisplayNamePolicy, _>(String::from("pa\u{301}\u{443}\u{202E}"))
+        .expect_err("spoofed name");
+    assert_eq!(
+        errors.iter().map(ValidationError::code).collect::<Vec<_>>(),
+        ["not_normalized", "bidi_control", "mixed_script"]
+    );
+
+    let error =
+        validate_first::<DisplayNamePolicy, _>("x".repeat(17)).expect_err("too many graphemes");
+    assert_eq!((error.rule(), error.code()), ("max_graphemes", "too_long"));
+}
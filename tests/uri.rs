@@ -0,0 +1,218 @@
+#![cfg(feature = "alloc")]
+
+use strustegy::prelude::*;
+use strustegy::refine::parse::Ensure;
+use strustegy::refine::uri::{
+    MaxQueryPairs, NoUserInfo, PathNoDotSegments, SchemeIn, Uri, UriParts,
+};
+
+fn within(input: &str, view: &str) -> bool {
+    let start = input.as_ptr() as usize;
+    let view_start = view.as_ptr() as usize;
+
+    start <= view_start && view_start + view.len() <= start + input.len()
+}
+
+#[test]
+fn uri_borrows_every_component() {
+    let input = String::from("https://user:pw@example.com:8443/a/b%20c?x=1&y=2#top");
+    let uri = Uri.refine(input.as_str()).expect("uri");
+
+    assert_eq!(
+        uri,
+        UriParts {
+            scheme: "https",
+            authority: Some("user:pw@example.com:8443"),
+            user_info: Some("user:pw"),
+            host: Some("example.com"),
+            port: Some("8443"),
+            path: "/a/b%20c",
+            query: Some("x=1&y=2"),
+            fragment: Some("top"),
+        }
+    );
+    assert!(within(&input, uri.scheme));
+    assert!(within(&input, uri.path));
+    assert!(uri.query.is_some_and(|query| within(&input, query)));
+}
+
+#[test]
+fn uri_accepts_authority_less_and_literal_forms() {
+    let mail = Uri.refine("mailto:ops@example.com").expect("mailto");
+    assert_eq!((mail.authority, mail.path), (None, "ops@example.com"));
+
+    let urn = Uri.refine("urn:isbn:0451450523").expect("urn");
+    assert_eq!(urn.path, "isbn:0451450523");
+
+    let ipv6 = Uri.refine("http://[::1]:80/").expect("ipv6 literal");
+    assert_eq!((ipv6.host, ipv6.port), (Some("[::1]"), Some("80")));
+
+    let no_port = Uri.refine("http://[2001:db8::7]").expect("ipv6 literal");
+    assert_eq!(
+        (no_port.host, no_port.port, no_port.path),
+        (Some("[2001:db8::7]"), None, "")
+    );
+
+    let file = Uri.refine("file:///etc/hosts").expect("empty authority");
+    assert_eq!((file.host, file.path), (Some(""), "/etc/hosts"));
+
+    let future = Uri.refine("x-app://[v1.fe80::a+en1]/").expect("ipvfuture");
+    assert_eq!(future.scheme, "x-app");
+    assert_eq!(Uri.refine("web+app:").expect("empty path").path, "");
+}
+
+#[test]
+fn uri_rejects_malformed_components() {
+    assert_eq!(
+        Uri.refine("/relative/path").unwrap_err().code(),
+        "missing_scheme"
+    );
+    assert_eq!(
+        Uri.refine("example.com").unwrap_err().code(),
+        "missing_scheme"
+    );
+    assert_eq!(Uri.refine("a/b:c").unwrap_err().code(), "missing_scheme");
+    assert_eq!(
+        Uri.refine(":no-scheme").unwrap_err().code(),
+        "invalid_scheme"
+    );
+    assert_eq!(
+        Uri.refine("1http://example.com").unwrap_err().code(),
+        "invalid_scheme"
+    );
+    assert_eq!(
+        Uri.refine("ht_tp://example.com").unwrap_err().code(),
+        "invalid_scheme"
+    );
+    assert_eq!(
+        Uri.refine("http://exa mple.com").unwrap_err().code(),
+        "invalid_authority"
+    );
+    assert_eq!(
+        Uri.refine("http://[::g]/").unwrap_err().code(),
+        "invalid_authority"
+    );
+    assert_eq!(
+        Uri.refine("http://example.com:80a/").unwrap_err().code(),
+        "invalid_authority"
+    );
+    assert_eq!(
+        Uri.refine("http://a@b@example.com").unwrap_err().code(),
+        "invalid_authority"
+    );
+    assert_eq!(
+        Uri.refine("http://example.com/a b").unwrap_err().code(),
+        "invalid_path"
+    );
+    assert_eq!(
+        Uri.refine("http://example.com/%zz").unwrap_err().code(),
+        "invalid_path"
+    );
+    assert_eq!(
+        Uri.refine("http://example.com/%2").unwrap_err().code(),
+        "invalid_path"
+    );
+    assert_eq!(
+        Uri.refine("http://example.com/?a=<b>").unwrap_err().code(),
+        "invalid_query"
+    );
+    assert_eq!(
+        Uri.refine("http://example.com/#a#b").unwrap_err().code(),
+        "invalid_fragment"
+    );
+}
+
+#[test]
+fn companion_rules_check_uri_components() {
+    let web = SchemeIn::new(&["https", "http"]);
+    let uri = Uri
+        .refine("HTTPS://example.com/hooks?a=1&&b=2&")
+        .expect("uri");
+
+    assert!(web.check(&uri).is_ok());
+    assert_eq!(
+        web.check(&Uri.refine("javascript:alert(1)").expect("uri"))
+            .expect_err("scheme")
+            .code(),
+        "scheme_not_allowed"
+    );
+
+    assert!(NoUserInfo.check(&uri).is_ok());
+    assert_eq!(
+        NoUserInfo
+            .check(&Uri.refine("https://admin@example.com/").expect("uri"))
+            .expect_err("user info")
+            .code(),
+        "user_info_present"
+    );
+
+    assert!(MaxQueryPairs::<2>.check(&uri).is_ok());
+    assert_eq!(
+        MaxQueryPairs::<1>
+            .check(&uri)
+            .expect_err("two pairs")
+            .code(),
+        "too_many_query_pairs"
+    );
+    assert!(
+        MaxQueryPairs::<0>
+            .check(&Uri.refine("https://a/").expect("uri"))
+            .is_ok()
+    );
+
+    assert!(PathNoDotSegments.check(&uri).is_ok());
+    assert!(
+        PathNoDotSegments
+            .check(&Uri.refine("https://a/.well-known/x..y/...").expect("uri"))
+            .is_ok()
+    );
+    for input in [
+        "https://a/./x",
+        "https://a/x/..",
+        "https://a/x/%2e%2E/y",
+        "https://a/.%2e/y",
+        "file:..",
+    ] {
+        let uri = Uri.refine(input).expect("uri");
+        assert_eq!(
+            PathNoDotSegments.check(&uri).expect_err(input).code(),
+            "dot_segment"
+        );
+    }
+}
+
+enum RedirectTargetProof {}
+
+impl ProofPolicy<str> for RedirectTargetProof {
+    type Refiners = hlist_ty![
+        Ensure<Ensure<Uri, SchemeIn>, NoUserInfo>,
+        Ensure<Uri, PathNoDotSegments>,
+        Ensure<Uri, MaxQueryPairs<4>>,
+    ];
+
+    fn refiners() -> Self::Refiners {
+        hlist![
+            Ensure::new(Ensure::new(Uri, SchemeIn::new(&["https"])), NoUserInfo),
+            Ensure::new(Uri, PathNoDotSegments),
+            Ensure::new(Uri, MaxQueryPairs),
+        ]
+    }
+}
+
+#[test]
+fn uri_rules_compose_in_proof_policies() {
+    let input = String::from("https://app.example.com/callback?state=abc");
+    let hlist_pat![uri, _, _] = prove::<RedirectTargetProof, _>(input.as_str())
+        .expect("redirect target should satisfy the policy")
+        .into_evidence();
+
+    assert_eq!(uri.host, Some("app.example.com"));
+    assert!(uri.host.is_some_and(|host| within(&input, host)));
+
+    let errors = prove_all::<RedirectTargetProof, _>("http://evil@example.com/../x?a&b&c&d&e")
+        .expect_err("unsafe redirect target");
+    assert_eq!(
+        errors.iter().map(ValidationError::code).collect::<Vec<_>>(),
+        ["scheme_not_allowed", "dot_segment", "too_many_query_pairs"]
+    );
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use std::any::TypeId;
 
 use strustegy::prelude::*;
@@ -375,3 +377,130 @@ fn inclusive_u64_composes_in_a_policy_hlist() {
     assert_eq!(errors.as_slice()[0].rule(), "inclusive_u64");
     assert_eq!(errors.as_slice()[0].code(), "out_of_range");
 }
+
+#[test]
+fn validate_all_buffered_matches_validate_all_without_allocating() {
+    let rejected = "not/an identifier at all";
+    let buffered =
+        validate_all_buffered::<ToolNamePolicy, _, 3>(String::from(rejected)).unwrap_err();
+    let collected = validate_all::<ToolNamePolicy, _>(String::from(rejected)).unwrap_err();
+
+    assert_eq!(buffered.as_slice(), collected.as_slice());
+    assert_eq!(buffered.first(), collected.first());
+    assert_eq!(buffered.iter().count(), 2);
+    assert_eq!((buffered.len(), buffered.capacity()), (2, 3));
+    assert_eq!(buffered.to_string(), collected.to_string());
+    assert!(!format!("{buffered:?}").contains(rejected));
+
+    let accepted =
+        validate_all_buffered::<ToolNamePolicy, _, 3>(String::from("sync_status")).unwrap();
+    assert_eq!(accepted.get(), "sync_status");
+}
+
+#[test]
+fn error_buffer_keeps_the_first_failures_that_fit() {
+    let mut buffer = ErrorBuffer::<1>::new();
+    assert!(buffer.is_empty());
+
+    buffer.push(ValidationError::new("first", "code"));
+    buffer.push(ValidationError::new("second", "code"));
+
+    assert_eq!(buffer.len(), 1);
+    assert_eq!(buffer.first().map(ValidationError::rule), Some("first"));
+    assert_eq!(buffer, {
+        let mut other = ErrorBuffer::<1>::default();
+        other.push(ValidationError::new("first", "code"));
+        other
+    });
+}
+
+enum StaticNamePolicy {}
+
+validation_policy! {
+    StaticNamePolicy: &'static str => [NonEmpty, MaxBytes<16>, MaxUnicodeScalars<12>, AsciiIdentifier]
+}
+
+const DEFAULT_TOOL: Validated<&str, StaticNamePolicy> =
+    const_validated!(StaticNamePolicy, "sync_status");
+
+enum ShortScalarsPolicy {}
+
+validation_policy! {
+    ShortScalarsPolicy: &'static str => [MaxUnicodeScalars<3>]
+}
+
+#[test]
+fn const_validated_literals_match_runtime_validation() {
+    assert_eq!(*DEFAULT_TOOL.get(), "sync_status");
+    assert_eq!(
+        *const_validated!(StaticNamePolicy, "build-42").get(),
+        validate_all::<StaticNamePolicy, _>("build-42")
+            .expect("runtime validation")
+            .into_inner()
+    );
+
+    let scalars = const_validated!(ShortScalarsPolicy, "🌹é");
+    assert_eq!(*scalars.get(), "🌹é");
+    assert!(validate_first::<ShortScalarsPolicy, _>("🌹éab").is_err());
+}
+
+#[test]
+fn const_rules_mirror_their_runtime_checks() {
+    struct Spaced;
+
+    impl ConstLiteral for Spaced {
+        const VALUE: &'static str = "sync status";
+    }
+
+    assert_eq!(
+        <AsciiIdentifier as ConstRule<Spaced>>::CHECK,
+        AsciiIdentifier.check(&Spaced::VALUE)
+    );
+    assert_eq!(
+        <hlist_ty![NonEmpty, MaxBytes<4>, AsciiIdentifier] as ConstRule<Spaced>>::CHECK,
+        Err(ValidationError::new("max_bytes", "too_long"))
+    );
+    assert!(<NonEmpty as ConstRule<Spaced>>::CHECK.is_ok());
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct AlwaysFails<const INDEX: usize>;
+
+impl<const INDEX: usize> Rule<String> for AlwaysFails<INDEX> {
+    fn check(&self, _value: &String) -> Result<(), ValidationError> {
+        const RULES: [&str; 6] = ["zero", "one", "two", "three", "four", "five"];
+        Err(ValidationError::new(RULES[INDEX], "failed"))
+    }
+}
+
+enum SixFailuresPolicy {}
+
+validation_policy! {
+    SixFailuresPolicy: String => [
+        AlwaysFails<0>,
+        AlwaysFails<1>,
+        AlwaysFails<2>,
+        AlwaysFails<3>,
+        AlwaysFails<4>,
+        AlwaysFails<5>,
+    ]
+}
+
+#[test]
+fn validation_errors_keep_order_past_inline_capacity() {
+    let errors = validate_all::<SixFailuresPolicy, _>(String::new()).unwrap_err();
+    let rules: Vec<_> = errors.iter().map(ValidationError::rule).collect();
+
+    assert_eq!(rules, ["zero", "one", "two", "three", "four", "five"]);
+    assert_eq!(errors.first().map(ValidationError::rule), Some("zero"));
+    assert_eq!(errors.as_slice().len(), 6);
+    assert_eq!(errors.to_string(), "validation failed with 6 error(s)");
+    assert_eq!(errors.clone().into_vec().len(), 6);
+    assert_eq!(errors.clone(), errors);
+
+    let inline = validate_all::<ToolNamePolicy, _>(String::new()).unwrap_err();
+    assert_eq!(
+        format!("{inline:?}"),
+        "ValidationErrors { errors: [ValidationError { rule: \"non_empty\", code: \"empty\" }] }"
+    );
+}
@@ -0,0 +1,191 @@
+#![cfg(feature = "serde")]
+
+use serde::{Deserialize, Serialize};
+use strustegy::prelude::*;
+use strustegy::refine::parse::Ensure;
+use strustegy::refine::uri::{SchemeIn, Uri, UriParts};
+
+pub enum ToolNamePolicy {}
+
+validation_policy! {
+    ToolNamePolicy: String => [NonEmpty, MaxBytes<16>, AsciiIdentifier]
+}
+
+pub enum RetryPolicy {}
+
+validation_policy! {
+    RetryPolicy: u64 => [InclusiveU64<0, 5>]
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct WireTool {
+    name: Validated<String, ToolNamePolicy>,
+    retries: Validated<u64, RetryPolicy>,
+}
+
+#[test]
+fn validated_fields_deserialize_through_their_policy() {
+    let tool: WireTool =
+        serde_json::from_str(r#"{"name":"sync_status","retries":3}"#).expect("valid wire value");
+
+    assert_eq!(tool.name.get(), "sync_status");
+    assert_eq!(*tool.retries.get(), 3);
+}
+
+#[test]
+fn validated_serializes_transparently() {
+    let tool: WireTool =
+        serde_json::from_str(r#"{"name":"sync_status","retries":3}"#).expect("valid wire value");
+
+    assert_eq!(
+        serde_json::to_string(&tool).expect("serialize"),
+        r#"{"name":"sync_status","retries":3}"#
+    );
+    assert_eq!(
+        serde_json::to_string(tool.name.get()).expect("serialize"),
+        serde_json::to_string(&tool.name).expect("serialize")
+    );
+}
+
+#[test]
+fn policy_failures_report_only_rules_and_codes() {
+    let rejected = "not/an identifier at all";
+    let error =
+        serde_json::from_str::<Validated<String, ToolNamePolicy>>(&format!("\"{rejected}\""))
+            .expect_err("policy should reject");
+    let message = error.to_string();
+
+    assert!(message.starts_with(
+        "rule 'max_bytes' failed with code 'too_long'; rule 'ascii_identifier' failed with code 'invalid_character'"
+    ));
+    assert!(!message.contains(rejected));
+
+    let error = serde_json::from_str::<WireTool>(r#"{"name":"ok","retries":9}"#)
+        .expect_err("retries out of range");
+    assert!(
+        error
+            .to_string()
+            .starts_with("rule 'inclusive_u64' failed with code 'out_of_range'")
+    );
+}
+
+#[test]
+fn type_errors_are_reported_before_validation() {
+    let error =
+        serde_json::from_str::<Validated<u64, RetryPolicy>>("\"three\"").expect_err("wrong type");
+
+    assert!(error.is_data());
+    assert!(!error.to_string().contains("rule '"));
+}
+
+pub enum ToolNameProof {}
+
+impl ProofPolicy<str> for ToolNameProof {
+    type Refiners = hlist_ty![TrimmedAsciiIdentifier, ByteLen];
+
+    fn refiners() -> Self::Refiners {
+        hlist![TrimmedAsciiIdentifier, ByteLen]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ToolEvidence<'input> {
+    name: &'input str,
+    source_bytes: usize,
+}
+
+impl ProjectEvidence<str> for ToolNameProof {
+    type Output<'input> = ToolEvidence<'input>;
+
+    fn project<'input>(
+        _input: &'input str,
+        evidence: <Self::Refiners as Prove<str>>::Evidence<'input>,
+    ) -> Self::Output<'input> {
+        let hlist_pat![name, source_bytes] = evidence;
+        ToolEvidence { name, source_bytes }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WireRequest<'input> {
+    #[serde(borrow)]
+    tool: Projected<'input, ToolNameProof>,
+    #[serde(borrow)]
+    endpoint: Projected<'input, UriProof>,
+}
+
+pub enum UriProof {}
+
+impl ProofPolicy<str> for UriProof {
+    type Refiners = hlist_ty![Ensure<Uri, SchemeIn>];
+
+    fn refiners() -> Self::Refiners {
+        hlist![Ensure::new(Uri, SchemeIn::new(&["https"]))]
+    }
+}
+
+impl ProjectEvidence<str> for UriProof {
+    type Output<'input> = UriParts<'input>;
+
+    fn project<'input>(
+        _input: &'input str,
+        evidence: <Self::Refiners as Prove<str>>::Evidence<'input>,
+    ) -> Self::Output<'input> {
+        let hlist_pat![uri] = evidence;
+        uri
+    }
+}
+
+fn within(input: &str, view: &str) -> bool {
+    let start = input.as_ptr() as usize;
+    let view_start = view.as_ptr() as usize;
+
+    start <= view_start && view_start + view.len() <= start + input.len()
+}
+
+#[test]
+fn projected_fields_borrow_from_the_input_buffer() {
+    let body = String::from(r#"{"tool":"  sync_status ","endpoint":"https://example.com/hook"}"#);
+    let request: WireRequest<'_> = serde_json::from_str(&body).expect("valid request");
+
+    assert_eq!(
+        *request.tool.get(),
+        ToolEvidence {
+            name: "sync_status",
+            source_bytes: 14,
+        }
+    );
+    assert!(within(&body, request.tool.get().name));
+
+    let endpoint = request.endpoint.into_inner();
+    assert_eq!(endpoint.host, Some("example.com"));
+    assert!(endpoint.host.is_some_and(|host| within(&body, host)));
+    assert_eq!(format!("{:?}", request.tool), "Projected(<redacted>)");
+}
+
+#[test]
+fn projected_failures_report_every_refiner_without_input() {
+    let body = r#"{"tool":"not/allowed","endpoint":"http://example.com/"}"#;
+    let message = serde_json::from_str::<WireRequest<'_>>(body)
+        .expect_err("tool should be rejected")
+        .to_string();
+
+    assert!(
+        message
+            .starts_with("rule 'trimmed_ascii_identifier' failed with code 'invalid_identifier'")
+    );
+    assert!(!message.contains("not/allowed"));
+
+    let message = serde_json::from_str::<Projected<'_, UriProof>>(r#""http://example.com/""#)
+        .expect_err("scheme should be rejected")
+        .to_string();
+    assert!(message.starts_with("rule 'scheme_in' failed with code 'scheme_not_allowed'"));
+}
+
+#[test]
+fn projected_requires_a_borrowable_string() {
+    let escaped = r#""sync\u005fstatus""#;
+
+    assert!(serde_json::from_str::<Projected<'_, ToolNameProof>>(escaped).is_err());
+    assert!(serde_json::from_str::<Projected<'_, ToolNameProof>>("42").is_err());
+}
@@ -0,0 +1,159 @@
+use strustegy::prelude::*;
+use strustegy::refine::refiners::Checked;
+
+pub enum ToolNameProof {}
+
+impl ProofPolicy<str> for ToolNameProof {
+    type Refiners = hlist_ty![TrimmedAsciiIdentifier, ByteLen];
+
+    fn refiners() -> Self::Refiners {
+        hlist![TrimmedAsciiIdentifier, ByteLen]
+    }
+}
+
+impl ProjectEvidence<str> for ToolNameProof {
+    type Output<'input> = &'input str;
+
+    fn project<'input>(
+        _input: &'input str,
+        evidence: <Self::Refiners as Prove<str>>::Evidence<'input>,
+    ) -> Self::Output<'input> {
+        let hlist_pat![name, _] = evidence;
+        name
+    }
+}
+
+pub enum ToolNamePolicy {}
+
+validation_policy! {
+    ToolNamePolicy: String => [NonEmpty, MaxBytes<64>, AsciiIdentifier]
+}
+
+impl ImpliesValidation<str> for ToolNameProof {
+    type Owned = String;
+    type Validation = ToolNamePolicy;
+
+    fn owned<'input>(
+        _input: &'input str,
+        evidence: &<Self::Refiners as Prove<str>>::Evidence<'input>,
+    ) -> Self::Owned {
+        evidence.head.to_owned()
+    }
+}
+
+pub enum ShortInputProof {}
+
+impl ProofPolicy<str> for ShortInputProof {
+    type Refiners = hlist_ty![Checked<MaxBytes<80>>];
+
+    fn refiners() -> Self::Refiners {
+        hlist![Checked::new(MaxBytes)]
+    }
+}
+
+pub enum ShortToolNameProof {}
+
+impl CombinedPolicy<str> for ShortToolNameProof {
+    type Left = ToolNameProof;
+    type Right = ShortInputProof;
+}
+
+#[test]
+fn map_evidence_keeps_the_witness_and_input() {
+    let input = String::from("  sync_status  ");
+    let witnessed = prove::<ToolNameProof, _>(input.as_str())
+        .expect("tool name should satisfy the proof policy")
+        .map_evidence();
+
+    let _: &Witnessed<'_, str, ToolNameProof, &str> = &witnessed;
+    assert_eq!(witnessed.get(), input.as_str());
+    assert_eq!(*witnessed.evidence(), "sync_status");
+    assert_eq!(
+        witnessed.evidence().as_ptr(),
+        input.as_ptr().wrapping_add(2)
+    );
+}
+
+#[test]
+fn combine_pairs_witnesses_of_the_same_input() {
+    let input = String::from(" sync_status ");
+    let name = prove::<ToolNameProof, _>(input.as_str()).expect("tool name");
+    let short = prove::<ShortInputProof, _>(input.as_str()).expect("short input");
+
+    let combined = name
+        .combine::<ShortToolNameProof, _>(short)
+        .expect("same input");
+    let hlist_pat![hlist_pat![trimmed, byte_len], hlist_pat![checked]] = combined.into_evidence();
+
+    assert_eq!(
+        (trimmed, byte_len, checked),
+        ("sync_status", 13, input.as_str())
+    );
+}
+
+#[test]
+fn combine_rejects_witnesses_of_different_inputs() {
+    let first = String::from("sync_status");
+    let second = first.clone();
+    let name = prove::<ToolNameProof, _>(first.as_str()).expect("tool name");
+    let short = prove::<ShortInputProof, _>(second.as_str()).expect("short input");
+
+    let error = name
+        .combine::<ShortToolNameProof, _>(short)
+        .expect_err("equal text in another allocation is a different input");
+    assert_eq!(
+        (error.rule(), error.code()),
+        ("witnessed", "different_input")
+    );
+
+    let name = prove::<ToolNameProof, _>(first.as_str()).expect("tool name");
+    let prefix = prove::<ShortInputProof, _>(&first[..4]).expect("short input");
+    assert!(name.combine::<ShortToolNameProof, _>(prefix).is_err());
+}
+
+#[test]
+fn to_validated_produces_the_declared_receipt() {
+    let input = String::from("  sync_status  ");
+    let witnessed = prove::<ToolNameProof, _>(input.as_str()).expect("tool name");
+
+    let validated: Validated<String, ToolNamePolicy> = witnessed
+        .to_validated()
+        .expect("trimmed tool names satisfy the policy");
+
+    assert_eq!(validated.get(), "sync_status");
+    assert_eq!(witnessed.evidence().tail.head, input.len());
+    assert_eq!(format!("{validated:?}"), "Validated(<redacted>)");
+}
+
+pub enum AnyTextProof {}
+
+impl ProofPolicy<str> for AnyTextProof {
+    type Refiners = hlist_ty![];
+
+    fn refiners() -> Self::Refiners {
+        hlist![]
+    }
+}
+
+impl ImpliesValidation<str> for AnyTextProof {
+    type Owned = String;
+    type Validation = ToolNamePolicy;
+
+    fn owned(input: &str, _evidence: &HNil) -> Self::Owned {
+        input.to_owned()
+    }
+}
+
+#[test]
+fn to_validated_rejects_an_incorrect_implication() {
+    let witnessed = prove::<AnyTextProof, _>("not an identifier!!").expect("empty proof");
+
+    let error = witnessed
+        .to_validated()
+        .expect_err("the declared validation policy must still run");
+
+    assert_eq!(
+        (error.rule(), error.code()),
+        ("ascii_identifier", "invalid_character")
+    );
+}